@@ -0,0 +1,590 @@
+//! fanotify support for monitoring filesystem events.
+//!
+//! Events are read with [`fanotify_read`], which parses the
+//! `struct fanotify_event_metadata` records it reads into [`Event`]s.
+//!
+//! # Safety
+//!
+//! Events carry file descriptors opened by the kernel, which are converted
+//! into `OwnedFd`s. Each one is replaced by `FAN_NOFD` in the buffer as it is
+//! taken, so that it is owned only once. [`fanotify_read`] is `unsafe`
+//! because this is only sound if the records were written by the kernel for
+//! a fanotify object.
+#![allow(unsafe_code)]
+
+use crate::ffi::ZStr;
+use crate::fs::OFlags;
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::path;
+use crate::process::Pid;
+use imp::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, RawFd};
+
+bitflags::bitflags! {
+    /// `FAN_*` constants for use with [`fanotify_init`].
+    ///
+    /// With none of the `CLASS_*` flags, the fanotify object is of the
+    /// `FAN_CLASS_NOTIF` class, which receives only notification events.
+    pub struct InitFlags: u32 {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = 0x0000_0002;
+        /// `FAN_CLASS_CONTENT`—Receive permission events after the content
+        /// of files is final.
+        const CLASS_CONTENT = 0x0000_0004;
+        /// `FAN_CLASS_PRE_CONTENT`—Receive permission events before the
+        /// content of files is final.
+        const CLASS_PRE_CONTENT = 0x0000_0008;
+        /// `FAN_UNLIMITED_QUEUE`
+        const UNLIMITED_QUEUE = 0x0000_0010;
+        /// `FAN_UNLIMITED_MARKS`
+        const UNLIMITED_MARKS = 0x0000_0020;
+        /// `FAN_ENABLE_AUDIT` (since Linux 4.15)
+        const ENABLE_AUDIT = 0x0000_0040;
+        /// `FAN_REPORT_PIDFD` (since Linux 5.15)
+        const REPORT_PIDFD = 0x0000_0080;
+        /// `FAN_REPORT_TID` (since Linux 4.20)
+        const REPORT_TID = 0x0000_0100;
+        /// `FAN_REPORT_FID` (since Linux 5.1)
+        const REPORT_FID = 0x0000_0200;
+        /// `FAN_REPORT_DIR_FID` (since Linux 5.9)
+        const REPORT_DIR_FID = 0x0000_0400;
+        /// `FAN_REPORT_NAME` (since Linux 5.9)
+        const REPORT_NAME = 0x0000_0800;
+        /// `FAN_REPORT_TARGET_FID` (since Linux 5.17)
+        const REPORT_TARGET_FID = 0x0000_1000;
+        /// `FAN_REPORT_DFID_NAME` (since Linux 5.9)
+        const REPORT_DFID_NAME = Self::REPORT_DIR_FID.bits | Self::REPORT_NAME.bits;
+        /// `FAN_REPORT_DFID_NAME_TARGET` (since Linux 5.17)
+        const REPORT_DFID_NAME_TARGET = Self::REPORT_DFID_NAME.bits
+            | Self::REPORT_FID.bits
+            | Self::REPORT_TARGET_FID.bits;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FAN_MARK_*` constants for use with [`fanotify_mark`].
+    ///
+    /// With neither `MOUNT` nor `FILESYSTEM`, the mark is an inode mark.
+    pub struct MarkFlags: u32 {
+        /// `FAN_MARK_ADD`
+        const ADD = 0x0000_0001;
+        /// `FAN_MARK_REMOVE`
+        const REMOVE = 0x0000_0002;
+        /// `FAN_MARK_DONT_FOLLOW`
+        const DONT_FOLLOW = 0x0000_0004;
+        /// `FAN_MARK_ONLYDIR`
+        const ONLYDIR = 0x0000_0008;
+        /// `FAN_MARK_MOUNT`
+        const MOUNT = 0x0000_0010;
+        /// `FAN_MARK_IGNORED_MASK`
+        const IGNORED_MASK = 0x0000_0020;
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`
+        const IGNORED_SURV_MODIFY = 0x0000_0040;
+        /// `FAN_MARK_FLUSH`
+        const FLUSH = 0x0000_0080;
+        /// `FAN_MARK_FILESYSTEM` (since Linux 4.20)
+        const FILESYSTEM = 0x0000_0100;
+        /// `FAN_MARK_EVICTABLE` (since Linux 5.19)
+        const EVICTABLE = 0x0000_0200;
+        /// `FAN_MARK_IGNORE` (since Linux 6.0)
+        const IGNORE = 0x0000_0400;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FAN_*` event constants for use with [`fanotify_mark`], and reported
+    /// in [`Event`]s.
+    pub struct EventMask: u64 {
+        /// `FAN_ACCESS`
+        const ACCESS = 0x0000_0001;
+        /// `FAN_MODIFY`
+        const MODIFY = 0x0000_0002;
+        /// `FAN_ATTRIB` (since Linux 5.1)
+        const ATTRIB = 0x0000_0004;
+        /// `FAN_CLOSE_WRITE`
+        const CLOSE_WRITE = 0x0000_0008;
+        /// `FAN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = 0x0000_0010;
+        /// `FAN_OPEN`
+        const OPEN = 0x0000_0020;
+        /// `FAN_MOVED_FROM` (since Linux 5.1)
+        const MOVED_FROM = 0x0000_0040;
+        /// `FAN_MOVED_TO` (since Linux 5.1)
+        const MOVED_TO = 0x0000_0080;
+        /// `FAN_CREATE` (since Linux 5.1)
+        const CREATE = 0x0000_0100;
+        /// `FAN_DELETE` (since Linux 5.1)
+        const DELETE = 0x0000_0200;
+        /// `FAN_DELETE_SELF` (since Linux 5.1)
+        const DELETE_SELF = 0x0000_0400;
+        /// `FAN_MOVE_SELF` (since Linux 5.1)
+        const MOVE_SELF = 0x0000_0800;
+        /// `FAN_OPEN_EXEC` (since Linux 5.0)
+        const OPEN_EXEC = 0x0000_1000;
+        /// `FAN_Q_OVERFLOW`
+        const Q_OVERFLOW = 0x0000_4000;
+        /// `FAN_FS_ERROR` (since Linux 5.16)
+        const FS_ERROR = 0x0000_8000;
+        /// `FAN_OPEN_PERM`
+        const OPEN_PERM = 0x0001_0000;
+        /// `FAN_ACCESS_PERM`
+        const ACCESS_PERM = 0x0002_0000;
+        /// `FAN_OPEN_EXEC_PERM` (since Linux 5.0)
+        const OPEN_EXEC_PERM = 0x0004_0000;
+        /// `FAN_EVENT_ON_CHILD`
+        const EVENT_ON_CHILD = 0x0800_0000;
+        /// `FAN_RENAME` (since Linux 5.17)
+        const RENAME = 0x1000_0000;
+        /// `FAN_ONDIR`
+        const ONDIR = 0x4000_0000;
+        /// `FAN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits | Self::CLOSE_NOWRITE.bits;
+        /// `FAN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits | Self::MOVED_TO.bits;
+    }
+}
+
+/// A response to a permission event, for use with [`fanotify_respond`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum Response {
+    /// `FAN_ALLOW`
+    Allow = 0x01,
+    /// `FAN_DENY`
+    Deny = 0x02,
+}
+
+/// `FANOTIFY_METADATA_VERSION`
+const METADATA_VERSION: u8 = 3;
+
+/// The size of `struct fanotify_event_metadata`.
+const METADATA_SIZE: usize = 24;
+
+/// The size of `struct fanotify_event_info_header`.
+const INFO_HEADER_SIZE: usize = 4;
+
+/// `FAN_NOFD` and `FAN_NOPIDFD`
+const NOFD: RawFd = -1;
+
+const INFO_TYPE_FID: u8 = 1;
+const INFO_TYPE_DFID_NAME: u8 = 2;
+const INFO_TYPE_DFID: u8 = 3;
+const INFO_TYPE_PIDFD: u8 = 4;
+const INFO_TYPE_ERROR: u8 = 5;
+const INFO_TYPE_OLD_DFID_NAME: u8 = 10;
+const INFO_TYPE_NEW_DFID_NAME: u8 = 12;
+
+/// `fanotify_init(flags, event_flags)`—Creates a new fanotify object.
+///
+/// `event_flags` are the flags that the file descriptors in events are
+/// opened with.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_init.2.html
+#[inline]
+pub fn fanotify_init(flags: InitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::fanotify_init(flags, event_flags)
+}
+
+/// `fanotify_mark(fanotify, flags, mask, dirfd, path)`—Adds, removes, or
+/// modifies a mark on the filesystem object at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[inline]
+pub fn fanotify_mark<Fd: AsFd, DirFd: AsFd, P: path::Arg>(
+    fanotify: &Fd,
+    flags: MarkFlags,
+    mask: EventMask,
+    dirfd: &DirFd,
+    path: P,
+) -> io::Result<()> {
+    let fanotify = fanotify.as_fd();
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| {
+        imp::syscalls::fanotify_mark(fanotify, flags, mask, dirfd, Some(path))
+    })
+}
+
+/// `fanotify_mark(fanotify, flags, mask, fd, NULL)`—Adds, removes, or
+/// modifies a mark on the filesystem object referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[inline]
+pub fn fanotify_mark_fd<Fd: AsFd, MarkedFd: AsFd>(
+    fanotify: &Fd,
+    flags: MarkFlags,
+    mask: EventMask,
+    fd: &MarkedFd,
+) -> io::Result<()> {
+    let fanotify = fanotify.as_fd();
+    let fd = fd.as_fd();
+    imp::syscalls::fanotify_mark(fanotify, flags, mask, fd, None)
+}
+
+/// `read(fanotify, buf)`—Reads events from a fanotify object into `buf`.
+///
+/// The returned iterator takes ownership of the file descriptors in the
+/// events. Any which aren't iterated over are closed when it is dropped.
+///
+/// # Safety
+///
+/// `fanotify` must be a fanotify object, such as one returned by
+/// [`fanotify_init`]. The file descriptor numbers in the records read from it
+/// are assumed to be newly opened and owned by nothing else.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+pub unsafe fn fanotify_read<'buf, Fd: AsFd>(
+    fanotify: &Fd,
+    buf: &'buf mut [u8],
+) -> io::Result<Events<'buf>> {
+    let nread = io::read(fanotify, buf)?;
+    Ok(Events {
+        buf: &mut buf[..nread],
+    })
+}
+
+/// `write(fanotify, &fanotify_response)`—Responds to a permission event with
+/// the file descriptor `event_fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+#[doc(alias = "FAN_ALLOW")]
+#[doc(alias = "FAN_DENY")]
+pub fn fanotify_respond<Fd: AsFd, EventFd: AsFd>(
+    fanotify: &Fd,
+    event_fd: &EventFd,
+    response: Response,
+) -> io::Result<()> {
+    // `struct fanotify_response`
+    let mut bytes = [0_u8; 8];
+    bytes[..4].copy_from_slice(&event_fd.as_fd().as_raw_fd().to_ne_bytes());
+    bytes[4..].copy_from_slice(&(response as u32).to_ne_bytes());
+    if io::write(fanotify, &bytes)? != bytes.len() {
+        return Err(io::Error::IO);
+    }
+    Ok(())
+}
+
+/// An iterator over the events read by [`fanotify_read`].
+///
+/// Iteration stops at the end of the buffer, or at a record which is
+/// truncated or has an unknown metadata version.
+#[derive(Debug)]
+pub struct Events<'buf> {
+    buf: &'buf mut [u8],
+}
+
+impl<'buf> Iterator for Events<'buf> {
+    type Item = Event<'buf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buf = core::mem::take(&mut self.buf);
+        if buf.len() < METADATA_SIZE {
+            return None;
+        }
+
+        let event_len = u32_at(buf, 0) as usize;
+        let metadata_len = usize::from(u16_at(buf, 6));
+        if buf[4] != METADATA_VERSION
+            || metadata_len < METADATA_SIZE
+            || event_len < metadata_len
+            || event_len > buf.len()
+        {
+            return None;
+        }
+
+        let (record, rest) = buf.split_at_mut(event_len);
+        self.buf = rest;
+
+        let mask = EventMask::from_bits_truncate(u64_at(record, 8));
+        let fd = take_fd(record, 16);
+        let pid = u32_at(record, 20) as i32;
+
+        // Take the pidfd out of its info record, if there is one.
+        let mut pidfd = None;
+        let mut pos = metadata_len;
+        while let Some((info_type, len)) = info_header(&record[pos..]) {
+            if info_type == INFO_TYPE_PIDFD && len >= INFO_HEADER_SIZE + 4 {
+                pidfd = take_fd(record, pos + INFO_HEADER_SIZE);
+            }
+            pos += len;
+        }
+
+        let record: &'buf [u8] = record;
+        Some(Event {
+            mask,
+            fd,
+            pid,
+            pidfd,
+            info: &record[metadata_len..],
+        })
+    }
+}
+
+impl<'buf> Drop for Events<'buf> {
+    fn drop(&mut self) {
+        // Close the file descriptors of any remaining events.
+        self.for_each(drop);
+    }
+}
+
+/// `struct fanotify_event_metadata`—An event read by [`fanotify_read`].
+#[derive(Debug)]
+pub struct Event<'buf> {
+    mask: EventMask,
+    fd: Option<OwnedFd>,
+    pid: i32,
+    pidfd: Option<OwnedFd>,
+    info: &'buf [u8],
+}
+
+impl<'buf> Event<'buf> {
+    /// Returns the events which occurred.
+    #[inline]
+    pub fn mask(&self) -> EventMask {
+        self.mask
+    }
+
+    /// Returns the file descriptor of the object the event is for, if the
+    /// fanotify object doesn't report file ids and the event isn't a
+    /// [`EventMask::Q_OVERFLOW`].
+    #[inline]
+    pub fn fd(&self) -> Option<BorrowedFd<'_>> {
+        self.fd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Takes ownership of the file descriptor returned by [`Event::fd`].
+    #[inline]
+    pub fn take_fd(&mut self) -> Option<OwnedFd> {
+        self.fd.take()
+    }
+
+    /// Returns the id of the process, or the thread with
+    /// [`InitFlags::REPORT_TID`], which caused the event, if it is visible
+    /// in the reader's PID namespace.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        // Safety: The kernel reports a valid process id or 0.
+        unsafe { Pid::from_raw(self.pid as _) }
+    }
+
+    /// Returns a pidfd for the process which caused the event, with
+    /// [`InitFlags::REPORT_PIDFD`], if the process still exists.
+    #[inline]
+    pub fn pidfd(&self) -> Option<BorrowedFd<'_>> {
+        self.pidfd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Takes ownership of the file descriptor returned by [`Event::pidfd`].
+    #[inline]
+    pub fn take_pidfd(&mut self) -> Option<OwnedFd> {
+        self.pidfd.take()
+    }
+
+    /// Returns an iterator over the event's info records.
+    #[inline]
+    pub fn info_records(&self) -> InfoRecords<'buf> {
+        InfoRecords { buf: self.info }
+    }
+}
+
+/// An iterator over the info records of an [`Event`].
+///
+/// `FAN_EVENT_INFO_TYPE_PIDFD` records are reported by [`Event::pidfd`]
+/// rather than by this iterator.
+#[derive(Debug, Clone)]
+pub struct InfoRecords<'buf> {
+    buf: &'buf [u8],
+}
+
+impl<'buf> Iterator for InfoRecords<'buf> {
+    type Item = InfoRecord<'buf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (info_type, len) = info_header(self.buf)?;
+            let (record, rest) = self.buf.split_at(len);
+            self.buf = rest;
+
+            let parsed =
+                match info_type {
+                    INFO_TYPE_FID => file_id(record).map(|(fid, _)| InfoRecord::Fid(fid)),
+                    INFO_TYPE_DFID => file_id(record).map(|(fid, _)| InfoRecord::Dfid(fid)),
+                    INFO_TYPE_DFID_NAME => {
+                        file_id_and_name(record).map(|(fid, name)| InfoRecord::DfidName(fid, name))
+                    }
+                    INFO_TYPE_OLD_DFID_NAME => file_id_and_name(record)
+                        .map(|(fid, name)| InfoRecord::OldDfidName(fid, name)),
+                    INFO_TYPE_NEW_DFID_NAME => file_id_and_name(record)
+                        .map(|(fid, name)| InfoRecord::NewDfidName(fid, name)),
+                    INFO_TYPE_PIDFD => continue,
+                    INFO_TYPE_ERROR if len >= INFO_HEADER_SIZE + 8 => Some(InfoRecord::Error {
+                        error: u32_at(record, 4) as i32,
+                        error_count: u32_at(record, 8),
+                    }),
+                    _ => None,
+                };
+            return Some(parsed.unwrap_or(InfoRecord::Unknown {
+                info_type,
+                data: &record[INFO_HEADER_SIZE..],
+            }));
+        }
+    }
+}
+
+/// An info record of an [`Event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InfoRecord<'buf> {
+    /// `FAN_EVENT_INFO_TYPE_FID`—The object the event is for.
+    Fid(FileId<'buf>),
+    /// `FAN_EVENT_INFO_TYPE_DFID`—The directory of the object the event is
+    /// for.
+    Dfid(FileId<'buf>),
+    /// `FAN_EVENT_INFO_TYPE_DFID_NAME`—The directory and name of the object
+    /// the event is for.
+    DfidName(FileId<'buf>, &'buf ZStr),
+    /// `FAN_EVENT_INFO_TYPE_OLD_DFID_NAME`—The directory and name an object
+    /// was renamed from.
+    OldDfidName(FileId<'buf>, &'buf ZStr),
+    /// `FAN_EVENT_INFO_TYPE_NEW_DFID_NAME`—The directory and name an object
+    /// was renamed to.
+    NewDfidName(FileId<'buf>, &'buf ZStr),
+    /// `FAN_EVENT_INFO_TYPE_ERROR`—The filesystem error of a
+    /// [`EventMask::FS_ERROR`] event.
+    Error {
+        /// The errno value of the first error.
+        error: i32,
+        /// The number of errors since the last event.
+        error_count: u32,
+    },
+    /// A record of an unrecognized type, or which is malformed.
+    Unknown {
+        /// The `FAN_EVENT_INFO_TYPE_*` value.
+        info_type: u8,
+        /// The record, following its header.
+        data: &'buf [u8],
+    },
+}
+
+/// The filesystem id and file handle of an object, reported in an
+/// [`InfoRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileId<'buf> {
+    fsid: [i32; 2],
+    handle_type: i32,
+    handle: &'buf [u8],
+}
+
+impl<'buf> FileId<'buf> {
+    /// Returns the id of the filesystem containing the object, as reported
+    /// in `f_fsid` by `statfs`.
+    #[inline]
+    pub fn fsid(&self) -> [i32; 2] {
+        self.fsid
+    }
+
+    /// Returns the type of the file handle.
+    #[inline]
+    pub fn handle_type(&self) -> i32 {
+        self.handle_type
+    }
+
+    /// Returns the bytes of the file handle.
+    #[inline]
+    pub fn handle(&self) -> &'buf [u8] {
+        self.handle
+    }
+}
+
+/// Parse a `struct fanotify_event_info_fid` record, returning the file id and
+/// the bytes that follow it.
+fn file_id(record: &[u8]) -> Option<(FileId<'_>, &[u8])> {
+    // The header, `fsid`, and `handle_bytes` and `handle_type` of
+    // `struct file_handle`.
+    const FIXED_SIZE: usize = INFO_HEADER_SIZE + 8 + 8;
+    if record.len() < FIXED_SIZE {
+        return None;
+    }
+    let handle_bytes = u32_at(record, 12) as usize;
+    let handle_end = FIXED_SIZE.checked_add(handle_bytes)?;
+    if record.len() < handle_end {
+        return None;
+    }
+    let fid = FileId {
+        fsid: [u32_at(record, 4) as i32, u32_at(record, 8) as i32],
+        handle_type: u32_at(record, 16) as i32,
+        handle: &record[FIXED_SIZE..handle_end],
+    };
+    Some((fid, &record[handle_end..]))
+}
+
+/// Parse a `struct fanotify_event_info_fid` record which is followed by a
+/// NUL-terminated name.
+fn file_id_and_name(record: &[u8]) -> Option<(FileId<'_>, &ZStr)> {
+    let (fid, rest) = file_id(record)?;
+    let nul = rest.iter().position(|x| *x == b'\0')?;
+    let name = ZStr::from_bytes_with_nul(&rest[..nul + 1]).unwrap();
+    Some((fid, name))
+}
+
+/// Parse a `struct fanotify_event_info_header`, returning the record's type
+/// and length, if it fits in `buf`.
+fn info_header(buf: &[u8]) -> Option<(u8, usize)> {
+    if buf.len() < INFO_HEADER_SIZE {
+        return None;
+    }
+    let len = usize::from(u16_at(buf, 2));
+    if len < INFO_HEADER_SIZE || len > buf.len() {
+        return None;
+    }
+    Some((buf[0], len))
+}
+
+/// Take the file descriptor at `offset` in `record`, replacing it with
+/// `FAN_NOFD`.
+fn take_fd(record: &mut [u8], offset: usize) -> Option<OwnedFd> {
+    let fd = u32_at(record, offset) as RawFd;
+    record[offset..offset + 4].copy_from_slice(&NOFD.to_ne_bytes());
+    if fd >= 0 {
+        // Safety: `fanotify_read` requires that the kernel opened `fd` for
+        // this event, and we've removed it from the buffer so that it can't
+        // be taken again.
+        Some(unsafe { OwnedFd::from_raw_fd(fd) })
+    } else {
+        None
+    }
+}
+
+// Do unaligned loads, as the buffer need not be aligned.
+
+fn u16_at(buf: &[u8], offset: usize) -> u16 {
+    u16::from_ne_bytes([buf[offset], buf[offset + 1]])
+}
+
+fn u32_at(buf: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0_u8; 4];
+    bytes.copy_from_slice(&buf[offset..offset + 4]);
+    u32::from_ne_bytes(bytes)
+}
+
+fn u64_at(buf: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0_u8; 8];
+    bytes.copy_from_slice(&buf[offset..offset + 8]);
+    u64::from_ne_bytes(bytes)
+}
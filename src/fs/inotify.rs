@@ -0,0 +1,163 @@
+//! inotify support for watching for filesystem events.
+//!
+//! Events are read from the inotify file descriptor with [`read`] into a
+//! caller-supplied buffer, and then parsed with [`Events`].
+//!
+//! [`read`]: crate::io::read
+
+use crate::ffi::ZStr;
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::path;
+use imp::fd::AsFd;
+
+pub use imp::fs::inotify::{CreateFlags, WatchFlags};
+
+/// The size of the fixed part of `struct inotify_event`, before the name.
+const EVENT_HEADER_SIZE: usize = 16;
+
+/// `inotify_init1(flags)`—Creates a new inotify object.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_init1.2.html
+#[inline]
+#[doc(alias = "inotify_init1")]
+pub fn inotify_init(flags: CreateFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::inotify_init1(flags)
+}
+
+/// `inotify_add_watch(inot, path, flags)`—Adds a watch to an inotify object,
+/// or modifies the existing watch for `path`.
+///
+/// Returns the watch descriptor, which identifies the watch in events and in
+/// [`inotify_remove_watch`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html
+#[inline]
+pub fn inotify_add_watch<Fd: AsFd, P: path::Arg>(
+    inot: &Fd,
+    path: P,
+    flags: WatchFlags,
+) -> io::Result<i32> {
+    let inot = inot.as_fd();
+    path.into_with_z_str(|path| imp::syscalls::inotify_add_watch(inot, path, flags))
+}
+
+/// `inotify_rm_watch(inot, wd)`—Removes a watch from an inotify object.
+///
+/// The kernel queues an event with [`WatchFlags::IGNORED`] for the watch.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html
+#[inline]
+#[doc(alias = "inotify_rm_watch")]
+pub fn inotify_remove_watch<Fd: AsFd>(inot: &Fd, wd: i32) -> io::Result<()> {
+    let inot = inot.as_fd();
+    imp::syscalls::inotify_rm_watch(inot, wd)
+}
+
+/// An iterator over the `struct inotify_event` records in a buffer filled by
+/// reading from an inotify file descriptor.
+///
+/// The events borrow their names from the buffer. Iteration stops at the end
+/// of the buffer, or at a record which is truncated.
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Events<'a> {
+    /// Construct an `Events` over the records in `buf`, which should hold
+    /// exactly the bytes returned by a `read`.
+    #[inline]
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.len() < EVENT_HEADER_SIZE {
+            return None;
+        }
+
+        // Do unaligned u32 loads, as the buffer need not be aligned.
+        let field = |offset: usize| {
+            let mut bytes = [0_u8; 4];
+            bytes.copy_from_slice(&self.buf[offset..offset + 4]);
+            u32::from_ne_bytes(bytes)
+        };
+        let wd = field(0) as i32;
+        let mask = field(4);
+        let cookie = field(8);
+        let len = field(12) as usize;
+
+        let record_len = EVENT_HEADER_SIZE.checked_add(len)?;
+        if self.buf.len() < record_len {
+            return None;
+        }
+
+        // The name is NUL-terminated, and padded with further NULs.
+        let name_bytes = &self.buf[EVENT_HEADER_SIZE..record_len];
+        let name = name_bytes
+            .iter()
+            .position(|x| *x == b'\0')
+            .map(|nul| ZStr::from_bytes_with_nul(&name_bytes[..nul + 1]).unwrap());
+
+        self.buf = &self.buf[record_len..];
+
+        Some(Event {
+            wd,
+            events: WatchFlags::from_bits_truncate(mask),
+            cookie,
+            name,
+        })
+    }
+}
+
+/// `struct inotify_event`—An inotify event, parsed by [`Events`].
+#[derive(Debug, Clone, Copy)]
+pub struct Event<'a> {
+    wd: i32,
+    events: WatchFlags,
+    cookie: u32,
+    name: Option<&'a ZStr>,
+}
+
+impl<'a> Event<'a> {
+    /// Returns the watch descriptor of the watch this event is for, or -1 for
+    /// [`WatchFlags::Q_OVERFLOW`] events.
+    #[inline]
+    pub fn wd(&self) -> i32 {
+        self.wd
+    }
+
+    /// Returns the events which occurred.
+    #[inline]
+    pub fn events(&self) -> WatchFlags {
+        self.events
+    }
+
+    /// Returns the cookie which connects the [`WatchFlags::MOVED_FROM`] and
+    /// [`WatchFlags::MOVED_TO`] events of a rename, or 0.
+    #[inline]
+    pub fn cookie(&self) -> u32 {
+        self.cookie
+    }
+
+    /// Returns the name of the file within a watched directory that this
+    /// event is for, if any.
+    #[inline]
+    pub fn file_name(&self) -> Option<&'a ZStr> {
+        self.name
+    }
+}
@@ -21,6 +21,8 @@ mod dir;
     target_os = "redox"
 )))]
 mod fadvise;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub mod fanotify;
 pub(crate) mod fcntl;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 mod fcntl_darwin;
@@ -30,6 +32,8 @@ pub(crate) mod fd;
 mod file_type;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 mod getpath;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub mod inotify;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "ios",
@@ -43,12 +47,16 @@ mod getpath;
 mod makedev;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod memfd_create;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub mod mount;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat2;
 #[cfg(target_os = "linux")]
 mod sendfile;
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod xattr;
 
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use at::fclonefileat;
@@ -157,6 +165,12 @@ pub use openat2::openat2;
 pub use sendfile::sendfile;
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 pub use statx::{statx, StatxFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use xattr::{
+    fgetxattr, flistxattr, fremovexattr, fsetxattr, getxattr, getxattrat, lgetxattr, listxattr,
+    listxattrat, llistxattr, lremovexattr, lsetxattr, removexattr, removexattrat, setxattr,
+    setxattrat, XattrFlags, XattrNames,
+};
 
 pub use imp::fs::Stat;
 
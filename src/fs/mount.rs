@@ -0,0 +1,926 @@
+//! Linux `mount` API.
+//!
+//! This includes the classic [`mount`] and [`umount2`], and the file
+//! descriptor based API of [`fsopen`], [`fsconfig_create`], [`fsmount`],
+//! [`move_mount`], [`open_tree`], and [`mount_setattr`], and the mount
+//! introspection API of [`statmount`] and [`listmount`].
+#![allow(unsafe_code)]
+
+use crate::ffi::ZStr;
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::path;
+use core::convert::TryInto;
+use core::fmt;
+use imp::fd::{AsFd, AsRawFd, BorrowedFd};
+
+bitflags::bitflags! {
+    /// `MS_*` constants for use with [`mount`].
+    pub struct MountFlags: u32 {
+        /// `MS_RDONLY`
+        const RDONLY = 0x0000_0001;
+        /// `MS_NOSUID`
+        const NOSUID = 0x0000_0002;
+        /// `MS_NODEV`
+        const NODEV = 0x0000_0004;
+        /// `MS_NOEXEC`
+        const NOEXEC = 0x0000_0008;
+        /// `MS_SYNCHRONOUS`
+        const SYNCHRONOUS = 0x0000_0010;
+        /// `MS_REMOUNT`
+        const REMOUNT = 0x0000_0020;
+        /// `MS_MANDLOCK`
+        const MANDLOCK = 0x0000_0040;
+        /// `MS_DIRSYNC`
+        const DIRSYNC = 0x0000_0080;
+        /// `MS_NOSYMFOLLOW` (since Linux 5.10)
+        const NOSYMFOLLOW = 0x0000_0100;
+        /// `MS_NOATIME`
+        const NOATIME = 0x0000_0400;
+        /// `MS_NODIRATIME`
+        const NODIRATIME = 0x0000_0800;
+        /// `MS_BIND`
+        const BIND = 0x0000_1000;
+        /// `MS_MOVE`
+        const MOVE = 0x0000_2000;
+        /// `MS_REC`
+        const REC = 0x0000_4000;
+        /// `MS_SILENT`
+        const SILENT = 0x0000_8000;
+        /// `MS_POSIXACL`
+        const POSIXACL = 0x0001_0000;
+        /// `MS_UNBINDABLE`
+        const UNBINDABLE = 0x0002_0000;
+        /// `MS_PRIVATE`
+        const PRIVATE = 0x0004_0000;
+        /// `MS_SLAVE`
+        const SLAVE = 0x0008_0000;
+        /// `MS_SHARED`
+        const SHARED = 0x0010_0000;
+        /// `MS_RELATIME`
+        const RELATIME = 0x0020_0000;
+        /// `MS_I_VERSION`
+        const I_VERSION = 0x0080_0000;
+        /// `MS_STRICTATIME`
+        const STRICTATIME = 0x0100_0000;
+        /// `MS_LAZYTIME`
+        const LAZYTIME = 0x0200_0000;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MNT_*` and `UMOUNT_*` constants for use with [`umount2`].
+    pub struct UnmountFlags: u32 {
+        /// `MNT_FORCE`
+        const FORCE = 0x0000_0001;
+        /// `MNT_DETACH`
+        const DETACH = 0x0000_0002;
+        /// `MNT_EXPIRE`
+        const EXPIRE = 0x0000_0004;
+        /// `UMOUNT_NOFOLLOW`
+        const NOFOLLOW = 0x0000_0008;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FSOPEN_*` constants for use with [`fsopen`].
+    pub struct FsOpenFlags: u32 {
+        /// `FSOPEN_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FSPICK_*` constants for use with [`fspick`].
+    pub struct FsPickFlags: u32 {
+        /// `FSPICK_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+        /// `FSPICK_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = 0x0000_0002;
+        /// `FSPICK_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = 0x0000_0004;
+        /// `FSPICK_EMPTY_PATH`
+        const EMPTY_PATH = 0x0000_0008;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FSMOUNT_*` constants for use with [`fsmount`].
+    pub struct FsMountFlags: u32 {
+        /// `FSMOUNT_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MOUNT_ATTR_*` constants for use with [`fsmount`] and
+    /// [`mount_setattr`].
+    ///
+    /// The atime behavior is set by one of `NOATIME` or `STRICTATIME`, or
+    /// neither for relatime. To change it with [`mount_setattr`], `ATIME`
+    /// must be in [`MountAttr::attr_clr`].
+    pub struct MountAttrFlags: u32 {
+        /// `MOUNT_ATTR_RDONLY`
+        const RDONLY = 0x0000_0001;
+        /// `MOUNT_ATTR_NOSUID`
+        const NOSUID = 0x0000_0002;
+        /// `MOUNT_ATTR_NODEV`
+        const NODEV = 0x0000_0004;
+        /// `MOUNT_ATTR_NOEXEC`
+        const NOEXEC = 0x0000_0008;
+        /// `MOUNT_ATTR__ATIME`—The mask of the atime behavior.
+        const ATIME = 0x0000_0070;
+        /// `MOUNT_ATTR_NOATIME`
+        const NOATIME = 0x0000_0010;
+        /// `MOUNT_ATTR_STRICTATIME`
+        const STRICTATIME = 0x0000_0020;
+        /// `MOUNT_ATTR_NODIRATIME`
+        const NODIRATIME = 0x0000_0080;
+        /// `MOUNT_ATTR_IDMAP` (since Linux 5.12)—Idmap the mount with
+        /// [`MountAttr::userns_fd`]; only for use with [`mount_setattr`].
+        const IDMAP = 0x0010_0000;
+        /// `MOUNT_ATTR_NOSYMFOLLOW` (since Linux 5.14)
+        const NOSYMFOLLOW = 0x0020_0000;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MOVE_MOUNT_*` constants for use with [`move_mount`].
+    pub struct MoveMountFlags: u32 {
+        /// `MOVE_MOUNT_F_SYMLINKS`
+        const F_SYMLINKS = 0x0000_0001;
+        /// `MOVE_MOUNT_F_AUTOMOUNTS`
+        const F_AUTOMOUNTS = 0x0000_0002;
+        /// `MOVE_MOUNT_F_EMPTY_PATH`
+        const F_EMPTY_PATH = 0x0000_0004;
+        /// `MOVE_MOUNT_T_SYMLINKS`
+        const T_SYMLINKS = 0x0000_0010;
+        /// `MOVE_MOUNT_T_AUTOMOUNTS`
+        const T_AUTOMOUNTS = 0x0000_0020;
+        /// `MOVE_MOUNT_T_EMPTY_PATH`
+        const T_EMPTY_PATH = 0x0000_0040;
+        /// `MOVE_MOUNT_SET_GROUP` (since Linux 5.15)
+        const SET_GROUP = 0x0000_0100;
+        /// `MOVE_MOUNT_BENEATH` (since Linux 6.5)
+        const BENEATH = 0x0000_0200;
+    }
+}
+
+bitflags::bitflags! {
+    /// `OPEN_TREE_*` and `AT_*` constants for use with [`open_tree`].
+    pub struct OpenTreeFlags: u32 {
+        /// `OPEN_TREE_CLONE`
+        const CLONE = 0x0000_0001;
+        /// `OPEN_TREE_CLOEXEC`
+        const CLOEXEC = 0x0008_0000;
+        /// `AT_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = 0x0000_0100;
+        /// `AT_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = 0x0000_0800;
+        /// `AT_EMPTY_PATH`
+        const EMPTY_PATH = 0x0000_1000;
+        /// `AT_RECURSIVE`
+        const RECURSIVE = 0x0000_8000;
+    }
+}
+
+bitflags::bitflags! {
+    /// `AT_*` constants for use with [`mount_setattr`].
+    pub struct MountSetattrFlags: u32 {
+        /// `AT_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = 0x0000_0100;
+        /// `AT_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = 0x0000_0800;
+        /// `AT_EMPTY_PATH`
+        const EMPTY_PATH = 0x0000_1000;
+        /// `AT_RECURSIVE`
+        const RECURSIVE = 0x0000_8000;
+    }
+}
+
+bitflags::bitflags! {
+    /// `STATMOUNT_*` constants for use with [`statmount`].
+    pub struct StatmountFlags: u64 {
+        /// `STATMOUNT_SB_BASIC`
+        const SB_BASIC = 0x0000_0001;
+        /// `STATMOUNT_MNT_BASIC`
+        const MNT_BASIC = 0x0000_0002;
+        /// `STATMOUNT_PROPAGATE_FROM`
+        const PROPAGATE_FROM = 0x0000_0004;
+        /// `STATMOUNT_MNT_ROOT`
+        const MNT_ROOT = 0x0000_0008;
+        /// `STATMOUNT_MNT_POINT`
+        const MNT_POINT = 0x0000_0010;
+        /// `STATMOUNT_FS_TYPE`
+        const FS_TYPE = 0x0000_0020;
+        /// `STATMOUNT_MNT_NS_ID` (since Linux 6.11)
+        const MNT_NS_ID = 0x0000_0040;
+        /// `STATMOUNT_MNT_OPTS` (since Linux 6.10)
+        const MNT_OPTS = 0x0000_0080;
+        /// `STATMOUNT_FS_SUBTYPE` (since Linux 6.12)
+        const FS_SUBTYPE = 0x0000_0100;
+        /// `STATMOUNT_SB_SOURCE` (since Linux 6.12)
+        const SB_SOURCE = 0x0000_0200;
+    }
+}
+
+bitflags::bitflags! {
+    /// `LISTMOUNT_*` constants for use with [`listmount`].
+    pub struct ListmountFlags: u32 {
+        /// `LISTMOUNT_REVERSE` (since Linux 6.11)
+        const REVERSE = 0x0000_0001;
+    }
+}
+
+/// `LSMT_ROOT`—The mount ID to pass to [`listmount`] to list the mounts at
+/// the root of the current mount namespace.
+pub const LSMT_ROOT: u64 = u64::MAX;
+
+/// The propagation type of a mount, for use with [`mount_setattr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum MountPropagation {
+    /// `MS_UNBINDABLE`
+    Unbindable = MountFlags::UNBINDABLE.bits(),
+    /// `MS_PRIVATE`
+    Private = MountFlags::PRIVATE.bits(),
+    /// `MS_SLAVE`
+    Slave = MountFlags::SLAVE.bits(),
+    /// `MS_SHARED`
+    Shared = MountFlags::SHARED.bits(),
+}
+
+/// `struct mount_attr`—Mount attributes to change with [`mount_setattr`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MountAttr<'a> {
+    /// Attributes to set.
+    pub attr_set: MountAttrFlags,
+    /// Attributes to clear.
+    pub attr_clr: MountAttrFlags,
+    /// A propagation type to set, or `None` to leave it unchanged.
+    pub propagation: Option<MountPropagation>,
+    /// The user namespace to idmap the mount with, with
+    /// [`MountAttrFlags::IDMAP`] in `attr_set`.
+    pub userns_fd: Option<BorrowedFd<'a>>,
+}
+
+impl Default for MountAttrFlags {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// `mount(source, target, file_system_type, flags, data)`—Mounts a
+/// filesystem, or changes an existing mount.
+///
+/// `source` and `file_system_type` are ignored by some operations, such as
+/// [`MountFlags::REMOUNT`] and changes of propagation type, and may be
+/// empty for them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount.2.html
+#[inline]
+pub fn mount<Source: path::Arg, Target: path::Arg, Fs: path::Arg>(
+    source: Source,
+    target: Target,
+    file_system_type: Fs,
+    flags: MountFlags,
+    data: Option<&ZStr>,
+) -> io::Result<()> {
+    source.into_with_z_str(|source| {
+        target.into_with_z_str(|target| {
+            file_system_type.into_with_z_str(|file_system_type| {
+                imp::syscalls::mount(source, target, file_system_type, flags, data)
+            })
+        })
+    })
+}
+
+/// `umount2(target, flags)`—Unmounts the filesystem mounted at `target`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/umount2.2.html
+#[inline]
+#[doc(alias = "umount")]
+pub fn umount2<Target: path::Arg>(target: Target, flags: UnmountFlags) -> io::Result<()> {
+    target.into_with_z_str(|target| imp::syscalls::umount2(target, flags))
+}
+
+/// `fsopen(fs_name, flags)`—Creates a filesystem context, to configure with
+/// `fsconfig_*` and mount with [`fsmount`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+pub fn fsopen<Fs: path::Arg>(fs_name: Fs, flags: FsOpenFlags) -> io::Result<OwnedFd> {
+    fs_name.into_with_z_str(|fs_name| imp::syscalls::fsopen(fs_name, flags))
+}
+
+/// `fspick(dirfd, path, flags)`—Creates a filesystem context for the
+/// existing filesystem at `path`, to reconfigure with `fsconfig_*`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+pub fn fspick<Fd: AsFd, P: path::Arg>(
+    dirfd: &Fd,
+    path: P,
+    flags: FsPickFlags,
+) -> io::Result<OwnedFd> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| imp::syscalls::fspick(dirfd, path, flags))
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_FLAG, key, NULL, 0)`—Sets the boolean
+/// parameter `key` of a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_flag<Fd: AsFd, Key: path::Arg>(fs_fd: &Fd, key: Key) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    key.into_with_z_str(|key| unsafe {
+        imp::syscalls::fsconfig(fs_fd, FSCONFIG_SET_FLAG, Some(key), core::ptr::null(), 0)
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_STRING, key, value, 0)`—Sets the string
+/// parameter `key` of a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_string<Fd: AsFd, Key: path::Arg, Value: path::Arg>(
+    fs_fd: &Fd,
+    key: Key,
+    value: Value,
+) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    key.into_with_z_str(|key| {
+        value.into_with_z_str(|value| unsafe {
+            imp::syscalls::fsconfig(
+                fs_fd,
+                FSCONFIG_SET_STRING,
+                Some(key),
+                value.as_ptr().cast(),
+                0,
+            )
+        })
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_BINARY, key, value, value.len())`—Sets the
+/// binary parameter `key` of a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_binary<Fd: AsFd, Key: path::Arg>(
+    fs_fd: &Fd,
+    key: Key,
+    value: &[u8],
+) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    let len = value.len().try_into().map_err(|_| io::Error::TOOBIG)?;
+    key.into_with_z_str(|key| unsafe {
+        imp::syscalls::fsconfig(
+            fs_fd,
+            FSCONFIG_SET_BINARY,
+            Some(key),
+            value.as_ptr().cast(),
+            len,
+        )
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_PATH, key, path, dirfd)`—Sets the path
+/// parameter `key` of a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_path<Fd: AsFd, Key: path::Arg, DirFd: AsFd, P: path::Arg>(
+    fs_fd: &Fd,
+    key: Key,
+    dirfd: &DirFd,
+    path: P,
+) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    let dirfd = dirfd.as_fd();
+    key.into_with_z_str(|key| {
+        path.into_with_z_str(|path| unsafe {
+            imp::syscalls::fsconfig(
+                fs_fd,
+                FSCONFIG_SET_PATH,
+                Some(key),
+                path.as_ptr().cast(),
+                dirfd.as_raw_fd(),
+            )
+        })
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_PATH_EMPTY, key, "", fd)`—Sets the path
+/// parameter `key` of a filesystem context to the file referred to by
+/// `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_path_empty<Fd: AsFd, Key: path::Arg, PathFd: AsFd>(
+    fs_fd: &Fd,
+    key: Key,
+    fd: &PathFd,
+) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    let fd = fd.as_fd();
+    key.into_with_z_str(|key| unsafe {
+        imp::syscalls::fsconfig(
+            fs_fd,
+            FSCONFIG_SET_PATH_EMPTY,
+            Some(key),
+            b"\0".as_ptr().cast(),
+            fd.as_raw_fd(),
+        )
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_FD, key, NULL, fd)`—Sets the file
+/// descriptor parameter `key` of a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_fd<Fd: AsFd, Key: path::Arg, ValueFd: AsFd>(
+    fs_fd: &Fd,
+    key: Key,
+    fd: &ValueFd,
+) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    let fd = fd.as_fd();
+    key.into_with_z_str(|key| unsafe {
+        imp::syscalls::fsconfig(
+            fs_fd,
+            FSCONFIG_SET_FD,
+            Some(key),
+            core::ptr::null(),
+            fd.as_raw_fd(),
+        )
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_CMD_CREATE, NULL, NULL, 0)`—Creates the
+/// filesystem configured in a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_create<Fd: AsFd>(fs_fd: &Fd) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    unsafe { imp::syscalls::fsconfig(fs_fd, FSCONFIG_CMD_CREATE, None, core::ptr::null(), 0) }
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_CMD_RECONFIGURE, NULL, NULL, 0)`—Applies the
+/// configuration in a filesystem context from [`fspick`] to its
+/// filesystem.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_reconfigure<Fd: AsFd>(fs_fd: &Fd) -> io::Result<()> {
+    let fs_fd = fs_fd.as_fd();
+    unsafe { imp::syscalls::fsconfig(fs_fd, FSCONFIG_CMD_RECONFIGURE, None, core::ptr::null(), 0) }
+}
+
+const FSCONFIG_SET_FLAG: u32 = 0;
+const FSCONFIG_SET_STRING: u32 = 1;
+const FSCONFIG_SET_BINARY: u32 = 2;
+const FSCONFIG_SET_PATH: u32 = 3;
+const FSCONFIG_SET_PATH_EMPTY: u32 = 4;
+const FSCONFIG_SET_FD: u32 = 5;
+const FSCONFIG_CMD_CREATE: u32 = 6;
+const FSCONFIG_CMD_RECONFIGURE: u32 = 7;
+
+/// `fsmount(fs_fd, flags, attr_flags)`—Creates a detached mount of the
+/// filesystem created in a filesystem context, to attach with
+/// [`move_mount`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/namespace.c
+#[inline]
+pub fn fsmount<Fd: AsFd>(
+    fs_fd: &Fd,
+    flags: FsMountFlags,
+    attr_flags: MountAttrFlags,
+) -> io::Result<OwnedFd> {
+    let fs_fd = fs_fd.as_fd();
+    imp::syscalls::fsmount(fs_fd, flags, attr_flags)
+}
+
+/// `move_mount(from_dirfd, from_path, to_dirfd, to_path, flags)`—Moves a
+/// mount, or attaches a detached mount.
+///
+/// With [`MoveMountFlags::F_EMPTY_PATH`] and an empty `from_path`, the
+/// mount moved is the one referred to by `from_dirfd`, such as one from
+/// [`fsmount`] or [`open_tree`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/namespace.c
+#[inline]
+pub fn move_mount<FromFd: AsFd, FromP: path::Arg, ToFd: AsFd, ToP: path::Arg>(
+    from_dirfd: &FromFd,
+    from_path: FromP,
+    to_dirfd: &ToFd,
+    to_path: ToP,
+    flags: MoveMountFlags,
+) -> io::Result<()> {
+    let from_dirfd = from_dirfd.as_fd();
+    let to_dirfd = to_dirfd.as_fd();
+    from_path.into_with_z_str(|from_path| {
+        to_path.into_with_z_str(|to_path| {
+            imp::syscalls::move_mount(from_dirfd, from_path, to_dirfd, to_path, flags)
+        })
+    })
+}
+
+/// `open_tree(dirfd, path, flags)`—Opens the mount at `path`, or with
+/// [`OpenTreeFlags::CLONE`], creates a detached copy of it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/namespace.c
+#[inline]
+pub fn open_tree<Fd: AsFd, P: path::Arg>(
+    dirfd: &Fd,
+    path: P,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| imp::syscalls::open_tree(dirfd, path, flags))
+}
+
+/// `mount_setattr(dirfd, path, flags, &attr, sizeof(attr))`—Changes the
+/// attributes of the mount at `path` (since Linux 5.12).
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn mount_setattr<Fd: AsFd, P: path::Arg>(
+    dirfd: &Fd,
+    path: P,
+    flags: MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| imp::syscalls::mount_setattr(dirfd, path, flags, attr))
+}
+
+/// `statmount(&req, buf.as_ptr(), buf.len(), 0)`—Reads information about
+/// the mount with ID `mnt_id` into `buf` (since Linux 6.8).
+///
+/// `mnt_id` is a unique mount ID, such as the `stx_mnt_id` field of a
+/// [`Statx`] queried with [`StatxFlags::MNT_ID_UNIQUE`], or an ID from
+/// [`listmount`]. `mask` selects the information to read; the strings in it
+/// are stored in `buf` after the fixed-size part, so `buf` should have room
+/// for them. If it doesn't, this fails with `EOVERFLOW`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/statmount.2.html
+/// [`Statx`]: crate::fs::Statx
+/// [`StatxFlags::MNT_ID_UNIQUE`]: crate::fs::StatxFlags::MNT_ID_UNIQUE
+#[inline]
+pub fn statmount(mnt_id: u64, mask: StatmountFlags, buf: &mut [u8]) -> io::Result<Statmount<'_>> {
+    imp::syscalls::statmount(mnt_id, mask, buf)?;
+    Ok(Statmount::new(buf))
+}
+
+/// `listmount(&req, buf.as_ptr(), buf.len(), flags)`—Reads the IDs of the
+/// child mounts of the mount with ID `mnt_id` into `buf` (since Linux 6.8).
+///
+/// Only IDs after `last_mnt_id` are read, so a list longer than `buf` may be
+/// read by passing the last ID read, or 0 to start from the beginning.
+/// Returns the number of IDs read. [`ChildMounts`] does this automatically.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listmount.2.html
+#[inline]
+pub fn listmount(
+    mnt_id: u64,
+    last_mnt_id: u64,
+    flags: ListmountFlags,
+    buf: &mut [u64],
+) -> io::Result<usize> {
+    imp::syscalls::listmount(mnt_id, last_mnt_id, flags, buf)
+}
+
+/// The size of the fixed-size part of `struct statmount`, after which the
+/// strings are stored.
+const STATMOUNT_SIZE: usize = 512;
+
+/// `struct statmount`—Information about a mount, read by [`statmount`].
+///
+/// Fields not requested in the mask, or not supported by the kernel, read as
+/// zero, or `None` for strings; [`Statmount::mask`] tells which were
+/// returned.
+#[derive(Clone, Copy)]
+pub struct Statmount<'buf> {
+    buf: &'buf [u8],
+}
+
+impl<'buf> Statmount<'buf> {
+    fn new(buf: &'buf [u8]) -> Self {
+        let size = Self { buf }.u32_at(0) as usize;
+        Self {
+            buf: &buf[..size.min(buf.len())],
+        }
+    }
+
+    fn u32_at(&self, offset: usize) -> u32 {
+        self.buf
+            .get(offset..offset + 4)
+            .map_or(0, |bytes| u32::from_ne_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn u64_at(&self, offset: usize) -> u64 {
+        self.buf
+            .get(offset..offset + 8)
+            .map_or(0, |bytes| u64::from_ne_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn str_at(&self, flag: StatmountFlags, offset: usize) -> Option<&'buf ZStr> {
+        if !self.mask().contains(flag) {
+            return None;
+        }
+        let start = STATMOUNT_SIZE + self.u32_at(offset) as usize;
+        let bytes = self.buf.get(start..)?;
+        let nul = bytes.iter().position(|x| *x == b'\0')?;
+        ZStr::from_bytes_with_nul(&bytes[..=nul]).ok()
+    }
+
+    /// The information which was returned.
+    #[inline]
+    pub fn mask(&self) -> StatmountFlags {
+        StatmountFlags::from_bits_truncate(self.u64_at(8))
+    }
+
+    /// The major number of the filesystem's device, with
+    /// [`StatmountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_dev_major(&self) -> u32 {
+        self.u32_at(16)
+    }
+
+    /// The minor number of the filesystem's device, with
+    /// [`StatmountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_dev_minor(&self) -> u32 {
+        self.u32_at(20)
+    }
+
+    /// The filesystem's magic number, with [`StatmountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_magic(&self) -> u64 {
+        self.u64_at(24)
+    }
+
+    /// The filesystem's `SB_*` flags, with [`StatmountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_flags(&self) -> u32 {
+        self.u32_at(32)
+    }
+
+    /// The filesystem type, with [`StatmountFlags::FS_TYPE`].
+    #[inline]
+    pub fn fs_type(&self) -> Option<&'buf ZStr> {
+        self.str_at(StatmountFlags::FS_TYPE, 36)
+    }
+
+    /// The unique mount ID, with [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_id(&self) -> u64 {
+        self.u64_at(40)
+    }
+
+    /// The unique mount ID of the parent mount, with
+    /// [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_parent_id(&self) -> u64 {
+        self.u64_at(48)
+    }
+
+    /// The mount ID as in `/proc/self/mountinfo`, with
+    /// [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_id_old(&self) -> u32 {
+        self.u32_at(56)
+    }
+
+    /// The parent mount ID as in `/proc/self/mountinfo`, with
+    /// [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_parent_id_old(&self) -> u32 {
+        self.u32_at(60)
+    }
+
+    /// The mount attributes, with [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_attr(&self) -> MountAttrFlags {
+        MountAttrFlags::from_bits_truncate(self.u64_at(64) as u32)
+    }
+
+    /// The propagation type, as [`MountFlags::SHARED`],
+    /// [`MountFlags::SLAVE`], [`MountFlags::PRIVATE`], and
+    /// [`MountFlags::UNBINDABLE`], with [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_propagation(&self) -> MountFlags {
+        MountFlags::from_bits_truncate(self.u64_at(72) as u32)
+    }
+
+    /// The ID of the peer group of a shared mount, with
+    /// [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_peer_group(&self) -> u64 {
+        self.u64_at(80)
+    }
+
+    /// The ID of the peer group a slave mount receives propagation from,
+    /// with [`StatmountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_master(&self) -> u64 {
+        self.u64_at(88)
+    }
+
+    /// The ID of the closest dominant peer group in the current mount
+    /// namespace, with [`StatmountFlags::PROPAGATE_FROM`].
+    #[inline]
+    pub fn propagate_from(&self) -> u64 {
+        self.u64_at(96)
+    }
+
+    /// The root of the mount, relative to the root of the filesystem, with
+    /// [`StatmountFlags::MNT_ROOT`].
+    #[inline]
+    pub fn mnt_root(&self) -> Option<&'buf ZStr> {
+        self.str_at(StatmountFlags::MNT_ROOT, 104)
+    }
+
+    /// The mount point, relative to the current root, with
+    /// [`StatmountFlags::MNT_POINT`].
+    #[inline]
+    pub fn mnt_point(&self) -> Option<&'buf ZStr> {
+        self.str_at(StatmountFlags::MNT_POINT, 108)
+    }
+
+    /// The ID of the mount namespace, with [`StatmountFlags::MNT_NS_ID`].
+    #[inline]
+    pub fn mnt_ns_id(&self) -> u64 {
+        self.u64_at(112)
+    }
+
+    /// The filesystem's comma-separated mount options, with
+    /// [`StatmountFlags::MNT_OPTS`].
+    #[inline]
+    pub fn mnt_opts(&self) -> Option<&'buf ZStr> {
+        self.str_at(StatmountFlags::MNT_OPTS, 4)
+    }
+
+    /// The filesystem subtype, such as for FUSE, with
+    /// [`StatmountFlags::FS_SUBTYPE`].
+    #[inline]
+    pub fn fs_subtype(&self) -> Option<&'buf ZStr> {
+        self.str_at(StatmountFlags::FS_SUBTYPE, 120)
+    }
+
+    /// The source of the filesystem, such as a device path, with
+    /// [`StatmountFlags::SB_SOURCE`].
+    #[inline]
+    pub fn sb_source(&self) -> Option<&'buf ZStr> {
+        self.str_at(StatmountFlags::SB_SOURCE, 124)
+    }
+}
+
+impl<'buf> fmt::Debug for Statmount<'buf> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Statmount")
+            .field("mask", &self.mask())
+            .field("mnt_id", &self.mnt_id())
+            .field("mnt_parent_id", &self.mnt_parent_id())
+            .field("fs_type", &self.fs_type())
+            .field("mnt_root", &self.mnt_root())
+            .field("mnt_point", &self.mnt_point())
+            .field("mnt_opts", &self.mnt_opts())
+            .field("mnt_propagation", &self.mnt_propagation())
+            .finish()
+    }
+}
+
+/// An iterator over the IDs of the child mounts of a mount, reading them
+/// with [`listmount`] in batches into a caller-provided buffer.
+#[derive(Debug)]
+pub struct ChildMounts<'buf> {
+    mnt_id: u64,
+    flags: ListmountFlags,
+    buf: &'buf mut [u64],
+    pos: usize,
+    len: usize,
+    done: bool,
+}
+
+impl<'buf> ChildMounts<'buf> {
+    /// Iterate over the child mounts of the mount with ID `mnt_id`, or of
+    /// the root of the current mount namespace with [`LSMT_ROOT`].
+    ///
+    /// `buf` must not be empty.
+    #[inline]
+    pub fn new(mnt_id: u64, flags: ListmountFlags, buf: &'buf mut [u64]) -> Self {
+        Self {
+            mnt_id,
+            flags,
+            buf,
+            pos: 0,
+            len: 0,
+            done: false,
+        }
+    }
+}
+
+impl<'buf> Iterator for ChildMounts<'buf> {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.len {
+            if self.done {
+                return None;
+            }
+            let last_mnt_id = if self.len == 0 {
+                0
+            } else {
+                self.buf[self.len - 1]
+            };
+            match listmount(self.mnt_id, last_mnt_id, self.flags, self.buf) {
+                Ok(len) => {
+                    // A short read means there are no more IDs to read.
+                    self.done = len < self.buf.len();
+                    self.pos = 0;
+                    self.len = len;
+                    if len == 0 {
+                        return None;
+                    }
+                }
+                Err(err) => {
+                    self.done = true;
+                    self.pos = 0;
+                    self.len = 0;
+                    return Some(Err(err));
+                }
+            }
+        }
+        let mnt_id = self.buf[self.pos];
+        self.pos += 1;
+        Some(Ok(mnt_id))
+    }
+}
@@ -0,0 +1,337 @@
+use crate::ffi::ZStr;
+use crate::fs::AtFlags;
+use crate::imp::fd::AsFd;
+use crate::{imp, io, path};
+
+pub use imp::fs::XattrFlags;
+
+/// `getxattr(path, name, value.as_ptr(), value.len())`—Reads the value of
+/// the extended attribute `name` of the file at `path`.
+///
+/// Returns the size of the value. If `value` is empty, nothing is read, and
+/// the size needed to hold the value is returned.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[inline]
+pub fn getxattr<P: path::Arg, Name: path::Arg>(
+    path: P,
+    name: Name,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::getxattr(path, name, value))
+    })
+}
+
+/// `lgetxattr(path, name, value.as_ptr(), value.len())`—Like [`getxattr`],
+/// but doesn't follow a symlink at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/lgetxattr.2.html
+#[inline]
+pub fn lgetxattr<P: path::Arg, Name: path::Arg>(
+    path: P,
+    name: Name,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::lgetxattr(path, name, value))
+    })
+}
+
+/// `fgetxattr(fd, name, value.as_ptr(), value.len())`—Like [`getxattr`],
+/// for the file referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fgetxattr.2.html
+#[inline]
+pub fn fgetxattr<Fd: AsFd, Name: path::Arg>(
+    fd: &Fd,
+    name: Name,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    name.into_with_z_str(|name| imp::syscalls::fgetxattr(fd, name, value))
+}
+
+/// `getxattrat(dirfd, path, at_flags, name, &args, sizeof(args))`—Like
+/// [`getxattr`], for the file at `path` relative to `dirfd` (since Linux
+/// 6.13).
+///
+/// `at_flags` may contain [`AtFlags::SYMLINK_NOFOLLOW`] and
+/// [`AtFlags::EMPTY_PATH`].
+///
+/// On older kernels, this fails with `ENOSYS`. The path and fd variants such
+/// as [`getxattr`] always use their own syscalls instead of trying the `*at`
+/// ones first, since those work on every kernel, and seccomp filters may
+/// reject unknown syscalls with an error other than `ENOSYS`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[inline]
+pub fn getxattrat<DirFd: AsFd, P: path::Arg, Name: path::Arg>(
+    dirfd: &DirFd,
+    path: P,
+    at_flags: AtFlags,
+    name: Name,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::getxattrat(dirfd, path, at_flags, name, value))
+    })
+}
+
+/// `setxattr(path, name, value.as_ptr(), value.len(), flags)`—Sets the
+/// value of the extended attribute `name` of the file at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setxattr.2.html
+#[inline]
+pub fn setxattr<P: path::Arg, Name: path::Arg>(
+    path: P,
+    name: Name,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::setxattr(path, name, value, flags))
+    })
+}
+
+/// `lsetxattr(path, name, value.as_ptr(), value.len(), flags)`—Like
+/// [`setxattr`], but doesn't follow a symlink at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/lsetxattr.2.html
+#[inline]
+pub fn lsetxattr<P: path::Arg, Name: path::Arg>(
+    path: P,
+    name: Name,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::lsetxattr(path, name, value, flags))
+    })
+}
+
+/// `fsetxattr(fd, name, value.as_ptr(), value.len(), flags)`—Like
+/// [`setxattr`], for the file referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fsetxattr.2.html
+#[inline]
+pub fn fsetxattr<Fd: AsFd, Name: path::Arg>(
+    fd: &Fd,
+    name: Name,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    name.into_with_z_str(|name| imp::syscalls::fsetxattr(fd, name, value, flags))
+}
+
+/// `setxattrat(dirfd, path, at_flags, name, &args, sizeof(args))`—Like
+/// [`setxattr`], for the file at `path` relative to `dirfd` (since Linux
+/// 6.13).
+///
+/// `at_flags` may contain [`AtFlags::SYMLINK_NOFOLLOW`] and
+/// [`AtFlags::EMPTY_PATH`].
+///
+/// On older kernels, this fails with `ENOSYS`; see [`getxattrat`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setxattr.2.html
+#[inline]
+pub fn setxattrat<DirFd: AsFd, P: path::Arg, Name: path::Arg>(
+    dirfd: &DirFd,
+    path: P,
+    at_flags: AtFlags,
+    name: Name,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| {
+            imp::syscalls::setxattrat(dirfd, path, at_flags, name, value, flags)
+        })
+    })
+}
+
+/// `listxattr(path, list.as_ptr(), list.len())`—Reads the names of the
+/// extended attributes of the file at `path`.
+///
+/// Returns the size of the list, which may be iterated over with
+/// [`XattrNames`]. If `list` is empty, nothing is read, and the size needed
+/// to hold the list is returned.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[inline]
+pub fn listxattr<P: path::Arg>(path: P, list: &mut [u8]) -> io::Result<usize> {
+    path.into_with_z_str(|path| imp::syscalls::listxattr(path, list))
+}
+
+/// `llistxattr(path, list.as_ptr(), list.len())`—Like [`listxattr`], but
+/// doesn't follow a symlink at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/llistxattr.2.html
+#[inline]
+pub fn llistxattr<P: path::Arg>(path: P, list: &mut [u8]) -> io::Result<usize> {
+    path.into_with_z_str(|path| imp::syscalls::llistxattr(path, list))
+}
+
+/// `flistxattr(fd, list.as_ptr(), list.len())`—Like [`listxattr`], for the
+/// file referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/flistxattr.2.html
+#[inline]
+pub fn flistxattr<Fd: AsFd>(fd: &Fd, list: &mut [u8]) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    imp::syscalls::flistxattr(fd, list)
+}
+
+/// `listxattrat(dirfd, path, at_flags, list.as_ptr(), list.len())`—Like
+/// [`listxattr`], for the file at `path` relative to `dirfd` (since Linux
+/// 6.13).
+///
+/// `at_flags` may contain [`AtFlags::SYMLINK_NOFOLLOW`] and
+/// [`AtFlags::EMPTY_PATH`].
+///
+/// On older kernels, this fails with `ENOSYS`; see [`getxattrat`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[inline]
+pub fn listxattrat<DirFd: AsFd, P: path::Arg>(
+    dirfd: &DirFd,
+    path: P,
+    at_flags: AtFlags,
+    list: &mut [u8],
+) -> io::Result<usize> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| imp::syscalls::listxattrat(dirfd, path, at_flags, list))
+}
+
+/// `removexattr(path, name)`—Removes the extended attribute `name` of the
+/// file at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/removexattr.2.html
+#[inline]
+pub fn removexattr<P: path::Arg, Name: path::Arg>(path: P, name: Name) -> io::Result<()> {
+    path.into_with_z_str(|path| name.into_with_z_str(|name| imp::syscalls::removexattr(path, name)))
+}
+
+/// `lremovexattr(path, name)`—Like [`removexattr`], but doesn't follow a
+/// symlink at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/lremovexattr.2.html
+#[inline]
+pub fn lremovexattr<P: path::Arg, Name: path::Arg>(path: P, name: Name) -> io::Result<()> {
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::lremovexattr(path, name))
+    })
+}
+
+/// `fremovexattr(fd, name)`—Like [`removexattr`], for the file referred to
+/// by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fremovexattr.2.html
+#[inline]
+pub fn fremovexattr<Fd: AsFd, Name: path::Arg>(fd: &Fd, name: Name) -> io::Result<()> {
+    let fd = fd.as_fd();
+    name.into_with_z_str(|name| imp::syscalls::fremovexattr(fd, name))
+}
+
+/// `removexattrat(dirfd, path, at_flags, name)`—Like [`removexattr`], for
+/// the file at `path` relative to `dirfd` (since Linux 6.13).
+///
+/// `at_flags` may contain [`AtFlags::SYMLINK_NOFOLLOW`] and
+/// [`AtFlags::EMPTY_PATH`].
+///
+/// On older kernels, this fails with `ENOSYS`; see [`getxattrat`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/removexattr.2.html
+#[inline]
+pub fn removexattrat<DirFd: AsFd, P: path::Arg, Name: path::Arg>(
+    dirfd: &DirFd,
+    path: P,
+    at_flags: AtFlags,
+    name: Name,
+) -> io::Result<()> {
+    let dirfd = dirfd.as_fd();
+    path.into_with_z_str(|path| {
+        name.into_with_z_str(|name| imp::syscalls::removexattrat(dirfd, path, at_flags, name))
+    })
+}
+
+/// An iterator over the NUL-terminated names in a list read by
+/// [`listxattr`] and its variants.
+///
+/// Iteration stops at the end of the list, or at a name which isn't
+/// NUL-terminated.
+#[derive(Debug, Clone)]
+pub struct XattrNames<'a> {
+    list: &'a [u8],
+}
+
+impl<'a> XattrNames<'a> {
+    /// Iterate over the names in `list`, which should be the part of the
+    /// buffer filled by [`listxattr`].
+    #[inline]
+    pub fn new(list: &'a [u8]) -> Self {
+        Self { list }
+    }
+}
+
+impl<'a> Iterator for XattrNames<'a> {
+    type Item = &'a ZStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let nul = self.list.iter().position(|x| *x == b'\0')?;
+        let (name, rest) = self.list.split_at(nul + 1);
+        self.list = rest;
+        Some(ZStr::from_bytes_with_nul(name).unwrap())
+    }
+}
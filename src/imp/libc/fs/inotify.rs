@@ -0,0 +1,75 @@
+//! inotify flags.
+
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `IN_*` constants for use with [`inotify_init`].
+    ///
+    /// [`inotify_init`]: crate::fs::inotify::inotify_init
+    pub struct CreateFlags: c::c_int {
+        /// `IN_CLOEXEC`
+        const CLOEXEC = c::IN_CLOEXEC;
+        /// `IN_NONBLOCK`
+        const NONBLOCK = c::IN_NONBLOCK;
+    }
+}
+
+bitflags! {
+    /// `IN_*` constants for use with [`inotify_add_watch`], and reported in
+    /// inotify events.
+    ///
+    /// [`inotify_add_watch`]: crate::fs::inotify::inotify_add_watch
+    pub struct WatchFlags: u32 {
+        /// `IN_ACCESS`
+        const ACCESS = c::IN_ACCESS;
+        /// `IN_ATTRIB`
+        const ATTRIB = c::IN_ATTRIB;
+        /// `IN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = c::IN_CLOSE_NOWRITE;
+        /// `IN_CLOSE_WRITE`
+        const CLOSE_WRITE = c::IN_CLOSE_WRITE;
+        /// `IN_CREATE`
+        const CREATE = c::IN_CREATE;
+        /// `IN_DELETE`
+        const DELETE = c::IN_DELETE;
+        /// `IN_DELETE_SELF`
+        const DELETE_SELF = c::IN_DELETE_SELF;
+        /// `IN_MODIFY`
+        const MODIFY = c::IN_MODIFY;
+        /// `IN_MOVE_SELF`
+        const MOVE_SELF = c::IN_MOVE_SELF;
+        /// `IN_MOVED_FROM`
+        const MOVED_FROM = c::IN_MOVED_FROM;
+        /// `IN_MOVED_TO`
+        const MOVED_TO = c::IN_MOVED_TO;
+        /// `IN_OPEN`
+        const OPEN = c::IN_OPEN;
+        /// `IN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits | Self::CLOSE_NOWRITE.bits;
+        /// `IN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits | Self::MOVED_TO.bits;
+        /// `IN_ALL_EVENTS`
+        const ALL_EVENTS = Self::ACCESS.bits | Self::ATTRIB.bits | Self::CLOSE_NOWRITE.bits | Self::CLOSE_WRITE.bits | Self::CREATE.bits | Self::DELETE.bits | Self::DELETE_SELF.bits | Self::MODIFY.bits | Self::MOVE_SELF.bits | Self::MOVED_FROM.bits | Self::MOVED_TO.bits | Self::OPEN.bits;
+        /// `IN_DONT_FOLLOW`
+        const DONT_FOLLOW = c::IN_DONT_FOLLOW;
+        /// `IN_EXCL_UNLINK` (since Linux 2.6.36)
+        const EXCL_UNLINK = c::IN_EXCL_UNLINK;
+        /// `IN_MASK_ADD`
+        const MASK_ADD = c::IN_MASK_ADD;
+        /// `IN_MASK_CREATE` (since Linux 4.18)
+        const MASK_CREATE = c::IN_MASK_CREATE;
+        /// `IN_ONESHOT`
+        const ONESHOT = c::IN_ONESHOT;
+        /// `IN_ONLYDIR`
+        const ONLYDIR = c::IN_ONLYDIR;
+        /// `IN_IGNORED`
+        const IGNORED = c::IN_IGNORED;
+        /// `IN_ISDIR`
+        const ISDIR = c::IN_ISDIR;
+        /// `IN_Q_OVERFLOW`
+        const Q_OVERFLOW = c::IN_Q_OVERFLOW;
+        /// `IN_UNMOUNT`
+        const UNMOUNT = c::IN_UNMOUNT;
+    }
+}
@@ -3,6 +3,8 @@ pub(crate) mod syscalls;
 
 #[cfg(not(target_os = "redox"))]
 mod dir;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) mod inotify;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "ios",
@@ -50,6 +52,6 @@ pub use types::{Access, Dev, FdFlags, FileType, Mode, OFlags, RawMode, Stat};
 #[cfg(not(target_os = "redox"))]
 pub use types::{AtFlags, UTIME_NOW, UTIME_OMIT};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use types::{FsWord, MemfdFlags, RenameFlags, ResolveFlags, PROC_SUPER_MAGIC};
+pub use types::{FsWord, MemfdFlags, RenameFlags, ResolveFlags, XattrFlags, PROC_SUPER_MAGIC};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 pub use types::{Statx, StatxFlags};
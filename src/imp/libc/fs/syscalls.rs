@@ -45,6 +45,8 @@ use super::super::offset::libc_posix_fadvise;
 use super::super::offset::libc_posix_fallocate;
 use super::super::offset::{libc_fstat, libc_fstatat, libc_ftruncate, libc_lseek, libc_off_t};
 use super::super::time::Timespec;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::inotify::{CreateFlags as InotifyFlags, WatchFlags as InotifyWatchFlags};
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "ios",
@@ -70,19 +72,28 @@ use super::Dev;
 use super::FallocateFlags;
 #[cfg(not(target_os = "wasi"))]
 use super::FlockOperation;
-#[cfg(any(target_os = "android", target_os = "linux"))]
-use super::MemfdFlags;
 #[cfg(not(any(target_os = "netbsd", target_os = "redox", target_os = "wasi")))]
 // not implemented in libc for netbsd yet
 use super::StatFs;
 use super::{Access, FdFlags, Mode, OFlags, Stat};
 #[cfg(any(target_os = "android", target_os = "linux"))]
+use super::{MemfdFlags, XattrFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 use super::{RenameFlags, ResolveFlags};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 use super::{Statx, StatxFlags};
 use crate::ffi::ZStr;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 use crate::ffi::ZString;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fs::fanotify::{
+    EventMask as FanotifyEventMask, InitFlags as FanotifyInitFlags, MarkFlags as FanotifyMarkFlags,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fs::mount::{
+    FsMountFlags, FsOpenFlags, FsPickFlags, ListmountFlags, MountAttr, MountAttrFlags, MountFlags,
+    MountSetattrFlags, MoveMountFlags, OpenTreeFlags, StatmountFlags, UnmountFlags,
+};
 use crate::io::{self, OwnedFd, SeekFrom};
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Gid, Uid};
@@ -670,6 +681,572 @@ pub(crate) fn memfd_create(path: &ZStr, flags: MemfdFlags) -> io::Result<OwnedFd
     unsafe { syscall_ret_owned_fd(c::syscall(c::SYS_memfd_create, c_str(path), flags.bits())) }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn inotify_init1(flags: InotifyFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::inotify_init1(flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn inotify_add_watch(
+    inot: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: InotifyWatchFlags,
+) -> io::Result<i32> {
+    unsafe {
+        ret_c_int(c::inotify_add_watch(
+            borrowed_fd(inot),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn inotify_rm_watch(inot: BorrowedFd<'_>, wd: i32) -> io::Result<()> {
+    unsafe { ret(c::inotify_rm_watch(borrowed_fd(inot), wd as _)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fanotify_init(flags: FanotifyInitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    extern "C" {
+        fn fanotify_init(flags: c::c_uint, event_f_flags: c::c_uint) -> c::c_int;
+    }
+
+    unsafe { ret_owned_fd(fanotify_init(flags.bits(), event_flags.bits() as c::c_uint)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fanotify_mark(
+    fanotify: BorrowedFd<'_>,
+    flags: FanotifyMarkFlags,
+    mask: FanotifyEventMask,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&ZStr>,
+) -> io::Result<()> {
+    extern "C" {
+        fn fanotify_mark(
+            fanotify_fd: c::c_int,
+            flags: c::c_uint,
+            mask: u64,
+            dirfd: c::c_int,
+            pathname: *const c::c_char,
+        ) -> c::c_int;
+    }
+
+    unsafe {
+        ret(fanotify_mark(
+            borrowed_fd(fanotify),
+            flags.bits(),
+            mask.bits(),
+            borrowed_fd(dirfd),
+            path.map_or(core::ptr::null(), c_str),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn getxattr(path: &ZStr, name: &ZStr, value: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(c::getxattr(
+            c_str(path),
+            c_str(name),
+            value.as_mut_ptr().cast::<c::c_void>(),
+            value.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn lgetxattr(path: &ZStr, name: &ZStr, value: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(c::lgetxattr(
+            c_str(path),
+            c_str(name),
+            value.as_mut_ptr().cast::<c::c_void>(),
+            value.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &ZStr, value: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(c::fgetxattr(
+            borrowed_fd(fd),
+            c_str(name),
+            value.as_mut_ptr().cast::<c::c_void>(),
+            value.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn setxattr(
+    path: &ZStr,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::setxattr(
+            c_str(path),
+            c_str(name),
+            value.as_ptr().cast::<c::c_void>(),
+            value.len(),
+            flags.bits() as c::c_int,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn lsetxattr(
+    path: &ZStr,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::lsetxattr(
+            c_str(path),
+            c_str(name),
+            value.as_ptr().cast::<c::c_void>(),
+            value.len(),
+            flags.bits() as c::c_int,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsetxattr(
+    fd: BorrowedFd<'_>,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::fsetxattr(
+            borrowed_fd(fd),
+            c_str(name),
+            value.as_ptr().cast::<c::c_void>(),
+            value.len(),
+            flags.bits() as c::c_int,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn listxattr(path: &ZStr, list: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(c::listxattr(
+            c_str(path),
+            list.as_mut_ptr().cast::<c::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn llistxattr(path: &ZStr, list: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(c::llistxattr(
+            c_str(path),
+            list.as_mut_ptr().cast::<c::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        ret_ssize_t(c::flistxattr(
+            borrowed_fd(fd),
+            list.as_mut_ptr().cast::<c::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn removexattr(path: &ZStr, name: &ZStr) -> io::Result<()> {
+    unsafe { ret(c::removexattr(c_str(path), c_str(name))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn lremovexattr(path: &ZStr, name: &ZStr) -> io::Result<()> {
+    unsafe { ret(c::lremovexattr(c_str(path), c_str(name))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fremovexattr(fd: BorrowedFd<'_>, name: &ZStr) -> io::Result<()> {
+    unsafe { ret(c::fremovexattr(borrowed_fd(fd), c_str(name))) }
+}
+
+// Syscalls added since Linux 5.1 are numbered from a table shared by all
+// architectures, offset by the syscall base of the ABI on mips.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(any(target_arch = "mips", target_arch = "mips64"))
+))]
+const SYS_BASE: c::c_long = 0;
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_arch = "mips"))]
+const SYS_BASE: c::c_long = 4000;
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    target_arch = "mips64",
+    target_pointer_width = "64"
+))]
+const SYS_BASE: c::c_long = 5000;
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    target_arch = "mips64",
+    target_pointer_width = "32"
+))]
+const SYS_BASE: c::c_long = 6000;
+
+// The `*xattrat` syscalls were added in Linux 6.13.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_SETXATTRAT: c::c_long = SYS_BASE + 463;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_GETXATTRAT: c::c_long = SYS_BASE + 464;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_LISTXATTRAT: c::c_long = SYS_BASE + 465;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_REMOVEXATTRAT: c::c_long = SYS_BASE + 466;
+
+/// `struct xattr_args`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct XattrArgs {
+    value: u64,
+    size: u32,
+    flags: u32,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn getxattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    name: &ZStr,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    let args = XattrArgs {
+        value: value.as_mut_ptr() as usize as u64,
+        size: value.len().try_into().unwrap_or(u32::MAX),
+        flags: 0,
+    };
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            SYS_GETXATTRAT,
+            borrowed_fd(dirfd),
+            c_str(path),
+            at_flags.bits(),
+            c_str(name),
+            &args,
+            size_of::<XattrArgs>(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn setxattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let args = XattrArgs {
+        value: value.as_ptr() as usize as u64,
+        size: value.len().try_into().map_err(|_| io::Error::TOOBIG)?,
+        flags: flags.bits(),
+    };
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_SETXATTRAT,
+            borrowed_fd(dirfd),
+            c_str(path),
+            at_flags.bits(),
+            c_str(name),
+            &args,
+            size_of::<XattrArgs>(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn listxattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    list: &mut [u8],
+) -> io::Result<usize> {
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            SYS_LISTXATTRAT,
+            borrowed_fd(dirfd),
+            c_str(path),
+            at_flags.bits(),
+            list.as_mut_ptr().cast::<c::c_char>(),
+            list.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn removexattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    name: &ZStr,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_REMOVEXATTRAT,
+            borrowed_fd(dirfd),
+            c_str(path),
+            at_flags.bits(),
+            c_str(name),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn mount(
+    source: &ZStr,
+    target: &ZStr,
+    file_system_type: &ZStr,
+    flags: MountFlags,
+    data: Option<&ZStr>,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::mount(
+            c_str(source),
+            c_str(target),
+            c_str(file_system_type),
+            c::c_ulong::from(flags.bits()),
+            data.map_or(core::ptr::null(), |data| data.as_ptr().cast()),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn umount2(target: &ZStr, flags: UnmountFlags) -> io::Result<()> {
+    unsafe { ret(c::umount2(c_str(target), flags.bits() as c::c_int)) }
+}
+
+// The new mount API syscalls; see `SYS_BASE`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_OPEN_TREE: c::c_long = SYS_BASE + 428;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_MOVE_MOUNT: c::c_long = SYS_BASE + 429;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_FSOPEN: c::c_long = SYS_BASE + 430;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_FSCONFIG: c::c_long = SYS_BASE + 431;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_FSMOUNT: c::c_long = SYS_BASE + 432;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_FSPICK: c::c_long = SYS_BASE + 433;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_MOUNT_SETATTR: c::c_long = SYS_BASE + 442;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_STATMOUNT: c::c_long = SYS_BASE + 457;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_LISTMOUNT: c::c_long = SYS_BASE + 458;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsopen(fs_name: &ZStr, flags: FsOpenFlags) -> io::Result<OwnedFd> {
+    unsafe { syscall_ret_owned_fd(c::syscall(SYS_FSOPEN, c_str(fs_name), flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fspick(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: FsPickFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            SYS_FSPICK,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn fsconfig(
+    fs_fd: BorrowedFd<'_>,
+    cmd: c::c_uint,
+    key: Option<&ZStr>,
+    value: *const c::c_void,
+    aux: c::c_int,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        SYS_FSCONFIG,
+        borrowed_fd(fs_fd),
+        cmd,
+        key.map_or(core::ptr::null(), c_str),
+        value,
+        aux,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsmount(
+    fs_fd: BorrowedFd<'_>,
+    flags: FsMountFlags,
+    attr_flags: MountAttrFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            SYS_FSMOUNT,
+            borrowed_fd(fs_fd),
+            flags.bits(),
+            attr_flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn move_mount(
+    from_dirfd: BorrowedFd<'_>,
+    from_path: &ZStr,
+    to_dirfd: BorrowedFd<'_>,
+    to_path: &ZStr,
+    flags: MoveMountFlags,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_MOVE_MOUNT,
+            borrowed_fd(from_dirfd),
+            c_str(from_path),
+            borrowed_fd(to_dirfd),
+            c_str(to_path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn open_tree(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            SYS_OPEN_TREE,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+/// `struct mount_attr`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct RawMountAttr {
+    attr_set: u64,
+    attr_clr: u64,
+    propagation: u64,
+    userns_fd: u64,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn mount_setattr(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let attr = RawMountAttr {
+        attr_set: u64::from(attr.attr_set.bits()),
+        attr_clr: u64::from(attr.attr_clr.bits()),
+        propagation: attr.propagation.map_or(0, |propagation| propagation as u64),
+        userns_fd: attr
+            .userns_fd
+            .map_or(0, |userns_fd| borrowed_fd(userns_fd) as u64),
+    };
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_MOUNT_SETATTR,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+            &attr,
+            size_of::<RawMountAttr>(),
+        ))
+    }
+}
+
+/// `struct mnt_id_req`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct MntIdReq {
+    size: u32,
+    spare: u32,
+    mnt_id: u64,
+    param: u64,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn statmount(mnt_id: u64, mask: StatmountFlags, buf: &mut [u8]) -> io::Result<()> {
+    let req = MntIdReq {
+        size: size_of::<MntIdReq>() as u32,
+        spare: 0,
+        mnt_id,
+        param: mask.bits(),
+    };
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_STATMOUNT,
+            &req,
+            buf.as_mut_ptr(),
+            buf.len(),
+            0,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn listmount(
+    mnt_id: u64,
+    last_mnt_id: u64,
+    flags: ListmountFlags,
+    buf: &mut [u64],
+) -> io::Result<usize> {
+    let req = MntIdReq {
+        size: size_of::<MntIdReq>() as u32,
+        spare: 0,
+        mnt_id,
+        param: last_mnt_id,
+    };
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            SYS_LISTMOUNT,
+            &req,
+            buf.as_mut_ptr(),
+            buf.len(),
+            flags.bits(),
+        ))
+        .map(|n| n as usize)
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn openat2(
     dirfd: BorrowedFd<'_>,
@@ -499,6 +499,20 @@ bitflags! {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `XATTR_*` constants for use with [`setxattr`].
+    ///
+    /// [`setxattr`]: crate::fs::setxattr
+    pub struct XattrFlags: c::c_uint {
+        /// `XATTR_CREATE`
+        const CREATE = c::XATTR_CREATE as c::c_uint;
+
+        /// `XATTR_REPLACE`
+        const REPLACE = c::XATTR_REPLACE as c::c_uint;
+    }
+}
+
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 bitflags! {
     /// `STATX_*` constants for use with [`statx`].
@@ -546,6 +560,12 @@ bitflags! {
 
         /// `STATX_ALL`
         const ALL = c::STATX_ALL;
+
+        /// `STATX_MNT_ID` (since Linux 5.8)
+        const MNT_ID = 0x1000;
+
+        /// `STATX_MNT_ID_UNIQUE` (since Linux 6.8)
+        const MNT_ID_UNIQUE = 0x4000;
     }
 }
 
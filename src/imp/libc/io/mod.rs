@@ -32,7 +32,12 @@ pub use types::PIPE_BUF;
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub use types::{DupFlags, MapFlags, MprotectFlags, ProtFlags, Tcflag, Termios, Winsize, ICANON};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use types::{EventfdFlags, MlockFlags, ReadWriteFlags, UserfaultfdFlags};
+pub use types::{
+    EventfdFlags, MlockFlags, OpenptFlags, ReadWriteFlags, SignalfdFlags, Termios2,
+    UserfaultfdFlags,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use types::{BOTHER, CBAUD, CIBAUD, IBSHIFT};
 
 #[cfg(not(windows))]
 use super::c;
@@ -1,6 +1,8 @@
 use super::super::c;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use super::super::conv::syscall_ret_owned_fd;
+#[cfg(target_os = "linux")]
+use super::super::conv::syscall_ret_u32;
 use super::super::conv::{
     borrowed_fd, no_fd, ret, ret_c_int, ret_discarded_fd, ret_owned_fd, ret_ssize_t,
 };
@@ -12,6 +14,8 @@ use super::super::offset::{libc_pread, libc_pwrite};
 use super::super::offset::{libc_preadv, libc_pwritev};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 use super::super::offset::{libc_preadv2, libc_pwritev2};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::process::RawSigSet;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::Advice as IoAdvice;
 #[cfg(target_os = "linux")]
@@ -22,12 +26,14 @@ use super::PollFd;
 #[cfg(not(target_os = "wasi"))]
 use super::{DupFlags, MapFlags, MprotectFlags, ProtFlags, Termios, Winsize};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use super::{EventfdFlags, UserfaultfdFlags};
+use super::{EventfdFlags, SignalfdFlags, Termios2, UserfaultfdFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use super::{MlockFlags, ReadWriteFlags};
+use super::{MlockFlags, OpenptFlags, ReadWriteFlags};
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 use crate::ffi::ZStr;
 use crate::io::{self, IoSlice, IoSliceMut, OwnedFd};
+#[cfg(target_os = "linux")]
+use crate::io_uring::{IoUringParams, IoringEnterFlags, IoringRegisterOp};
 use core::cmp::min;
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
@@ -308,6 +314,16 @@ pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd>
     unsafe { syscall_ret_owned_fd(c::syscall(c::SYS_eventfd2, initval, flags.bits())) }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn signalfd(mask: &RawSigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::signalfd(-1, mask, flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &RawSigSet) -> io::Result<()> {
+    unsafe { ret_discarded_fd(c::signalfd(borrowed_fd(fd), mask, 0)) }
+}
+
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn ioctl_fionread(fd: BorrowedFd<'_>) -> io::Result<u64> {
     let mut nread = MaybeUninit::<c::c_int>::uninit();
@@ -488,6 +504,55 @@ pub(crate) fn ioctl_tcgets(fd: BorrowedFd<'_>) -> io::Result<Termios> {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn ioctl_tcgets2(fd: BorrowedFd<'_>) -> io::Result<Termios2> {
+    let mut result = MaybeUninit::<Termios2>::uninit();
+    unsafe {
+        ret(c::ioctl(borrowed_fd(fd), c::TCGETS2, result.as_mut_ptr()))
+            .map(|()| result.assume_init())
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn ioctl_tcsets2(fd: BorrowedFd<'_>, termios: &Termios2) -> io::Result<()> {
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            c::TCSETS2,
+            termios as *const Termios2,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::posix_openpt(flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn grantpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(c::grantpt(borrowed_fd(fd))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn unlockpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(c::unlockpt(borrowed_fd(fd))) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn ioctl_tiocgptn(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    let mut result = MaybeUninit::<c::c_uint>::uninit();
+    unsafe {
+        ret(c::ioctl(borrowed_fd(fd), c::TIOCGPTN, result.as_mut_ptr()))
+            .map(|()| result.assume_init())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn ioctl_tiocgptpeer(fd: BorrowedFd<'_>, flags: OpenptFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::ioctl(borrowed_fd(fd), c::TIOCGPTPEER, flags.bits())) }
+}
+
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub(crate) fn ioctl_fioclex(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(c::ioctl(borrowed_fd(fd), c::FIOCLEX)) }
@@ -516,6 +581,33 @@ pub(crate) fn ioctl_tiocnxcl(fd: BorrowedFd) -> io::Result<()> {
     unsafe { ret(c::ioctl(borrowed_fd(fd), c::TIOCNXCL as _)) }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn ioctl_tiocswinsz(fd: BorrowedFd, winsize: &Winsize) -> io::Result<()> {
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            c::TIOCSWINSZ as _,
+            winsize as *const Winsize,
+        ))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn ioctl_tiocsctty(fd: BorrowedFd, steal: bool) -> io::Result<()> {
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            c::TIOCSCTTY as _,
+            c::c_int::from(steal),
+        ))
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn ioctl_tiocnotty(fd: BorrowedFd) -> io::Result<()> {
+    unsafe { ret(c::ioctl(borrowed_fd(fd), c::TIOCNOTTY as _)) }
+}
+
 /// # Safety
 ///
 /// `mmap` is primarily unsafe due to the `addr` parameter, as anything working
@@ -700,3 +792,50 @@ pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usiz
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     syscall_ret_owned_fd(c::syscall(c::SYS_userfaultfd, flags.bits()))
 }
+
+#[cfg(target_os = "linux")]
+pub(crate) fn io_uring_setup(entries: u32, params: &mut IoUringParams) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_io_uring_setup,
+            entries as usize,
+            params as *mut IoUringParams,
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) unsafe fn io_uring_register(
+    fd: BorrowedFd<'_>,
+    opcode: IoringRegisterOp,
+    arg: *const c::c_void,
+    nr_args: u32,
+) -> io::Result<u32> {
+    syscall_ret_u32(c::syscall(
+        c::SYS_io_uring_register,
+        borrowed_fd(fd) as usize,
+        opcode as usize,
+        arg,
+        nr_args as usize,
+    ))
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) unsafe fn io_uring_enter(
+    fd: BorrowedFd<'_>,
+    to_submit: u32,
+    min_complete: u32,
+    flags: IoringEnterFlags,
+    arg: *const c::c_void,
+    size: usize,
+) -> io::Result<u32> {
+    syscall_ret_u32(c::syscall(
+        c::SYS_io_uring_enter,
+        borrowed_fd(fd) as usize,
+        to_submit as usize,
+        min_complete as usize,
+        flags.bits() as usize,
+        arg,
+        size,
+    ))
+}
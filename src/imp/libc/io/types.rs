@@ -315,6 +315,35 @@ bitflags! {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// The `O_*` flags accepted by [`openpt`] and [`ioctl_tiocgptpeer`].
+    ///
+    /// [`openpt`]: crate::io::openpt
+    /// [`ioctl_tiocgptpeer`]: crate::io::ioctl_tiocgptpeer
+    pub struct OpenptFlags: c::c_int {
+        /// `O_RDWR`
+        const RDWR = c::O_RDWR;
+        /// `O_NOCTTY`
+        const NOCTTY = c::O_NOCTTY;
+        /// `O_CLOEXEC`
+        const CLOEXEC = c::O_CLOEXEC;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// The `SFD_*` flags accepted by [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::io::signalfd
+    pub struct SignalfdFlags: c::c_int {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = c::SFD_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = c::SFD_NONBLOCK;
+    }
+}
+
 /// `POSIX_MADV_*` constants for use with [`madvise`].
 ///
 /// [`madvise`]: crate::io::madvise
@@ -417,12 +446,20 @@ impl Advice {
     pub const DontNeed: Self = Self::Normal;
 }
 
-/// `struct termios` for use with [`ioctl_tcgets`].
+/// `struct termios` for use with [`tcgetattr`] and [`tcsetattr`].
 ///
-/// [`ioctl_tcgets`]: crate::io::ioctl_tcgets
+/// [`tcgetattr`]: crate::termios::tcgetattr
+/// [`tcsetattr`]: crate::termios::tcsetattr
 #[cfg(not(target_os = "wasi"))]
 pub type Termios = c::termios;
 
+/// `struct termios2` for use with [`ioctl_tcgets2`] and [`ioctl_tcsets2`].
+///
+/// [`ioctl_tcgets2`]: crate::io::ioctl_tcgets2
+/// [`ioctl_tcsets2`]: crate::io::ioctl_tcsets2
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub type Termios2 = c::termios2;
+
 /// `struct winsize` for use with [`ioctl_tiocgwinsz`].
 ///
 /// [`ioctl_tiocgwinsz`]: crate::io::ioctl_tiocgwinsz
@@ -438,6 +475,15 @@ pub type Tcflag = c::tcflag_t;
 #[cfg(not(target_os = "wasi"))]
 pub const ICANON: Tcflag = c::ICANON;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const BOTHER: Tcflag = c::BOTHER as _;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const CBAUD: Tcflag = c::CBAUD;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const CIBAUD: Tcflag = c::CIBAUD;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const IBSHIFT: Tcflag = c::IBSHIFT;
+
 /// `PIPE_BUF`—The maximum size of a write to a pipe guaranteed to be atomic.
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub const PIPE_BUF: usize = c::PIPE_BUF;
@@ -59,6 +59,8 @@ pub(crate) mod process;
 #[cfg(not(windows))]
 pub(crate) mod rand;
 pub(crate) mod syscalls;
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub(crate) mod termios;
 #[cfg(not(windows))]
 pub(crate) mod thread;
 #[cfg(not(windows))]
@@ -23,6 +23,67 @@ pub(crate) use c::{
     target_os = "dragonfly"
 ))]
 pub(crate) mod cpu_set;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) mod sig_set;
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) use c::{
+    SYS_bpf as __NR_bpf, SYS_brk as __NR_brk, SYS_chdir as __NR_chdir, SYS_chroot as __NR_chroot,
+    SYS_clone as __NR_clone, SYS_close as __NR_close, SYS_dup as __NR_dup, SYS_dup3 as __NR_dup3,
+    SYS_execve as __NR_execve, SYS_execveat as __NR_execveat, SYS_exit as __NR_exit,
+    SYS_exit_group as __NR_exit_group, SYS_fchdir as __NR_fchdir, SYS_fchmod as __NR_fchmod,
+    SYS_fchmodat as __NR_fchmodat, SYS_fchownat as __NR_fchownat, SYS_getpgid as __NR_getpgid,
+    SYS_getpid as __NR_getpid, SYS_getppid as __NR_getppid, SYS_getrandom as __NR_getrandom,
+    SYS_gettid as __NR_gettid, SYS_ioctl as __NR_ioctl, SYS_kcmp as __NR_kcmp,
+    SYS_keyctl as __NR_keyctl, SYS_kill as __NR_kill, SYS_madvise as __NR_madvise,
+    SYS_memfd_create as __NR_memfd_create, SYS_mkdirat as __NR_mkdirat, SYS_mount as __NR_mount,
+    SYS_mprotect as __NR_mprotect, SYS_mremap as __NR_mremap, SYS_munmap as __NR_munmap,
+    SYS_openat as __NR_openat, SYS_perf_event_open as __NR_perf_event_open,
+    SYS_personality as __NR_personality, SYS_pipe2 as __NR_pipe2,
+    SYS_pivot_root as __NR_pivot_root, SYS_prctl as __NR_prctl,
+    SYS_process_vm_readv as __NR_process_vm_readv, SYS_process_vm_writev as __NR_process_vm_writev,
+    SYS_ptrace as __NR_ptrace, SYS_read as __NR_read, SYS_readv as __NR_readv,
+    SYS_reboot as __NR_reboot, SYS_seccomp as __NR_seccomp, SYS_setns as __NR_setns,
+    SYS_tgkill as __NR_tgkill, SYS_unlinkat as __NR_unlinkat, SYS_unshare as __NR_unshare,
+    SYS_userfaultfd as __NR_userfaultfd, SYS_write as __NR_write, SYS_writev as __NR_writev,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use c::{
+    CLD_CONTINUED, CLD_DUMPED, CLD_EXITED, CLD_KILLED, CLD_STOPPED, CLD_TRAPPED, WEXITED, WNOWAIT,
+    WSTOPPED,
+};
+#[cfg(not(target_os = "wasi"))]
+pub(crate) use c::{
+    SIGABRT, SIGALRM, SIGBUS, SIGCHLD, SIGCONT, SIGFPE, SIGHUP, SIGILL, SIGINT, SIGIO, SIGKILL,
+    SIGPIPE, SIGPROF, SIGQUIT, SIGSEGV, SIGSTOP, SIGSYS, SIGTERM, SIGTRAP, SIGTSTP, SIGTTIN,
+    SIGTTOU, SIGURG, SIGUSR1, SIGUSR2, SIGVTALRM, SIGWINCH, SIGXCPU, SIGXFSZ,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use c::{SIGPWR, SIGSTKFLT};
+// x86 uses `socketcall` for these on older kernels.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) use c::{
+    SYS_accept4 as __NR_accept4, SYS_bind as __NR_bind, SYS_connect as __NR_connect,
+    SYS_listen as __NR_listen, SYS_recvfrom as __NR_recvfrom, SYS_recvmsg as __NR_recvmsg,
+    SYS_sendmsg as __NR_sendmsg, SYS_sendto as __NR_sendto, SYS_shutdown as __NR_shutdown,
+    SYS_socket as __NR_socket, SYS_socketpair as __NR_socketpair,
+};
 #[cfg(not(target_os = "wasi"))]
 pub(crate) use types::RawUname;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
@@ -35,7 +96,39 @@ pub use types::Resource;
 ))]
 pub(crate) use types::{raw_cpu_set_new, RawCpuSet, CPU_SETSIZE};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use types::{MembarrierCommand, RawCpuid};
+pub(crate) use types::{
+    raw_sig_set_new, sigrtmax, sigrtmin, RawSigSet, RawSiginfoChld, UserCapData, UserCapHeader,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use types::{
+    MembarrierCommand, NamespaceType, PidfdFlags, PidfdGetfdFlags, RawCpuid, RemoteIoVec,
+    SigmaskHow, UnshareFlags,
+};
 #[cfg(not(target_os = "wasi"))]
 pub use types::{RawGid, RawNonZeroPid, RawPid, RawUid, EXIT_SIGNALED_SIGABRT};
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub use types::{SeccompData, SeccompNotif, SeccompNotifResp};
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) use types::{
+    SeccompNotifAddfd, SECCOMP_IOCTL_NOTIF_ADDFD, SECCOMP_IOCTL_NOTIF_ID_VALID,
+    SECCOMP_IOCTL_NOTIF_RECV, SECCOMP_IOCTL_NOTIF_SEND,
+};
 pub use types::{EXIT_FAILURE, EXIT_SUCCESS};
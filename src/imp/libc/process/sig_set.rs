@@ -0,0 +1,53 @@
+use super::super::c;
+use super::{sigrtmax, RawSigSet};
+
+#[inline]
+pub(crate) fn sigaddset(sig_set: &mut RawSigSet, sig: c::c_int) {
+    assert!(
+        sig >= 1 && sig <= sigrtmax(),
+        "signal out of bounds: the signal max is {} but the signal is {}",
+        sigrtmax(),
+        sig
+    );
+    unsafe {
+        c::sigaddset(sig_set, sig);
+    }
+}
+
+#[inline]
+pub(crate) fn sigemptyset(sig_set: &mut RawSigSet) {
+    unsafe {
+        c::sigemptyset(sig_set);
+    }
+}
+
+#[inline]
+pub(crate) fn sigfillset(sig_set: &mut RawSigSet) {
+    unsafe {
+        c::sigfillset(sig_set);
+    }
+}
+
+#[inline]
+pub(crate) fn sigdelset(sig_set: &mut RawSigSet, sig: c::c_int) {
+    assert!(
+        sig >= 1 && sig <= sigrtmax(),
+        "signal out of bounds: the signal max is {} but the signal is {}",
+        sigrtmax(),
+        sig
+    );
+    unsafe {
+        c::sigdelset(sig_set, sig);
+    }
+}
+
+#[inline]
+pub(crate) fn sigismember(sig_set: &RawSigSet, sig: c::c_int) -> bool {
+    assert!(
+        sig >= 1 && sig <= sigrtmax(),
+        "signal out of bounds: the signal max is {} but the signal is {}",
+        sigrtmax(),
+        sig
+    );
+    unsafe { c::sigismember(sig_set, sig) == 1 }
+}
@@ -3,13 +3,17 @@ use super::super::c;
 use super::super::conv::borrowed_fd;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 use super::super::conv::ret_infallible;
+#[cfg(target_os = "linux")]
+use super::super::conv::ret_owned_fd;
 use super::super::conv::{c_str, ret, ret_c_int, ret_discarded_char_ptr};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use super::super::conv::{syscall_ret, syscall_ret_u32};
+use super::super::conv::{syscall_ret, syscall_ret_owned_fd, syscall_ret_ssize_t, syscall_ret_u32};
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use super::super::fd::BorrowedFd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::fd::RawFd;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
-use super::super::offset::libc_getrlimit;
+use super::super::offset::{libc_getrlimit, libc_setrlimit};
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 use super::super::offset::{libc_rlimit, LIBC_RLIM_INFINITY};
 #[cfg(any(
@@ -21,19 +25,34 @@ use super::super::offset::{libc_rlimit, LIBC_RLIM_INFINITY};
 use super::RawCpuSet;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 use super::Resource;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::{
+    NamespaceType, PidfdFlags, PidfdGetfdFlags, RawSigSet, RawSiginfoChld, RemoteIoVec, SigmaskHow,
+    UnshareFlags, UserCapData, UserCapHeader,
+};
 #[cfg(not(target_os = "wasi"))]
 use super::{RawNonZeroPid, RawPid, RawUname};
 use crate::ffi::ZStr;
 use crate::io;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::io::{IoSlice, IoSliceMut, OwnedFd};
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 use crate::process::Rlimit;
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::process::{Cpuid, MembarrierCommand, MembarrierQuery};
+use crate::process::{
+    Cpuid, MembarrierCommand, MembarrierQuery, Signal, WaitId, WaitidOptions, WaitidStatus,
+};
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Gid, Pid, Uid, WaitOptions, WaitStatus};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{ResGid, ResUid};
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use core::ptr::null;
+#[cfg(not(target_os = "wasi"))]
+use {super::super::fs::Mode, core::cmp};
 
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn chdir(path: &ZStr) -> io::Result<()> {
@@ -140,6 +159,116 @@ pub(crate) fn getppid() -> Option<Pid> {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[must_use]
+pub(crate) fn getresuid() -> ResUid {
+    let mut ruid = MaybeUninit::<c::uid_t>::uninit();
+    let mut euid = MaybeUninit::<c::uid_t>::uninit();
+    let mut suid = MaybeUninit::<c::uid_t>::uninit();
+    unsafe {
+        ret_infallible(c::getresuid(
+            ruid.as_mut_ptr(),
+            euid.as_mut_ptr(),
+            suid.as_mut_ptr(),
+        ));
+        ResUid {
+            ruid: Uid::from_raw(ruid.assume_init()),
+            euid: Uid::from_raw(euid.assume_init()),
+            suid: Uid::from_raw(suid.assume_init()),
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[must_use]
+pub(crate) fn getresgid() -> ResGid {
+    let mut rgid = MaybeUninit::<c::gid_t>::uninit();
+    let mut egid = MaybeUninit::<c::gid_t>::uninit();
+    let mut sgid = MaybeUninit::<c::gid_t>::uninit();
+    unsafe {
+        ret_infallible(c::getresgid(
+            rgid.as_mut_ptr(),
+            egid.as_mut_ptr(),
+            sgid.as_mut_ptr(),
+        ));
+        ResGid {
+            rgid: Gid::from_raw(rgid.assume_init()),
+            egid: Gid::from_raw(egid.assume_init()),
+            sgid: Gid::from_raw(sgid.assume_init()),
+        }
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
+    let len = cmp::min(buf.len(), c::c_int::MAX as usize) as c::c_int;
+    unsafe { ret_c_int(c::getgroups(len, buf.as_mut_ptr().cast::<c::gid_t>())).map(|n| n as usize) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn umask(mode: Mode) -> Mode {
+    // `umask` always succeeds.
+    unsafe { Mode::from_bits_truncate(c::umask(mode.bits())) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+#[must_use]
+pub(crate) fn getpgrp() -> Option<Pid> {
+    unsafe {
+        let pgid = c::getpgrp();
+        Pid::from_raw(pgid)
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let pgid = ret_c_int(c::getpgid(Pid::as_raw(pid)))?;
+        pid_from_raw(pgid)
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe { ret(c::setpgid(Pid::as_raw(pid), Pid::as_raw(pgid))) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let sid = ret_c_int(c::getsid(Pid::as_raw(pid)))?;
+        pid_from_raw(sid)
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn setsid() -> io::Result<Pid> {
+    unsafe {
+        let sid = ret_c_int(c::setsid())?;
+        pid_from_raw(sid)
+    }
+}
+
+/// Linux reports a process group or session ID of zero when it isn't visible
+/// in the caller's PID namespace. `Pid` can't represent zero, so fail with
+/// `OPNOTSUPP` in that case.
+#[cfg(not(target_os = "wasi"))]
+fn pid_from_raw(pid: c::pid_t) -> io::Result<Pid> {
+    match RawNonZeroPid::new(pid) {
+        Some(pid) => Ok(unsafe { Pid::from_raw_nonzero(pid) }),
+        None => Err(io::Error::OPNOTSUPP),
+    }
+}
+
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -275,21 +404,322 @@ pub(crate) fn getrlimit(limit: Resource) -> Rlimit {
     let mut result = MaybeUninit::<libc_rlimit>::uninit();
     unsafe {
         ret_infallible(libc_getrlimit(limit as _, result.as_mut_ptr()));
-        let result = result.assume_init();
-        let current = if result.rlim_cur == LIBC_RLIM_INFINITY {
-            None
-        } else {
-            result.rlim_cur.try_into().ok()
-        };
-        let maximum = if result.rlim_max == LIBC_RLIM_INFINITY {
-            None
-        } else {
-            result.rlim_max.try_into().ok()
-        };
-        Rlimit { current, maximum }
+        rlimit_from_libc(result.assume_init())
+    }
+}
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn setrlimit(limit: Resource, new: Rlimit) -> io::Result<()> {
+    let lim = rlimit_to_libc(new)?;
+    unsafe { ret(libc_setrlimit(limit as _, &lim)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn prlimit(
+    pid: Option<Pid>,
+    limit: Resource,
+    new: Option<Rlimit>,
+) -> io::Result<Rlimit> {
+    let lim = match new {
+        Some(new) => Some(rlimit_to_libc(new)?),
+        None => None,
+    };
+    let lim_ptr = match &lim {
+        Some(lim) => lim as *const c::rlimit64,
+        None => core::ptr::null(),
+    };
+    let mut result = MaybeUninit::<c::rlimit64>::uninit();
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_prlimit64,
+            Pid::as_raw(pid),
+            limit as c::c_int,
+            lim_ptr,
+            result.as_mut_ptr(),
+        ))?;
+        Ok(rlimit_from_libc(result.assume_init()))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn prctl(
+    option: c::c_int,
+    arg2: *mut c::c_void,
+    arg3: *mut c::c_void,
+    arg4: *mut c::c_void,
+    arg5: *mut c::c_void,
+) -> io::Result<usize> {
+    // Use `syscall` rather than the libc `prctl` wrapper, as the wrapper
+    // truncates the kernel's `long` return value to `int`.
+    syscall_ret_ssize_t(c::syscall(c::SYS_prctl, option, arg2, arg3, arg4, arg5))
+        .map(|r| r as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn capget(header: &mut UserCapHeader, data: &mut [UserCapData; 2]) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_capget,
+            header as *mut UserCapHeader,
+            data.as_mut_ptr(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn capset(header: &mut UserCapHeader, data: &[UserCapData; 2]) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_capset,
+            header as *mut UserCapHeader,
+            data.as_ptr(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn seccomp(
+    operation: c::c_uint,
+    flags: c::c_uint,
+    args: *mut c::c_void,
+) -> io::Result<c::c_int> {
+    syscall_ret_ssize_t(c::syscall(c::SYS_seccomp, operation, flags, args)).map(|r| r as c::c_int)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &[IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            c::SYS_process_vm_readv,
+            pid.as_raw_nonzero().get(),
+            local.as_ptr().cast::<c::iovec>(),
+            cmp::min(local.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+            remote.as_ptr().cast::<c::iovec>(),
+            cmp::min(remote.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+            0 as c::c_ulong,
+        ))
+        .map(|nread| nread as usize)
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            c::SYS_process_vm_writev,
+            pid.as_raw_nonzero().get(),
+            local.as_ptr().cast::<c::iovec>(),
+            cmp::min(local.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+            remote.as_ptr().cast::<c::iovec>(),
+            cmp::min(remote.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+            0 as c::c_ulong,
+        ))
+        .map(|nwritten| nwritten as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Option<Pid>,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<usize> {
+    // Call the syscall directly, rather than `c::ptrace`, so that the
+    // `PTRACE_PEEK*` requests store their result in `data` on all libcs.
+    syscall_ret_ssize_t(c::syscall(
+        c::SYS_ptrace,
+        c::c_long::from(request),
+        c::c_long::from(Pid::as_raw(pid)),
+        addr,
+        data,
+    ))
+    .map(|r| r as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn unshare(flags: UnshareFlags) -> io::Result<()> {
+    unsafe { ret(c::unshare(flags.bits() as c::c_int)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn setns(fd: BorrowedFd<'_>, nstype: NamespaceType) -> io::Result<()> {
+    unsafe { ret(c::setns(borrowed_fd(fd), nstype.bits() as c::c_int)) }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn ioctl_ns_get_userns(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::ioctl(borrowed_fd(fd), c::NS_GET_USERNS as _)) }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn ioctl_ns_get_parent(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::ioctl(borrowed_fd(fd), c::NS_GET_PARENT as _)) }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn ioctl_ns_get_nstype(fd: BorrowedFd<'_>) -> io::Result<NamespaceType> {
+    unsafe {
+        let nstype = ret_c_int(c::ioctl(borrowed_fd(fd), c::NS_GET_NSTYPE as _))?;
+        Ok(NamespaceType::from_bits_truncate(nstype as c::c_uint))
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn ioctl_ns_get_owner_uid(fd: BorrowedFd<'_>) -> io::Result<Uid> {
+    let mut uid = MaybeUninit::<c::uid_t>::uninit();
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            c::NS_GET_OWNER_UID as _,
+            uid.as_mut_ptr(),
+        ))?;
+        Ok(Uid::from_raw(uid.assume_init()))
+    }
+}
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) use seccomp_notif::*;
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+mod seccomp_notif {
+    use super::super::super::c;
+    use super::super::super::conv::{borrowed_fd, ret, ret_c_int};
+    use super::super::super::fd::{BorrowedFd, RawFd};
+    use super::super::{
+        SeccompNotif, SeccompNotifAddfd, SeccompNotifResp, SECCOMP_IOCTL_NOTIF_ADDFD,
+        SECCOMP_IOCTL_NOTIF_ID_VALID, SECCOMP_IOCTL_NOTIF_RECV, SECCOMP_IOCTL_NOTIF_SEND,
+    };
+    use crate::io;
+
+    #[inline]
+    pub(crate) fn seccomp_notif_recv(
+        fd: BorrowedFd<'_>,
+        notif: &mut SeccompNotif,
+    ) -> io::Result<()> {
+        unsafe {
+            ret(c::ioctl(
+                borrowed_fd(fd),
+                SECCOMP_IOCTL_NOTIF_RECV as _,
+                notif as *mut SeccompNotif,
+            ))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn seccomp_notif_send(
+        fd: BorrowedFd<'_>,
+        resp: &SeccompNotifResp,
+    ) -> io::Result<()> {
+        unsafe {
+            ret(c::ioctl(
+                borrowed_fd(fd),
+                SECCOMP_IOCTL_NOTIF_SEND as _,
+                resp as *const SeccompNotifResp,
+            ))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn seccomp_notif_id_valid(fd: BorrowedFd<'_>, id: u64) -> io::Result<()> {
+        unsafe {
+            ret(c::ioctl(
+                borrowed_fd(fd),
+                SECCOMP_IOCTL_NOTIF_ID_VALID as _,
+                &id as *const u64,
+            ))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn seccomp_notif_addfd(
+        fd: BorrowedFd<'_>,
+        addfd: &SeccompNotifAddfd,
+    ) -> io::Result<RawFd> {
+        unsafe {
+            ret_c_int(c::ioctl(
+                borrowed_fd(fd),
+                SECCOMP_IOCTL_NOTIF_ADDFD as _,
+                addfd as *const SeccompNotifAddfd,
+            ))
+        }
+    }
+}
+
+/// Convert a Rust [`Rlimit`] to a C `libc_rlimit`.
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+fn rlimit_to_libc(lim: Rlimit) -> io::Result<libc_rlimit> {
+    let current = match lim.current {
+        Some(r) => r.try_into().map_err(|_| io::Error::INVAL)?,
+        None => LIBC_RLIM_INFINITY as _,
+    };
+    let maximum = match lim.maximum {
+        Some(r) => r.try_into().map_err(|_| io::Error::INVAL)?,
+        None => LIBC_RLIM_INFINITY as _,
+    };
+    Ok(libc_rlimit {
+        rlim_cur: current,
+        rlim_max: maximum,
+    })
+}
+
+/// Convert a C `libc_rlimit` to a Rust [`Rlimit`].
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+fn rlimit_from_libc(lim: libc_rlimit) -> Rlimit {
+    let current = if lim.rlim_cur == LIBC_RLIM_INFINITY {
+        None
+    } else {
+        lim.rlim_cur.try_into().ok()
+    };
+    let maximum = if lim.rlim_max == LIBC_RLIM_INFINITY {
+        None
+    } else {
+        lim.rlim_max.try_into().ok()
+    };
+    Rlimit { current, maximum }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn wait(waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
@@ -336,3 +766,127 @@ pub(crate) fn exit_group(code: c::c_int) -> ! {
         libc::_exit(code)
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe { ret(c::kill(pid.as_raw_nonzero().get(), sig.as_raw())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe { ret(c::kill(-pid.as_raw_nonzero().get(), sig.as_raw())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn kill_current_process_group(sig: Signal) -> io::Result<()> {
+    unsafe { ret(c::kill(0, sig.as_raw())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_tgkill,
+            tgid.as_raw_nonzero().get(),
+            tid.as_raw_nonzero().get(),
+            sig.as_raw(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sigprocmask(
+    how: SigmaskHow,
+    set: Option<&RawSigSet>,
+    old: &mut RawSigSet,
+) -> io::Result<()> {
+    let set = set.map_or(core::ptr::null(), |set| set as *const RawSigSet);
+    unsafe { ret(c::sigprocmask(how as c::c_int, set, old)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn waitid(id: WaitId<'_>, options: WaitidOptions) -> io::Result<Option<WaitidStatus>> {
+    // Get the id to wait on.
+    match id {
+        WaitId::All => _waitid(c::P_ALL, 0, options),
+        WaitId::Pid(pid) => _waitid(c::P_PID, pid.as_raw_nonzero().get() as _, options),
+        WaitId::Pgid(pgid) => _waitid(c::P_PGID, Pid::as_raw(pgid) as _, options),
+        WaitId::PidFd(fd) => {
+            const P_PIDFD: c::idtype_t = 3;
+            _waitid(P_PIDFD, borrowed_fd(fd) as _, options)
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+fn _waitid(
+    idtype: c::idtype_t,
+    id: c::id_t,
+    options: WaitidOptions,
+) -> io::Result<Option<WaitidStatus>> {
+    // `waitid` can return successfully without initializing the struct (no
+    // children found when using `WNOHANG`)
+    let mut info = MaybeUninit::<c::siginfo_t>::zeroed();
+    unsafe {
+        ret(c::waitid(
+            idtype,
+            id,
+            info.as_mut_ptr(),
+            options.bits() as _,
+        ))?;
+        let info = &*info.as_ptr().cast::<RawSiginfoChld>();
+        Ok(RawNonZeroPid::new(info.si_pid).map(|non_zero| {
+            WaitidStatus::new(
+                info.si_code,
+                Pid::from_raw_nonzero(non_zero),
+                Uid::from_raw(info.si_uid),
+                info.si_status,
+            )
+        }))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_pidfd_open,
+            pid.as_raw_nonzero().get(),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn pidfd_send_signal(fd: BorrowedFd<'_>, sig: Signal) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_pidfd_send_signal,
+            borrowed_fd(fd),
+            sig.as_raw(),
+            null::<c::siginfo_t>(),
+            0,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn pidfd_getfd(
+    fd: BorrowedFd<'_>,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_pidfd_getfd,
+            borrowed_fd(fd),
+            targetfd,
+            flags.bits(),
+        ))
+    }
+}
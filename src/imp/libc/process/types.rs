@@ -1,4 +1,6 @@
 use super::super::c;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use bitflags::bitflags;
 
 /// A command for use with [`membarrier`] and [`membarrier_cpu`].
 ///
@@ -187,3 +189,330 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
     target_os = "dragonfly"
 ))]
 pub(crate) const CPU_SETSIZE: usize = c::CPU_SETSIZE as usize;
+
+/// A command for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(i32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    Block = c::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    Unblock = c::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    SetMask = c::SIG_SETMASK,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) type RawSigSet = c::sigset_t;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn raw_sig_set_new() -> RawSigSet {
+    let mut set = unsafe { core::mem::zeroed() };
+    super::sig_set::sigemptyset(&mut set);
+    set
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn sigrtmin() -> c::c_int {
+    extern "C" {
+        fn __libc_current_sigrtmin() -> c::c_int;
+    }
+
+    unsafe { __libc_current_sigrtmin() }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn sigrtmax() -> c::c_int {
+    extern "C" {
+        fn __libc_current_sigrtmax() -> c::c_int;
+    }
+
+    unsafe { __libc_current_sigrtmax() }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `PIDFD_*` flags for use with [`pidfd_open`].
+    ///
+    /// [`pidfd_open`]: crate::process::pidfd_open
+    pub struct PidfdFlags: c::c_uint {
+        /// `PIDFD_NONBLOCK`
+        const NONBLOCK = c::O_NONBLOCK as _;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// Flags for use with [`pidfd_getfd`].
+    ///
+    /// No flags are currently defined.
+    ///
+    /// [`pidfd_getfd`]: crate::process::pidfd_getfd
+    pub struct PidfdGetfdFlags: c::c_uint {}
+}
+
+/// The header of a `siginfo_t`, followed by the `SIGCHLD` fields filled in
+/// by `waitid`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+pub(crate) struct RawSiginfoChld {
+    pub(crate) si_signo: c::c_int,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    pub(crate) si_errno: c::c_int,
+    pub(crate) si_code: c::c_int,
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    pub(crate) si_errno: c::c_int,
+    // The union of fields following the header is pointer-aligned.
+    _align: [usize; 0],
+    pub(crate) si_pid: c::pid_t,
+    pub(crate) si_uid: c::uid_t,
+    pub(crate) si_status: c::c_int,
+}
+
+/// `struct iovec`—A range of memory in another process, for use with
+/// [`process_vm_readv`] and [`process_vm_writev`].
+///
+/// [`process_vm_readv`]: crate::process::process_vm_readv
+/// [`process_vm_writev`]: crate::process::process_vm_writev
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RemoteIoVec {
+    /// The address of the range, in the other process.
+    pub base: usize,
+    /// The length of the range, in bytes.
+    pub len: usize,
+}
+
+/// `struct __user_cap_header_struct`, for use with `capget` and `capset`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+pub(crate) struct UserCapHeader {
+    pub(crate) version: u32,
+    pub(crate) pid: c::c_int,
+}
+
+/// `struct __user_cap_data_struct`, for use with `capget` and `capset`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct UserCapData {
+    pub(crate) effective: u32,
+    pub(crate) permitted: u32,
+    pub(crate) inheritable: u32,
+}
+
+// TODO: Use `c::CLONE_NEWTIME` once upstream libc has it on Android.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const CLONE_NEWTIME: c::c_uint = 0x80;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `CLONE_*` flags for use with [`unshare`].
+    ///
+    /// [`unshare`]: crate::process::unshare
+    #[doc(alias = "CloneFlags")]
+    pub struct UnshareFlags: c::c_uint {
+        /// `CLONE_FILES`
+        const FILES = c::CLONE_FILES as _;
+        /// `CLONE_FS`
+        const FS = c::CLONE_FS as _;
+        /// `CLONE_NEWCGROUP` (since Linux 4.6)
+        const NEWCGROUP = c::CLONE_NEWCGROUP as _;
+        /// `CLONE_NEWIPC`
+        const NEWIPC = c::CLONE_NEWIPC as _;
+        /// `CLONE_NEWNET`
+        const NEWNET = c::CLONE_NEWNET as _;
+        /// `CLONE_NEWNS`
+        const NEWNS = c::CLONE_NEWNS as _;
+        /// `CLONE_NEWPID`
+        const NEWPID = c::CLONE_NEWPID as _;
+        /// `CLONE_NEWTIME` (since Linux 5.6)
+        const NEWTIME = CLONE_NEWTIME;
+        /// `CLONE_NEWUSER`
+        const NEWUSER = c::CLONE_NEWUSER as _;
+        /// `CLONE_NEWUTS`
+        const NEWUTS = c::CLONE_NEWUTS as _;
+        /// `CLONE_SYSVSEM`
+        const SYSVSEM = c::CLONE_SYSVSEM as _;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `CLONE_NEW*` namespace types, for use with [`setns`].
+    ///
+    /// [`setns`]: crate::process::setns
+    pub struct NamespaceType: c::c_uint {
+        /// `CLONE_NEWCGROUP` (since Linux 4.6)
+        const CGROUP = c::CLONE_NEWCGROUP as _;
+        /// `CLONE_NEWIPC`
+        const IPC = c::CLONE_NEWIPC as _;
+        /// `CLONE_NEWNET`
+        const NETWORK = c::CLONE_NEWNET as _;
+        /// `CLONE_NEWNS`
+        const MOUNT = c::CLONE_NEWNS as _;
+        /// `CLONE_NEWPID`
+        const PID = c::CLONE_NEWPID as _;
+        /// `CLONE_NEWTIME` (since Linux 5.6)
+        const TIME = CLONE_NEWTIME;
+        /// `CLONE_NEWUSER`
+        const USER = c::CLONE_NEWUSER as _;
+        /// `CLONE_NEWUTS`
+        const UTS = c::CLONE_NEWUTS as _;
+    }
+}
+
+/// `struct seccomp_data`—The syscall a filter or supervisor is examining.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeccompData {
+    /// The syscall number.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` value of the syscall's ABI.
+    pub arch: u32,
+    /// The instruction pointer at the time of the syscall.
+    pub instruction_pointer: u64,
+    /// The syscall arguments.
+    pub args: [u64; 6],
+}
+
+/// `struct seccomp_notif`—A notification received with
+/// [`seccomp_notif_recv`].
+///
+/// [`seccomp_notif_recv`]: crate::process::seccomp_notif_recv
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeccompNotif {
+    /// The notification's id, for use in the response.
+    pub id: u64,
+    /// The raw id of the thread which made the syscall, in the listener's PID
+    /// namespace, or 0 if it isn't visible there.
+    pub pid: u32,
+    /// Currently unused.
+    pub flags: u32,
+    /// The syscall being made.
+    pub data: SeccompData,
+}
+
+/// `struct seccomp_notif_resp`—A response sent with [`seccomp_notif_send`].
+///
+/// [`seccomp_notif_send`]: crate::process::seccomp_notif_send
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeccompNotifResp {
+    /// The id of the notification being responded to.
+    pub id: u64,
+    /// The syscall's return value, if `error` is 0.
+    pub val: i64,
+    /// The syscall's negated error number, or 0.
+    pub error: i32,
+    /// `SECCOMP_USER_NOTIF_FLAG_*` flags.
+    pub flags: u32,
+}
+
+/// `struct seccomp_notif_addfd`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+#[repr(C)]
+pub(crate) struct SeccompNotifAddfd {
+    pub(crate) id: u64,
+    pub(crate) flags: u32,
+    pub(crate) srcfd: u32,
+    pub(crate) newfd: u32,
+    pub(crate) newfd_flags: u32,
+}
+
+/// `SECCOMP_IOCTL_NOTIF_RECV`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) const SECCOMP_IOCTL_NOTIF_RECV: c::c_uint = 0xc050_2100;
+/// `SECCOMP_IOCTL_NOTIF_SEND`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) const SECCOMP_IOCTL_NOTIF_SEND: c::c_uint = 0xc018_2101;
+/// `SECCOMP_IOCTL_NOTIF_ID_VALID`, using the `_IOR` encoding from Linux 5.0
+/// which newer kernels still accept.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) const SECCOMP_IOCTL_NOTIF_ID_VALID: c::c_uint = 0x8008_2102;
+/// `SECCOMP_IOCTL_NOTIF_ADDFD`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) const SECCOMP_IOCTL_NOTIF_ADDFD: c::c_uint = 0x4018_2103;
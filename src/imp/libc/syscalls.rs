@@ -8,6 +8,8 @@ pub(crate) use super::io::syscalls::*;
 pub(crate) use super::net::syscalls::*;
 #[cfg(not(windows))]
 pub(crate) use super::process::syscalls::*;
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub(crate) use super::termios::syscalls::*;
 #[cfg(not(windows))]
 pub(crate) use super::thread::syscalls::*;
 #[cfg(not(windows))]
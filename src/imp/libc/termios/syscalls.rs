@@ -0,0 +1,89 @@
+use super::super::c;
+use super::super::conv::{borrowed_fd, ret, ret_c_int};
+use super::super::fd::BorrowedFd;
+use super::super::io::Termios;
+use super::super::process::RawNonZeroPid;
+use super::{Action, OptionalActions, QueueSelector, Speed};
+use crate::io;
+use crate::process::Pid;
+use core::mem::MaybeUninit;
+
+pub(crate) fn tcgetattr(fd: BorrowedFd<'_>) -> io::Result<Termios> {
+    let mut result = MaybeUninit::<Termios>::uninit();
+    unsafe {
+        ret(c::tcgetattr(borrowed_fd(fd), result.as_mut_ptr()))?;
+        Ok(result.assume_init())
+    }
+}
+
+pub(crate) fn tcsetattr(
+    fd: BorrowedFd<'_>,
+    optional_actions: OptionalActions,
+    termios: &Termios,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::tcsetattr(
+            borrowed_fd(fd),
+            optional_actions as _,
+            termios,
+        ))
+    }
+}
+
+pub(crate) fn tcsendbreak(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(c::tcsendbreak(borrowed_fd(fd), 0)) }
+}
+
+pub(crate) fn tcdrain(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(c::tcdrain(borrowed_fd(fd))) }
+}
+
+pub(crate) fn tcflush(fd: BorrowedFd<'_>, queue_selector: QueueSelector) -> io::Result<()> {
+    unsafe { ret(c::tcflush(borrowed_fd(fd), queue_selector as _)) }
+}
+
+pub(crate) fn tcflow(fd: BorrowedFd<'_>, action: Action) -> io::Result<()> {
+    unsafe { ret(c::tcflow(borrowed_fd(fd), action as _)) }
+}
+
+pub(crate) fn tcgetpgrp(fd: BorrowedFd<'_>) -> io::Result<Pid> {
+    let pid = unsafe { ret_c_int(c::tcgetpgrp(borrowed_fd(fd)))? };
+    pid_from_raw(pid)
+}
+
+pub(crate) fn tcsetpgrp(fd: BorrowedFd<'_>, pid: Pid) -> io::Result<()> {
+    unsafe { ret(c::tcsetpgrp(borrowed_fd(fd), pid.as_raw_nonzero().get())) }
+}
+
+pub(crate) fn tcgetsid(fd: BorrowedFd<'_>) -> io::Result<Pid> {
+    let pid = unsafe { ret_c_int(c::tcgetsid(borrowed_fd(fd)))? };
+    pid_from_raw(pid)
+}
+
+/// Linux reports a process group or session ID of zero when there is none,
+/// or when it isn't visible in the caller's PID namespace. `Pid` can't
+/// represent zero, so fail with `OPNOTSUPP` in that case.
+fn pid_from_raw(pid: c::pid_t) -> io::Result<Pid> {
+    match RawNonZeroPid::new(pid) {
+        Some(pid) => Ok(unsafe { Pid::from_raw_nonzero(pid) }),
+        None => Err(io::Error::OPNOTSUPP),
+    }
+}
+
+#[must_use]
+pub(crate) fn cfgetospeed(termios: &Termios) -> Speed {
+    unsafe { c::cfgetospeed(termios) }
+}
+
+#[must_use]
+pub(crate) fn cfgetispeed(termios: &Termios) -> Speed {
+    unsafe { c::cfgetispeed(termios) }
+}
+
+pub(crate) fn cfsetospeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    unsafe { ret(c::cfsetospeed(termios, speed)) }
+}
+
+pub(crate) fn cfsetispeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    unsafe { ret(c::cfsetispeed(termios, speed)) }
+}
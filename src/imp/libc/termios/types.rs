@@ -0,0 +1,425 @@
+use super::super::c;
+use bitflags::bitflags;
+
+/// `TCSA*` values for use with [`tcsetattr`].
+///
+/// [`tcsetattr`]: crate::termios::tcsetattr
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum OptionalActions {
+    /// `TCSANOW`—Make the change immediately.
+    Now = c::TCSANOW,
+
+    /// `TCSADRAIN`—Make the change after all output has been transmitted.
+    Drain = c::TCSADRAIN,
+
+    /// `TCSAFLUSH`—Discard any pending input and then make the change
+    /// after all output has been transmitted.
+    Flush = c::TCSAFLUSH,
+}
+
+/// `TC*` values for use with [`tcflush`].
+///
+/// [`tcflush`]: crate::termios::tcflush
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum QueueSelector {
+    /// `TCIFLUSH`—Flush data received but not read.
+    IFlush = c::TCIFLUSH,
+
+    /// `TCOFLUSH`—Flush data written but not transmitted.
+    OFlush = c::TCOFLUSH,
+
+    /// `TCIOFLUSH`—`IFlush` and `OFlush` combined.
+    IOFlush = c::TCIOFLUSH,
+}
+
+/// `TC*` values for use with [`tcflow`].
+///
+/// [`tcflow`]: crate::termios::tcflow
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum Action {
+    /// `TCOOFF`—Suspend output.
+    OOff = c::TCOOFF,
+
+    /// `TCOON`—Restart suspended output.
+    OOn = c::TCOON,
+
+    /// `TCIOFF`—Transmit a STOP character, intended to suspend input.
+    IOff = c::TCIOFF,
+
+    /// `TCION`—Transmit a START character, intended to restart input.
+    IOn = c::TCION,
+}
+
+bitflags! {
+    /// Flags for the `c_iflag` field of [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    pub struct InputModes: c::tcflag_t {
+        /// `IGNBRK`
+        const IGNBRK = c::IGNBRK;
+        /// `BRKINT`
+        const BRKINT = c::BRKINT;
+        /// `IGNPAR`
+        const IGNPAR = c::IGNPAR;
+        /// `PARMRK`
+        const PARMRK = c::PARMRK;
+        /// `INPCK`
+        const INPCK = c::INPCK;
+        /// `ISTRIP`
+        const ISTRIP = c::ISTRIP;
+        /// `INLCR`
+        const INLCR = c::INLCR;
+        /// `IGNCR`
+        const IGNCR = c::IGNCR;
+        /// `ICRNL`
+        const ICRNL = c::ICRNL;
+        /// `IUCLC`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const IUCLC = c::IUCLC;
+        /// `IXON`
+        const IXON = c::IXON;
+        /// `IXANY`
+        const IXANY = c::IXANY;
+        /// `IXOFF`
+        const IXOFF = c::IXOFF;
+        /// `IMAXBEL`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const IMAXBEL = c::IMAXBEL;
+        /// `IUTF8`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const IUTF8 = c::IUTF8;
+    }
+}
+
+bitflags! {
+    /// Flags for the `c_oflag` field of [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    pub struct OutputModes: c::tcflag_t {
+        /// `OPOST`
+        const OPOST = c::OPOST;
+        /// `OLCUC`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const OLCUC = c::OLCUC;
+        /// `ONLCR`
+        const ONLCR = c::ONLCR;
+        /// `OCRNL`
+        const OCRNL = c::OCRNL;
+        /// `ONOCR`
+        const ONOCR = c::ONOCR;
+        /// `ONLRET`
+        const ONLRET = c::ONLRET;
+        /// `OFILL`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const OFILL = c::OFILL;
+        /// `OFDEL`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const OFDEL = c::OFDEL;
+        /// `NLDLY`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NLDLY = c::NLDLY;
+        /// `NL1`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NL1 = c::NL1;
+        /// `CRDLY`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CRDLY = c::CRDLY;
+        /// `CR1`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CR1 = c::CR1;
+        /// `CR2`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CR2 = c::CR2;
+        /// `CR3`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CR3 = c::CR3;
+        /// `TABDLY`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const TABDLY = c::TABDLY;
+        /// `TAB1`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const TAB1 = c::TAB1;
+        /// `TAB2`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const TAB2 = c::TAB2;
+        /// `TAB3`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const TAB3 = c::TAB3;
+        /// `BSDLY`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const BSDLY = c::BSDLY;
+        /// `BS1`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const BS1 = c::BS1;
+        /// `VTDLY`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const VTDLY = c::VTDLY;
+        /// `VT1`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const VT1 = c::VT1;
+        /// `FFDLY`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const FFDLY = c::FFDLY;
+        /// `FF1`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const FF1 = c::FF1;
+    }
+}
+
+bitflags! {
+    /// Flags for the `c_cflag` field of [`Termios`].
+    ///
+    /// The speed bits of `c_cflag` are accessed with [`cfgetospeed`] and
+    /// [`cfsetospeed`] instead.
+    ///
+    /// [`cfgetospeed`]: crate::termios::cfgetospeed
+    /// [`cfsetospeed`]: crate::termios::cfsetospeed
+    /// [`Termios`]: crate::termios::Termios
+    pub struct ControlModes: c::tcflag_t {
+        /// `CSIZE`
+        const CSIZE = c::CSIZE;
+        /// `CS6`
+        const CS6 = c::CS6;
+        /// `CS7`
+        const CS7 = c::CS7;
+        /// `CS8`
+        const CS8 = c::CS8;
+        /// `CSTOPB`
+        const CSTOPB = c::CSTOPB;
+        /// `CREAD`
+        const CREAD = c::CREAD;
+        /// `PARENB`
+        const PARENB = c::PARENB;
+        /// `PARODD`
+        const PARODD = c::PARODD;
+        /// `HUPCL`
+        const HUPCL = c::HUPCL;
+        /// `CLOCAL`
+        const CLOCAL = c::CLOCAL;
+        /// `CRTSCTS`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CRTSCTS = c::CRTSCTS;
+        /// `CMSPAR`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CMSPAR = c::CMSPAR;
+    }
+}
+
+bitflags! {
+    /// Flags for the `c_lflag` field of [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    pub struct LocalModes: c::tcflag_t {
+        /// `ISIG`
+        const ISIG = c::ISIG;
+        /// `ICANON`
+        const ICANON = c::ICANON;
+        /// `XCASE`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const XCASE = c::XCASE;
+        /// `ECHO`
+        const ECHO = c::ECHO;
+        /// `ECHOE`
+        const ECHOE = c::ECHOE;
+        /// `ECHOK`
+        const ECHOK = c::ECHOK;
+        /// `ECHONL`
+        const ECHONL = c::ECHONL;
+        /// `NOFLSH`
+        const NOFLSH = c::NOFLSH;
+        /// `TOSTOP`
+        const TOSTOP = c::TOSTOP;
+        /// `ECHOCTL`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const ECHOCTL = c::ECHOCTL;
+        /// `ECHOPRT`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const ECHOPRT = c::ECHOPRT;
+        /// `ECHOKE`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const ECHOKE = c::ECHOKE;
+        /// `FLUSHO`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const FLUSHO = c::FLUSHO;
+        /// `PENDIN`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const PENDIN = c::PENDIN;
+        /// `IEXTEN`
+        const IEXTEN = c::IEXTEN;
+        /// `EXTPROC`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const EXTPROC = c::EXTPROC;
+    }
+}
+
+/// `speed_t`—A `B*` constant describing a terminal speed, for use with
+/// [`cfgetospeed`] and [`cfsetospeed`].
+///
+/// [`cfgetospeed`]: crate::termios::cfgetospeed
+/// [`cfsetospeed`]: crate::termios::cfsetospeed
+pub type Speed = c::speed_t;
+
+/// `B0`
+pub const B0: Speed = c::B0;
+
+/// `B50`
+pub const B50: Speed = c::B50;
+
+/// `B75`
+pub const B75: Speed = c::B75;
+
+/// `B110`
+pub const B110: Speed = c::B110;
+
+/// `B134`
+pub const B134: Speed = c::B134;
+
+/// `B150`
+pub const B150: Speed = c::B150;
+
+/// `B200`
+pub const B200: Speed = c::B200;
+
+/// `B300`
+pub const B300: Speed = c::B300;
+
+/// `B600`
+pub const B600: Speed = c::B600;
+
+/// `B1200`
+pub const B1200: Speed = c::B1200;
+
+/// `B1800`
+pub const B1800: Speed = c::B1800;
+
+/// `B2400`
+pub const B2400: Speed = c::B2400;
+
+/// `B4800`
+pub const B4800: Speed = c::B4800;
+
+/// `B9600`
+pub const B9600: Speed = c::B9600;
+
+/// `B19200`
+pub const B19200: Speed = c::B19200;
+
+/// `B38400`
+pub const B38400: Speed = c::B38400;
+
+/// `B57600`
+pub const B57600: Speed = c::B57600;
+
+/// `B115200`
+pub const B115200: Speed = c::B115200;
+
+/// `B230400`
+pub const B230400: Speed = c::B230400;
+
+/// `B460800`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B460800: Speed = c::B460800;
+
+/// `B500000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B500000: Speed = c::B500000;
+
+/// `B576000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B576000: Speed = c::B576000;
+
+/// `B921600`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B921600: Speed = c::B921600;
+
+/// `B1000000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B1000000: Speed = c::B1000000;
+
+/// `B1152000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B1152000: Speed = c::B1152000;
+
+/// `B1500000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B1500000: Speed = c::B1500000;
+
+/// `B2000000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B2000000: Speed = c::B2000000;
+
+/// `B2500000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B2500000: Speed = c::B2500000;
+
+/// `B3000000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B3000000: Speed = c::B3000000;
+
+/// `B3500000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B3500000: Speed = c::B3500000;
+
+/// `B4000000`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const B4000000: Speed = c::B4000000;
+
+/// `VINTR`
+pub const VINTR: usize = c::VINTR;
+
+/// `VQUIT`
+pub const VQUIT: usize = c::VQUIT;
+
+/// `VERASE`
+pub const VERASE: usize = c::VERASE;
+
+/// `VKILL`
+pub const VKILL: usize = c::VKILL;
+
+/// `VEOF`
+pub const VEOF: usize = c::VEOF;
+
+/// `VTIME`
+pub const VTIME: usize = c::VTIME;
+
+/// `VMIN`
+pub const VMIN: usize = c::VMIN;
+
+/// `VSTART`
+pub const VSTART: usize = c::VSTART;
+
+/// `VSTOP`
+pub const VSTOP: usize = c::VSTOP;
+
+/// `VSUSP`
+pub const VSUSP: usize = c::VSUSP;
+
+/// `VEOL`
+pub const VEOL: usize = c::VEOL;
+
+/// `VREPRINT`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const VREPRINT: usize = c::VREPRINT;
+
+/// `VDISCARD`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const VDISCARD: usize = c::VDISCARD;
+
+/// `VWERASE`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const VWERASE: usize = c::VWERASE;
+
+/// `VLNEXT`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const VLNEXT: usize = c::VLNEXT;
+
+/// `VEOL2`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const VEOL2: usize = c::VEOL2;
+
+/// `VSWTC`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub const VSWTC: usize = c::VSWTC;
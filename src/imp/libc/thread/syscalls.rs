@@ -1,9 +1,26 @@
 use super::super::c;
 use super::super::conv::ret;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::syscall_ret;
 use super::super::time::Timespec;
 use crate::io;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{Gid, Uid};
 #[cfg(not(target_os = "redox"))]
 use crate::thread::NanosleepRelativeResult;
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(any(target_arch = "x86", target_arch = "arm"))
+))]
+use c::{SYS_setgid, SYS_setgroups, SYS_setresgid, SYS_setresuid, SYS_setuid};
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(target_arch = "x86", target_arch = "arm")
+))]
+use c::{
+    SYS_setgid32 as SYS_setgid, SYS_setgroups32 as SYS_setgroups, SYS_setresgid32 as SYS_setresgid,
+    SYS_setresuid32 as SYS_setresuid, SYS_setuid32 as SYS_setuid,
+};
 use core::mem::MaybeUninit;
 #[cfg(not(any(
     target_os = "dragonfly",
@@ -73,3 +90,56 @@ pub(crate) fn nanosleep(request: &Timespec) -> NanosleepRelativeResult {
         }
     }
 }
+
+// Use the raw system calls rather than the libc functions, because the libc
+// functions change the credentials of every thread in the process. On
+// 32-bit x86 and arm, the plain system calls only support 16-bit IDs.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_thread_uid(uid: Uid) -> io::Result<()> {
+    unsafe { syscall_ret(c::syscall(SYS_setuid, uid.as_raw())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_thread_res_uid(ruid: Uid, euid: Uid, suid: Uid) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_setresuid,
+            ruid.as_raw(),
+            euid.as_raw(),
+            suid.as_raw(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_thread_gid(gid: Gid) -> io::Result<()> {
+    unsafe { syscall_ret(c::syscall(SYS_setgid, gid.as_raw())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_thread_res_gid(rgid: Gid, egid: Gid, sgid: Gid) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_setresgid,
+            rgid.as_raw(),
+            egid.as_raw(),
+            sgid.as_raw(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn set_thread_groups(groups: &[Gid]) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_setgroups,
+            groups.len(),
+            groups.as_ptr().cast::<c::gid_t>(),
+        ))
+    }
+}
@@ -5,4 +5,6 @@ pub(crate) mod syscalls;
 
 #[cfg(not(target_os = "wasi"))]
 pub use types::{ClockId, DynamicClockId};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use types::{Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags};
 pub use types::{Nsecs, Secs, Timespec};
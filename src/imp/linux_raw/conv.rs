@@ -175,7 +175,6 @@ pub(super) unsafe fn opt_mut<'a, T: Sized, Num: ArgNumber>(
 /// `Option<&T>` is represented as a nullable pointer to `T`, which is the
 /// same size as a `usize`, so we can directly transmute it and pass the result
 /// to syscalls expecting nullable pointers.
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 #[inline]
 pub(super) unsafe fn opt_ref<'a, T: Sized, Num: ArgNumber>(t: Option<&'a T>) -> ArgReg<'a, Num> {
     transmute(t)
@@ -0,0 +1,78 @@
+//! inotify flags.
+//!
+//! `linux_raw_sys` doesn't yet provide the `IN_*` constants, so they're
+//! defined here.
+
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `IN_*` constants for use with [`inotify_init`].
+    ///
+    /// [`inotify_init`]: crate::fs::inotify::inotify_init
+    pub struct CreateFlags: c::c_uint {
+        /// `IN_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `IN_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
+bitflags! {
+    /// `IN_*` constants for use with [`inotify_add_watch`], and reported in
+    /// inotify events.
+    ///
+    /// [`inotify_add_watch`]: crate::fs::inotify::inotify_add_watch
+    pub struct WatchFlags: u32 {
+        /// `IN_ACCESS`
+        const ACCESS = 0x0000_0001;
+        /// `IN_ATTRIB`
+        const ATTRIB = 0x0000_0004;
+        /// `IN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = 0x0000_0010;
+        /// `IN_CLOSE_WRITE`
+        const CLOSE_WRITE = 0x0000_0008;
+        /// `IN_CREATE`
+        const CREATE = 0x0000_0100;
+        /// `IN_DELETE`
+        const DELETE = 0x0000_0200;
+        /// `IN_DELETE_SELF`
+        const DELETE_SELF = 0x0000_0400;
+        /// `IN_MODIFY`
+        const MODIFY = 0x0000_0002;
+        /// `IN_MOVE_SELF`
+        const MOVE_SELF = 0x0000_0800;
+        /// `IN_MOVED_FROM`
+        const MOVED_FROM = 0x0000_0040;
+        /// `IN_MOVED_TO`
+        const MOVED_TO = 0x0000_0080;
+        /// `IN_OPEN`
+        const OPEN = 0x0000_0020;
+        /// `IN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits | Self::CLOSE_NOWRITE.bits;
+        /// `IN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits | Self::MOVED_TO.bits;
+        /// `IN_ALL_EVENTS`
+        const ALL_EVENTS = Self::ACCESS.bits | Self::ATTRIB.bits | Self::CLOSE_NOWRITE.bits | Self::CLOSE_WRITE.bits | Self::CREATE.bits | Self::DELETE.bits | Self::DELETE_SELF.bits | Self::MODIFY.bits | Self::MOVE_SELF.bits | Self::MOVED_FROM.bits | Self::MOVED_TO.bits | Self::OPEN.bits;
+        /// `IN_DONT_FOLLOW`
+        const DONT_FOLLOW = 0x0200_0000;
+        /// `IN_EXCL_UNLINK` (since Linux 2.6.36)
+        const EXCL_UNLINK = 0x0400_0000;
+        /// `IN_MASK_ADD`
+        const MASK_ADD = 0x2000_0000;
+        /// `IN_MASK_CREATE` (since Linux 4.18)
+        const MASK_CREATE = 0x1000_0000;
+        /// `IN_ONESHOT`
+        const ONESHOT = 0x8000_0000;
+        /// `IN_ONLYDIR`
+        const ONLYDIR = 0x0100_0000;
+        /// `IN_IGNORED`
+        const IGNORED = 0x0000_8000;
+        /// `IN_ISDIR`
+        const ISDIR = 0x4000_0000;
+        /// `IN_Q_OVERFLOW`
+        const Q_OVERFLOW = 0x0000_4000;
+        /// `IN_UNMOUNT`
+        const UNMOUNT = 0x0000_2000;
+    }
+}
@@ -1,4 +1,5 @@
 mod dir;
+pub(crate) mod inotify;
 mod makedev;
 mod types;
 
@@ -9,5 +10,5 @@ pub use makedev::{major, makedev, minor};
 pub use types::{
     Access, Advice, AtFlags, Dev, FallocateFlags, FdFlags, FileType, FlockOperation, FsWord,
     MemfdFlags, Mode, OFlags, RawMode, RenameFlags, ResolveFlags, Stat, StatFs, Statx, StatxFlags,
-    PROC_SUPER_MAGIC, UTIME_NOW, UTIME_OMIT,
+    XattrFlags, PROC_SUPER_MAGIC, UTIME_NOW, UTIME_OMIT,
 };
@@ -14,30 +14,36 @@
 #![allow(unsafe_code)]
 #![allow(dead_code)]
 
-#[cfg(target_pointer_width = "32")]
-use super::super::arch::choose::syscall6_readonly;
 use super::super::arch::choose::{
     syscall1_readonly, syscall2, syscall2_readonly, syscall3, syscall3_readonly, syscall4,
-    syscall4_readonly, syscall5, syscall5_readonly, syscall6,
+    syscall4_readonly, syscall5, syscall5_readonly, syscall6, syscall6_readonly,
 };
 use super::super::c;
 #[cfg(all(target_pointer_width = "32", target_arch = "arm"))]
 use super::super::conv::zero;
 use super::super::conv::{
-    borrowed_fd, by_ref, c_int, c_str, c_uint, dev_t, mode_as, oflags, oflags_for_open_how,
-    opt_c_str, opt_mut, out, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint, ret_owned_fd,
-    ret_usize, size_of, slice_just_addr, slice_mut,
+    borrowed_fd, by_ref, c_int, c_str, c_uint, const_void_star, dev_t, mode_as, oflags,
+    oflags_for_open_how, opt_c_str, opt_mut, out, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
+    ret_owned_fd, ret_usize, size_of, slice, slice_just_addr, slice_mut,
 };
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use super::super::fd::AsFd;
-use super::super::fd::{BorrowedFd, RawFd};
+use super::super::fd::{AsRawFd, BorrowedFd, RawFd};
 use super::super::reg::nr;
 use super::super::time::Timespec;
+use super::inotify::{CreateFlags as InotifyFlags, WatchFlags as InotifyWatchFlags};
 use super::{
     Access, Advice as FsAdvice, AtFlags, FallocateFlags, FdFlags, FlockOperation, MemfdFlags, Mode,
-    OFlags, RenameFlags, ResolveFlags, Stat, StatFs, StatxFlags,
+    OFlags, RenameFlags, ResolveFlags, Stat, StatFs, StatxFlags, XattrFlags,
 };
 use crate::ffi::ZStr;
+use crate::fs::fanotify::{
+    EventMask as FanotifyEventMask, InitFlags as FanotifyInitFlags, MarkFlags as FanotifyMarkFlags,
+};
+use crate::fs::mount::{
+    FsMountFlags, FsOpenFlags, FsPickFlags, ListmountFlags, MountAttr, MountAttrFlags, MountFlags,
+    MountSetattrFlags, MoveMountFlags, OpenTreeFlags, StatmountFlags, UnmountFlags,
+};
 use crate::io::{self, OwnedFd, SeekFrom};
 use crate::process::{Gid, Uid};
 use core::convert::TryInto;
@@ -52,15 +58,24 @@ use linux_raw_sys::general::__NR_open;
 use linux_raw_sys::general::__NR_renameat;
 use linux_raw_sys::general::{
     __NR_faccessat, __NR_fallocate, __NR_fchmod, __NR_fchmodat, __NR_fchown, __NR_fchownat,
-    __NR_fdatasync, __NR_flock, __NR_fsync, __NR_getdents64, __NR_linkat, __NR_mkdirat,
-    __NR_mknodat, __NR_openat, __NR_readlinkat, __NR_symlinkat, __NR_unlinkat, __NR_utimensat,
-    __kernel_timespec, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW, F_DUPFD, F_DUPFD_CLOEXEC,
-    F_GETFD, F_GETFL, F_GETLEASE, F_GETOWN, F_GETSIG, F_SETFD, F_SETFL,
+    __NR_fdatasync, __NR_fgetxattr, __NR_flistxattr, __NR_flock, __NR_fremovexattr, __NR_fsetxattr,
+    __NR_fsync, __NR_getdents64, __NR_getxattr, __NR_lgetxattr, __NR_linkat, __NR_listxattr,
+    __NR_llistxattr, __NR_lremovexattr, __NR_lsetxattr, __NR_mkdirat, __NR_mknodat, __NR_mount,
+    __NR_openat, __NR_readlinkat, __NR_removexattr, __NR_setxattr, __NR_symlinkat, __NR_umount2,
+    __NR_unlinkat, __NR_utimensat, __kernel_timespec, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW,
+    F_DUPFD, F_DUPFD_CLOEXEC, F_GETFD, F_GETFL, F_GETLEASE, F_GETOWN, F_GETSIG, F_SETFD, F_SETFL,
+};
+use linux_raw_sys::v5_11::general::{
+    __NR_fsconfig, __NR_fsmount, __NR_fsopen, __NR_fspick, __NR_move_mount, __NR_open_tree,
+    __NR_openat2, open_how, statx,
+};
+use linux_raw_sys::v5_4::general::{
+    __NR_copy_file_range, __NR_memfd_create, __NR_renameat2, __NR_statx, F_GETPIPE_SZ, F_GET_SEALS,
+    F_SETPIPE_SZ,
 };
-use linux_raw_sys::v5_11::general::{__NR_openat2, open_how};
 use linux_raw_sys::v5_4::general::{
-    __NR_copy_file_range, __NR_memfd_create, __NR_renameat2, __NR_statx, statx, F_GETPIPE_SZ,
-    F_GET_SEALS, F_SETPIPE_SZ,
+    __NR_fanotify_init, __NR_fanotify_mark, __NR_inotify_add_watch, __NR_inotify_init1,
+    __NR_inotify_rm_watch,
 };
 #[cfg(target_pointer_width = "32")]
 use {
@@ -1274,3 +1289,599 @@ pub(crate) fn sendfile(
         ))
     }
 }
+
+#[inline]
+pub(crate) fn inotify_init1(flags: InotifyFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall1_readonly(
+            nr(__NR_inotify_init1),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn inotify_add_watch(
+    inot: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: InotifyWatchFlags,
+) -> io::Result<i32> {
+    unsafe {
+        ret_c_int(syscall3_readonly(
+            nr(__NR_inotify_add_watch),
+            borrowed_fd(inot),
+            c_str(path),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn inotify_rm_watch(inot: BorrowedFd<'_>, wd: i32) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_inotify_rm_watch),
+            borrowed_fd(inot),
+            c_int(wd),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fanotify_init(flags: FanotifyInitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2_readonly(
+            nr(__NR_fanotify_init),
+            c_uint(flags.bits()),
+            oflags(event_flags),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fanotify_mark(
+    fanotify: BorrowedFd<'_>,
+    flags: FanotifyMarkFlags,
+    mask: FanotifyEventMask,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&ZStr>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall6_readonly(
+            nr(__NR_fanotify_mark),
+            borrowed_fd(fanotify),
+            c_uint(flags.bits()),
+            hi(mask.bits()),
+            lo(mask.bits()),
+            borrowed_fd(dirfd),
+            opt_c_str(path),
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_fanotify_mark),
+            borrowed_fd(fanotify),
+            c_uint(flags.bits()),
+            pass_usize(mask.bits() as usize),
+            borrowed_fd(dirfd),
+            opt_c_str(path),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn getxattr(path: &ZStr, name: &ZStr, value: &mut [u8]) -> io::Result<usize> {
+    let (value_addr_mut, value_len) = slice_mut(value);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_getxattr),
+            c_str(path),
+            c_str(name),
+            value_addr_mut,
+            value_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn lgetxattr(path: &ZStr, name: &ZStr, value: &mut [u8]) -> io::Result<usize> {
+    let (value_addr_mut, value_len) = slice_mut(value);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_lgetxattr),
+            c_str(path),
+            c_str(name),
+            value_addr_mut,
+            value_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &ZStr, value: &mut [u8]) -> io::Result<usize> {
+    let (value_addr_mut, value_len) = slice_mut(value);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_fgetxattr),
+            borrowed_fd(fd),
+            c_str(name),
+            value_addr_mut,
+            value_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn setxattr(
+    path: &ZStr,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_setxattr),
+            c_str(path),
+            c_str(name),
+            value_addr,
+            value_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn lsetxattr(
+    path: &ZStr,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_lsetxattr),
+            c_str(path),
+            c_str(name),
+            value_addr,
+            value_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fsetxattr(
+    fd: BorrowedFd<'_>,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_fsetxattr),
+            borrowed_fd(fd),
+            c_str(name),
+            value_addr,
+            value_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn listxattr(path: &ZStr, list: &mut [u8]) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
+    unsafe {
+        ret_usize(syscall3(
+            nr(__NR_listxattr),
+            c_str(path),
+            list_addr_mut,
+            list_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn llistxattr(path: &ZStr, list: &mut [u8]) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
+    unsafe {
+        ret_usize(syscall3(
+            nr(__NR_llistxattr),
+            c_str(path),
+            list_addr_mut,
+            list_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [u8]) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
+    unsafe {
+        ret_usize(syscall3(
+            nr(__NR_flistxattr),
+            borrowed_fd(fd),
+            list_addr_mut,
+            list_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn removexattr(path: &ZStr, name: &ZStr) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_removexattr),
+            c_str(path),
+            c_str(name),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn lremovexattr(path: &ZStr, name: &ZStr) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_lremovexattr),
+            c_str(path),
+            c_str(name),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fremovexattr(fd: BorrowedFd<'_>, name: &ZStr) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_fremovexattr),
+            borrowed_fd(fd),
+            c_str(name),
+        ))
+    }
+}
+
+// The `*xattrat` syscalls were added in Linux 6.13, after the headers
+// `linux_raw_sys` is generated from. They have the same numbers on all the
+// architectures we support.
+#[allow(non_upper_case_globals)]
+const __NR_setxattrat: u32 = 463;
+#[allow(non_upper_case_globals)]
+const __NR_getxattrat: u32 = 464;
+#[allow(non_upper_case_globals)]
+const __NR_listxattrat: u32 = 465;
+#[allow(non_upper_case_globals)]
+const __NR_removexattrat: u32 = 466;
+
+/// `struct xattr_args`
+#[repr(C)]
+struct XattrArgs {
+    value: u64,
+    size: u32,
+    flags: u32,
+}
+
+#[inline]
+pub(crate) fn getxattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    name: &ZStr,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    let args = XattrArgs {
+        value: value.as_mut_ptr() as usize as u64,
+        size: value.len().try_into().unwrap_or(u32::MAX),
+        flags: 0,
+    };
+    unsafe {
+        ret_usize(syscall6(
+            nr(__NR_getxattrat),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(at_flags.bits()),
+            c_str(name),
+            by_ref(&args),
+            size_of::<XattrArgs, _>(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn setxattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    name: &ZStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let args = XattrArgs {
+        value: value.as_ptr() as usize as u64,
+        size: value.len().try_into().map_err(|_| io::Error::TOOBIG)?,
+        flags: flags.bits(),
+    };
+    unsafe {
+        ret(syscall6_readonly(
+            nr(__NR_setxattrat),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(at_flags.bits()),
+            c_str(name),
+            by_ref(&args),
+            size_of::<XattrArgs, _>(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn listxattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    list: &mut [u8],
+) -> io::Result<usize> {
+    let (list_addr_mut, list_len) = slice_mut(list);
+    unsafe {
+        ret_usize(syscall5(
+            nr(__NR_listxattrat),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(at_flags.bits()),
+            list_addr_mut,
+            list_len,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn removexattrat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    at_flags: AtFlags,
+    name: &ZStr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall4_readonly(
+            nr(__NR_removexattrat),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(at_flags.bits()),
+            c_str(name),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mount(
+    source: &ZStr,
+    target: &ZStr,
+    file_system_type: &ZStr,
+    flags: MountFlags,
+    data: Option<&ZStr>,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_mount),
+            c_str(source),
+            c_str(target),
+            c_str(file_system_type),
+            c_uint(flags.bits()),
+            opt_c_str(data),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn umount2(target: &ZStr, flags: UnmountFlags) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_umount2),
+            c_str(target),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fsopen(fs_name: &ZStr, flags: FsOpenFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2_readonly(
+            nr(__NR_fsopen),
+            c_str(fs_name),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fspick(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: FsPickFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall3_readonly(
+            nr(__NR_fspick),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn fsconfig(
+    fs_fd: BorrowedFd<'_>,
+    cmd: c::c_uint,
+    key: Option<&ZStr>,
+    value: *const c::c_void,
+    aux: c::c_int,
+) -> io::Result<()> {
+    ret(syscall5_readonly(
+        nr(__NR_fsconfig),
+        borrowed_fd(fs_fd),
+        c_uint(cmd),
+        opt_c_str(key),
+        const_void_star(value),
+        c_int(aux),
+    ))
+}
+
+#[inline]
+pub(crate) fn fsmount(
+    fs_fd: BorrowedFd<'_>,
+    flags: FsMountFlags,
+    attr_flags: MountAttrFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall3_readonly(
+            nr(__NR_fsmount),
+            borrowed_fd(fs_fd),
+            c_uint(flags.bits()),
+            c_uint(attr_flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn move_mount(
+    from_dirfd: BorrowedFd<'_>,
+    from_path: &ZStr,
+    to_dirfd: BorrowedFd<'_>,
+    to_path: &ZStr,
+    flags: MoveMountFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_move_mount),
+            borrowed_fd(from_dirfd),
+            c_str(from_path),
+            borrowed_fd(to_dirfd),
+            c_str(to_path),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn open_tree(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall3_readonly(
+            nr(__NR_open_tree),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+// `mount_setattr` was added in Linux 5.12, after the headers `linux_raw_sys`
+// is generated from.
+#[allow(non_upper_case_globals)]
+const __NR_mount_setattr: u32 = 442;
+
+/// `struct mount_attr`
+#[repr(C)]
+struct RawMountAttr {
+    attr_set: u64,
+    attr_clr: u64,
+    propagation: u64,
+    userns_fd: u64,
+}
+
+#[inline]
+pub(crate) fn mount_setattr(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let attr = RawMountAttr {
+        attr_set: u64::from(attr.attr_set.bits()),
+        attr_clr: u64::from(attr.attr_clr.bits()),
+        propagation: attr.propagation.map_or(0, |propagation| propagation as u64),
+        userns_fd: attr
+            .userns_fd
+            .map_or(0, |userns_fd| userns_fd.as_raw_fd() as u64),
+    };
+    unsafe {
+        ret(syscall5_readonly(
+            nr(__NR_mount_setattr),
+            borrowed_fd(dirfd),
+            c_str(path),
+            c_uint(flags.bits()),
+            by_ref(&attr),
+            size_of::<RawMountAttr, _>(),
+        ))
+    }
+}
+
+// `statmount` and `listmount` were added in Linux 6.8, after the headers
+// `linux_raw_sys` is generated from.
+#[allow(non_upper_case_globals)]
+const __NR_statmount: u32 = 457;
+#[allow(non_upper_case_globals)]
+const __NR_listmount: u32 = 458;
+
+/// `struct mnt_id_req`
+#[repr(C)]
+struct MntIdReq {
+    size: u32,
+    spare: u32,
+    mnt_id: u64,
+    param: u64,
+}
+
+#[inline]
+pub(crate) fn statmount(mnt_id: u64, mask: StatmountFlags, buf: &mut [u8]) -> io::Result<()> {
+    let req = MntIdReq {
+        size: core::mem::size_of::<MntIdReq>() as u32,
+        spare: 0,
+        mnt_id,
+        param: mask.bits(),
+    };
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+    unsafe {
+        ret(syscall4(
+            nr(__NR_statmount),
+            by_ref(&req),
+            buf_addr_mut,
+            buf_len,
+            c_uint(0),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn listmount(
+    mnt_id: u64,
+    last_mnt_id: u64,
+    flags: ListmountFlags,
+    buf: &mut [u64],
+) -> io::Result<usize> {
+    let req = MntIdReq {
+        size: core::mem::size_of::<MntIdReq>() as u32,
+        spare: 0,
+        mnt_id,
+        param: last_mnt_id,
+    };
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+    unsafe {
+        ret_usize(syscall4(
+            nr(__NR_listmount),
+            by_ref(&req),
+            buf_addr_mut,
+            buf_len,
+            c_uint(flags.bits()),
+        ))
+    }
+}
@@ -362,6 +362,19 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `XATTR_*` constants for use with [`setxattr`].
+    ///
+    /// [`setxattr`]: crate::fs::setxattr
+    pub struct XattrFlags: c::c_uint {
+        /// `XATTR_CREATE`
+        const CREATE = 0x1;
+
+        /// `XATTR_REPLACE`
+        const REPLACE = 0x2;
+    }
+}
+
 bitflags! {
     /// `STATX_*` constants for use with [`statx`].
     ///
@@ -408,6 +421,12 @@ bitflags! {
 
         /// `STATX_ALL`
         const ALL = linux_raw_sys::v5_4::general::STATX_ALL;
+
+        /// `STATX_MNT_ID` (since Linux 5.8)
+        const MNT_ID = linux_raw_sys::v5_11::general::STATX_MNT_ID;
+
+        /// `STATX_MNT_ID_UNIQUE` (since Linux 6.8)
+        const MNT_ID_UNIQUE = 0x4000;
     }
 }
 
@@ -483,7 +502,7 @@ pub type StatFs = linux_raw_sys::general::statfs64;
 /// `struct statx` for use with [`statx`].
 ///
 /// [`statx`]: crate::fs::statx
-pub type Statx = linux_raw_sys::v5_4::general::statx;
+pub type Statx = linux_raw_sys::v5_11::general::statx;
 
 /// `mode_t`
 #[cfg(not(any(
@@ -10,9 +10,11 @@ pub use error::Error;
 pub use io_slice::{IoSlice, IoSliceMut};
 pub use poll_fd::{PollFd, PollFlags};
 pub use types::{
-    Advice, DupFlags, EventfdFlags, MapFlags, MlockFlags, MprotectFlags, MremapFlags, PipeFlags,
-    ProtFlags, ReadWriteFlags, Tcflag, Termios, UserfaultfdFlags, Winsize, ICANON, PIPE_BUF,
+    Advice, DupFlags, EventfdFlags, MapFlags, MlockFlags, MprotectFlags, MremapFlags, OpenptFlags,
+    PipeFlags, ProtFlags, ReadWriteFlags, SignalfdFlags, Tcflag, Termios, Termios2,
+    UserfaultfdFlags, Winsize, ICANON, PIPE_BUF,
 };
+pub(crate) use types::{BOTHER, CBAUD, CIBAUD, IBSHIFT};
 
 use super::c;
 
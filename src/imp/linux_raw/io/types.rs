@@ -174,6 +174,33 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// The `O_*` flags accepted by [`openpt`] and [`ioctl_tiocgptpeer`].
+    ///
+    /// [`openpt`]: crate::io::openpt
+    /// [`ioctl_tiocgptpeer`]: crate::io::ioctl_tiocgptpeer
+    pub struct OpenptFlags: c::c_uint {
+        /// `O_RDWR`
+        const RDWR = linux_raw_sys::general::O_RDWR;
+        /// `O_NOCTTY`
+        const NOCTTY = linux_raw_sys::general::O_NOCTTY;
+        /// `O_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+    }
+}
+
+bitflags! {
+    /// The `SFD_*` flags accepted by [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::io::signalfd
+    pub struct SignalfdFlags: c::c_uint {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
 /// `POSIX_MADV_*` constants for use with [`madvise`].
 ///
 /// [`madvise`]: crate::io::madvise
@@ -239,11 +266,18 @@ impl Advice {
     pub const DontNeed: Self = Self::Normal;
 }
 
-/// `struct termios` for use with [`ioctl_tcgets`].
+/// `struct termios` for use with [`tcgetattr`] and [`tcsetattr`].
 ///
-/// [`ioctl_tcgets`]: crate::io::ioctl_tcgets
+/// [`tcgetattr`]: crate::termios::tcgetattr
+/// [`tcsetattr`]: crate::termios::tcsetattr
 pub type Termios = linux_raw_sys::general::termios;
 
+/// `struct termios2` for use with [`ioctl_tcgets2`] and [`ioctl_tcsets2`].
+///
+/// [`ioctl_tcgets2`]: crate::io::ioctl_tcgets2
+/// [`ioctl_tcsets2`]: crate::io::ioctl_tcsets2
+pub type Termios2 = linux_raw_sys::v5_4::general::termios2;
+
 /// `struct winsize` for use with [`ioctl_tiocgwinsz`].
 ///
 /// [`ioctl_tiocgwinsz`]: crate::io::ioctl_tiocgwinsz
@@ -256,5 +290,10 @@ pub type Tcflag = linux_raw_sys::general::tcflag_t;
 /// canonical mode.
 pub const ICANON: c::c_uint = linux_raw_sys::general::ICANON;
 
+pub(crate) const BOTHER: c::c_uint = linux_raw_sys::v5_4::general::BOTHER;
+pub(crate) const CBAUD: c::c_uint = linux_raw_sys::v5_4::general::CBAUD;
+pub(crate) const CIBAUD: c::c_uint = linux_raw_sys::v5_4::general::CIBAUD;
+pub(crate) const IBSHIFT: c::c_uint = linux_raw_sys::v5_4::general::IBSHIFT;
+
 /// `PIPE_BUF`—The maximum size of a write to a pipe guaranteed to be atomic.
 pub const PIPE_BUF: usize = linux_raw_sys::general::PIPE_BUF as usize;
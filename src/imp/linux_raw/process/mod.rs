@@ -3,17 +3,49 @@ mod types;
 mod wait;
 
 pub(crate) mod cpu_set;
+pub(crate) mod sig_set;
 
 #[cfg(target_vendor = "mustang")]
 pub(crate) use auxv::init;
 pub(crate) use auxv::{exe_phdrs, linux_execfn, linux_hwcap, page_size};
 pub(super) use auxv::{exe_phdrs_slice, sysinfo_ehdr};
-pub(crate) use types::{raw_cpu_set_new, RawCpuSet, RawUname, CPU_SETSIZE};
+pub(crate) use linux_raw_sys::general::{
+    __NR_brk, __NR_chdir, __NR_chroot, __NR_clone, __NR_close, __NR_dup, __NR_dup3, __NR_execve,
+    __NR_exit, __NR_exit_group, __NR_fchdir, __NR_fchmod, __NR_fchmodat, __NR_fchownat,
+    __NR_getpgid, __NR_getpid, __NR_getppid, __NR_gettid, __NR_ioctl, __NR_keyctl, __NR_kill,
+    __NR_madvise, __NR_mkdirat, __NR_mount, __NR_mprotect, __NR_mremap, __NR_munmap, __NR_openat,
+    __NR_perf_event_open, __NR_personality, __NR_pipe2, __NR_pivot_root, __NR_prctl, __NR_ptrace,
+    __NR_read, __NR_readv, __NR_reboot, __NR_tgkill, __NR_unlinkat, __NR_unshare, __NR_write,
+    __NR_writev,
+};
+pub(crate) use linux_raw_sys::general::{
+    CLD_CONTINUED, CLD_DUMPED, CLD_EXITED, CLD_KILLED, CLD_STOPPED, CLD_TRAPPED, SIGABRT, SIGALRM,
+    SIGBUS, SIGCHLD, SIGCONT, SIGFPE, SIGHUP, SIGILL, SIGINT, SIGIO, SIGKILL, SIGPIPE, SIGPROF,
+    SIGPWR, SIGQUIT, SIGSEGV, SIGSTKFLT, SIGSTOP, SIGSYS, SIGTERM, SIGTRAP, SIGTSTP, SIGTTIN,
+    SIGTTOU, SIGURG, SIGUSR1, SIGUSR2, SIGVTALRM, SIGWINCH, SIGXCPU, SIGXFSZ,
+};
+// x86 uses `socketcall` for these until Linux 4.3.
+#[cfg(not(target_arch = "x86"))]
+pub(crate) use linux_raw_sys::general::{
+    __NR_accept4, __NR_bind, __NR_connect, __NR_listen, __NR_recvfrom, __NR_recvmsg, __NR_sendmsg,
+    __NR_sendto, __NR_shutdown, __NR_socket, __NR_socketpair,
+};
+pub(crate) use linux_raw_sys::v5_4::general::{
+    __NR_bpf, __NR_execveat, __NR_getrandom, __NR_kcmp, __NR_memfd_create, __NR_process_vm_readv,
+    __NR_process_vm_writev, __NR_seccomp, __NR_setns, __NR_userfaultfd,
+};
+pub(crate) use types::{
+    raw_cpu_set_new, raw_sig_set_new, sigrtmax, sigrtmin, RawCpuSet, RawSigSet, RawUname,
+    SeccompNotifAddfd, UserCapData, UserCapHeader, CPU_SETSIZE, SECCOMP_IOCTL_NOTIF_ADDFD,
+    SECCOMP_IOCTL_NOTIF_ID_VALID, SECCOMP_IOCTL_NOTIF_RECV, SECCOMP_IOCTL_NOTIF_SEND, SIGRTMAX,
+};
 pub use types::{
-    MembarrierCommand, RawCpuid, RawGid, RawNonZeroPid, RawPid, RawUid, Resource, EXIT_FAILURE,
-    EXIT_SIGNALED_SIGABRT, EXIT_SUCCESS,
+    MembarrierCommand, NamespaceType, PidfdFlags, PidfdGetfdFlags, RawCpuid, RawGid, RawNonZeroPid,
+    RawPid, RawUid, RemoteIoVec, Resource, SeccompData, SeccompNotif, SeccompNotifResp, Sigaction,
+    SigactionFlags, SigmaskHow, UnshareFlags, EXIT_FAILURE, EXIT_SIGNALED_SIGABRT, EXIT_SUCCESS,
+    SIG_DFL, SIG_IGN,
 };
 pub(crate) use wait::{
-    WCONTINUED, WEXITSTATUS, WIFCONTINUED, WIFEXITED, WIFSIGNALED, WIFSTOPPED, WNOHANG, WSTOPSIG,
-    WTERMSIG, WUNTRACED,
+    WCONTINUED, WEXITED, WEXITSTATUS, WIFCONTINUED, WIFEXITED, WIFSIGNALED, WIFSTOPPED, WNOHANG,
+    WNOWAIT, WSTOPPED, WSTOPSIG, WTERMSIG, WUNTRACED,
 };
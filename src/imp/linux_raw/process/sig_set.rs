@@ -0,0 +1,59 @@
+use super::super::c;
+use super::{RawSigSet, SIGRTMAX};
+use core::mem::size_of_val;
+
+#[inline]
+pub(crate) fn sigaddset(sig_set: &mut RawSigSet, sig: c::c_int) {
+    assert!(
+        sig >= 1 && sig as u32 <= SIGRTMAX,
+        "signal out of bounds: the signal max is {} but the signal is {}",
+        SIGRTMAX,
+        sig
+    );
+    let size_in_bits = 8 * size_of_val(&sig_set.bits[0]); // 32, 64 etc
+    let bit = (sig - 1) as usize;
+    let (idx, offset) = (bit / size_in_bits, bit % size_in_bits);
+    sig_set.bits[idx] |= 1 << offset
+}
+
+#[inline]
+pub(crate) fn sigemptyset(sig_set: &mut RawSigSet) {
+    for element in &mut sig_set.bits {
+        *element = 0;
+    }
+}
+
+#[inline]
+pub(crate) fn sigfillset(sig_set: &mut RawSigSet) {
+    for element in &mut sig_set.bits {
+        *element = !0;
+    }
+}
+
+#[inline]
+pub(crate) fn sigdelset(sig_set: &mut RawSigSet, sig: c::c_int) {
+    assert!(
+        sig >= 1 && sig as u32 <= SIGRTMAX,
+        "signal out of bounds: the signal max is {} but the signal is {}",
+        SIGRTMAX,
+        sig
+    );
+    let size_in_bits = 8 * size_of_val(&sig_set.bits[0]); // 32, 64 etc
+    let bit = (sig - 1) as usize;
+    let (idx, offset) = (bit / size_in_bits, bit % size_in_bits);
+    sig_set.bits[idx] &= !(1 << offset)
+}
+
+#[inline]
+pub(crate) fn sigismember(sig_set: &RawSigSet, sig: c::c_int) -> bool {
+    assert!(
+        sig >= 1 && sig as u32 <= SIGRTMAX,
+        "signal out of bounds: the signal max is {} but the signal is {}",
+        SIGRTMAX,
+        sig
+    );
+    let size_in_bits = 8 * size_of_val(&sig_set.bits[0]);
+    let bit = (sig - 1) as usize;
+    let (idx, offset) = (bit / size_in_bits, bit % size_in_bits);
+    (sig_set.bits[idx] & (1 << offset)) != 0
+}
@@ -1,4 +1,6 @@
 use super::super::c;
+use crate::process::SigSet;
+use bitflags::bitflags;
 
 /// A command for use with [`membarrier`] and [`membarrier_cpu`].
 ///
@@ -116,3 +118,277 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
 }
 
 pub(crate) const CPU_SETSIZE: usize = 8 * core::mem::size_of::<RawCpuSet>();
+
+/// `struct iovec`—A range of memory in another process, for use with
+/// [`process_vm_readv`] and [`process_vm_writev`].
+///
+/// [`process_vm_readv`]: crate::process::process_vm_readv
+/// [`process_vm_writev`]: crate::process::process_vm_writev
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RemoteIoVec {
+    /// The address of the range, in the other process.
+    pub base: usize,
+    /// The length of the range, in bytes.
+    pub len: usize,
+}
+
+/// `struct __user_cap_header_struct`, for use with `capget` and `capset`.
+#[repr(C)]
+pub(crate) struct UserCapHeader {
+    pub(crate) version: u32,
+    pub(crate) pid: c::c_int,
+}
+
+/// `struct __user_cap_data_struct`, for use with `capget` and `capset`.
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct UserCapData {
+    pub(crate) effective: u32,
+    pub(crate) permitted: u32,
+    pub(crate) inheritable: u32,
+}
+
+/// A command for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    Block = linux_raw_sys::general::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    Unblock = linux_raw_sys::general::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    SetMask = linux_raw_sys::general::SIG_SETMASK,
+}
+
+/// The kernel's `sigset_t`, as used by `rt_sigprocmask` and related
+/// syscalls. This is `_NSIG` bits, which may be larger than the `sigset_t`
+/// in linux-raw-sys.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct RawSigSet {
+    #[cfg(all(target_pointer_width = "32", not(target_arch = "x86_64")))]
+    pub(crate) bits: [u32; 2],
+    #[cfg(not(all(target_pointer_width = "32", not(target_arch = "x86_64"))))]
+    pub(crate) bits: [u64; 1],
+}
+
+#[inline]
+pub(crate) fn raw_sig_set_new() -> RawSigSet {
+    #[cfg(all(target_pointer_width = "32", not(target_arch = "x86_64")))]
+    {
+        RawSigSet { bits: [0; 2] }
+    }
+    #[cfg(not(all(target_pointer_width = "32", not(target_arch = "x86_64"))))]
+    {
+        RawSigSet { bits: [0; 1] }
+    }
+}
+
+/// The kernel's `_NSIG`, which is also its `SIGRTMAX`.
+pub(crate) const SIGRTMAX: u32 = 8 * core::mem::size_of::<RawSigSet>() as u32;
+
+#[inline]
+pub(crate) fn sigrtmin() -> c::c_int {
+    linux_raw_sys::general::SIGRTMIN as _
+}
+
+#[inline]
+pub(crate) fn sigrtmax() -> c::c_int {
+    SIGRTMAX as _
+}
+
+bitflags! {
+    /// `SA_*` flags for use with [`sigaction`].
+    ///
+    /// [`sigaction`]: crate::runtime::sigaction
+    pub struct SigactionFlags: c::c_ulong {
+        /// `SA_NOCLDSTOP`
+        const NOCLDSTOP = linux_raw_sys::general::SA_NOCLDSTOP as _;
+        /// `SA_NOCLDWAIT`
+        const NOCLDWAIT = linux_raw_sys::general::SA_NOCLDWAIT as _;
+        /// `SA_SIGINFO`
+        const SIGINFO = linux_raw_sys::general::SA_SIGINFO as _;
+        /// `SA_ONSTACK`
+        const ONSTACK = linux_raw_sys::general::SA_ONSTACK as _;
+        /// `SA_RESTART`
+        const RESTART = linux_raw_sys::general::SA_RESTART as _;
+        /// `SA_NODEFER`
+        const NODEFER = linux_raw_sys::general::SA_NODEFER as _;
+        /// `SA_RESETHAND`
+        const RESETHAND = linux_raw_sys::general::SA_RESETHAND as _;
+        /// `SA_RESTORER`
+        #[cfg(not(target_arch = "riscv64"))]
+        const RESTORER = linux_raw_sys::general::SA_RESTORER as _;
+    }
+}
+
+/// `SIG_DFL`, for use in [`Sigaction::sa_handler`].
+pub const SIG_DFL: usize = 0;
+/// `SIG_IGN`, for use in [`Sigaction::sa_handler`].
+pub const SIG_IGN: usize = 1;
+
+/// The kernel's `struct sigaction`, for use with [`sigaction`].
+///
+/// This is the layout expected by the `rt_sigaction` syscall, which differs
+/// from libc's `struct sigaction`.
+///
+/// [`sigaction`]: crate::runtime::sigaction
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Sigaction {
+    /// The address of the handler function, or [`SIG_DFL`] or [`SIG_IGN`].
+    pub sa_handler: usize,
+    /// Flags modifying the behavior of the handler.
+    pub sa_flags: SigactionFlags,
+    /// The function the handler returns to, which must invoke
+    /// `rt_sigreturn`. Used with [`SigactionFlags::RESTORER`].
+    #[cfg(not(target_arch = "riscv64"))]
+    pub sa_restorer: Option<unsafe extern "C" fn()>,
+    /// Signals to block while the handler runs.
+    pub sa_mask: SigSet,
+}
+
+bitflags! {
+    /// `PIDFD_*` flags for use with [`pidfd_open`].
+    ///
+    /// [`pidfd_open`]: crate::process::pidfd_open
+    pub struct PidfdFlags: c::c_uint {
+        /// `PIDFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
+bitflags! {
+    /// Flags for use with [`pidfd_getfd`].
+    ///
+    /// No flags are currently defined.
+    ///
+    /// [`pidfd_getfd`]: crate::process::pidfd_getfd
+    pub struct PidfdGetfdFlags: c::c_uint {}
+}
+
+bitflags! {
+    /// `CLONE_*` flags for use with [`unshare`].
+    ///
+    /// [`unshare`]: crate::process::unshare
+    #[doc(alias = "CloneFlags")]
+    pub struct UnshareFlags: c::c_uint {
+        /// `CLONE_FILES`
+        const FILES = linux_raw_sys::general::CLONE_FILES;
+        /// `CLONE_FS`
+        const FS = linux_raw_sys::general::CLONE_FS;
+        /// `CLONE_NEWCGROUP` (since Linux 4.6)
+        const NEWCGROUP = linux_raw_sys::v5_4::general::CLONE_NEWCGROUP;
+        /// `CLONE_NEWIPC`
+        const NEWIPC = linux_raw_sys::general::CLONE_NEWIPC;
+        /// `CLONE_NEWNET`
+        const NEWNET = linux_raw_sys::general::CLONE_NEWNET;
+        /// `CLONE_NEWNS`
+        const NEWNS = linux_raw_sys::general::CLONE_NEWNS;
+        /// `CLONE_NEWPID`
+        const NEWPID = linux_raw_sys::general::CLONE_NEWPID;
+        /// `CLONE_NEWTIME` (since Linux 5.6)
+        const NEWTIME = linux_raw_sys::v5_11::general::CLONE_NEWTIME;
+        /// `CLONE_NEWUSER`
+        const NEWUSER = linux_raw_sys::general::CLONE_NEWUSER;
+        /// `CLONE_NEWUTS`
+        const NEWUTS = linux_raw_sys::general::CLONE_NEWUTS;
+        /// `CLONE_SYSVSEM`
+        const SYSVSEM = linux_raw_sys::general::CLONE_SYSVSEM;
+    }
+}
+
+bitflags! {
+    /// `CLONE_NEW*` namespace types, for use with [`setns`].
+    ///
+    /// [`setns`]: crate::process::setns
+    pub struct NamespaceType: c::c_uint {
+        /// `CLONE_NEWCGROUP` (since Linux 4.6)
+        const CGROUP = linux_raw_sys::v5_4::general::CLONE_NEWCGROUP;
+        /// `CLONE_NEWIPC`
+        const IPC = linux_raw_sys::general::CLONE_NEWIPC;
+        /// `CLONE_NEWNET`
+        const NETWORK = linux_raw_sys::general::CLONE_NEWNET;
+        /// `CLONE_NEWNS`
+        const MOUNT = linux_raw_sys::general::CLONE_NEWNS;
+        /// `CLONE_NEWPID`
+        const PID = linux_raw_sys::general::CLONE_NEWPID;
+        /// `CLONE_NEWTIME` (since Linux 5.6)
+        const TIME = linux_raw_sys::v5_11::general::CLONE_NEWTIME;
+        /// `CLONE_NEWUSER`
+        const USER = linux_raw_sys::general::CLONE_NEWUSER;
+        /// `CLONE_NEWUTS`
+        const UTS = linux_raw_sys::general::CLONE_NEWUTS;
+    }
+}
+
+/// `struct seccomp_data`—The syscall a filter or supervisor is examining.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeccompData {
+    /// The syscall number.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` value of the syscall's ABI.
+    pub arch: u32,
+    /// The instruction pointer at the time of the syscall.
+    pub instruction_pointer: u64,
+    /// The syscall arguments.
+    pub args: [u64; 6],
+}
+
+/// `struct seccomp_notif`—A notification received with
+/// [`seccomp_notif_recv`].
+///
+/// [`seccomp_notif_recv`]: crate::process::seccomp_notif_recv
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeccompNotif {
+    /// The notification's id, for use in the response.
+    pub id: u64,
+    /// The raw id of the thread which made the syscall, in the listener's PID
+    /// namespace, or 0 if it isn't visible there.
+    pub pid: u32,
+    /// Currently unused.
+    pub flags: u32,
+    /// The syscall being made.
+    pub data: SeccompData,
+}
+
+/// `struct seccomp_notif_resp`—A response sent with [`seccomp_notif_send`].
+///
+/// [`seccomp_notif_send`]: crate::process::seccomp_notif_send
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeccompNotifResp {
+    /// The id of the notification being responded to.
+    pub id: u64,
+    /// The syscall's return value, if `error` is 0.
+    pub val: i64,
+    /// The syscall's negated error number, or 0.
+    pub error: i32,
+    /// `SECCOMP_USER_NOTIF_FLAG_*` flags.
+    pub flags: u32,
+}
+
+/// `struct seccomp_notif_addfd`
+#[repr(C)]
+pub(crate) struct SeccompNotifAddfd {
+    pub(crate) id: u64,
+    pub(crate) flags: u32,
+    pub(crate) srcfd: u32,
+    pub(crate) newfd: u32,
+    pub(crate) newfd_flags: u32,
+}
+
+/// `SECCOMP_IOCTL_NOTIF_RECV`
+pub(crate) const SECCOMP_IOCTL_NOTIF_RECV: c::c_uint = 0xc050_2100;
+/// `SECCOMP_IOCTL_NOTIF_SEND`
+pub(crate) const SECCOMP_IOCTL_NOTIF_SEND: c::c_uint = 0xc018_2101;
+/// `SECCOMP_IOCTL_NOTIF_ID_VALID`, using the `_IOR` encoding from Linux 5.0
+/// which newer kernels still accept.
+pub(crate) const SECCOMP_IOCTL_NOTIF_ID_VALID: c::c_uint = 0x8008_2102;
+/// `SECCOMP_IOCTL_NOTIF_ADDFD`
+pub(crate) const SECCOMP_IOCTL_NOTIF_ADDFD: c::c_uint = 0x4018_2103;
@@ -18,45 +18,49 @@
 // out into separate files.
 pub(crate) use super::fs::syscalls::*;
 pub(crate) use super::net::syscalls::*;
+pub(crate) use super::termios::syscalls::*;
 
-#[cfg(target_pointer_width = "32")]
-use super::arch::choose::syscall6_readonly;
 use super::arch::choose::{
     syscall0_readonly, syscall1, syscall1_noreturn, syscall1_readonly, syscall2, syscall2_readonly,
     syscall3, syscall3_readonly, syscall4, syscall4_readonly, syscall5, syscall5_readonly,
-    syscall6,
+    syscall6, syscall6_readonly,
 };
 use super::c;
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use super::conv::opt_ref;
 use super::conv::{
-    borrowed_fd, by_mut, by_ref, c_int, c_str, c_uint, clockid_t, const_void_star, no_fd, out,
-    pass_usize, raw_fd, ret, ret_c_int, ret_c_uint, ret_discarded_fd, ret_error, ret_infallible,
-    ret_owned_fd, ret_usize, ret_usize_infallible, ret_void_star, size_of, slice, slice_just_addr,
-    slice_mut, void_star, zero,
+    borrowed_fd, by_mut, by_ref, c_int, c_str, c_uint, clockid_t, const_void_star, mode_as, no_fd,
+    out, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint, ret_discarded_fd, ret_error,
+    ret_infallible, ret_owned_fd, ret_usize, ret_usize_infallible, ret_void_star, size_of, slice,
+    slice_just_addr, slice_mut, void_star, zero,
 };
-use super::fd::{AsFd, BorrowedFd, RawFd};
-use super::fs::AtFlags;
-#[cfg(feature = "procfs")]
-use super::fs::Mode;
+use super::fd::{AsFd, AsRawFd, BorrowedFd, RawFd};
+use super::fs::{AtFlags, Mode, OFlags};
 use super::io::{
     epoll, Advice as IoAdvice, DupFlags, EventfdFlags, MapFlags, MlockFlags, MprotectFlags,
-    MremapFlags, PipeFlags, PollFd, ProtFlags, ReadWriteFlags, UserfaultfdFlags,
+    MremapFlags, OpenptFlags, PipeFlags, PollFd, ProtFlags, ReadWriteFlags, SignalfdFlags,
+    UserfaultfdFlags,
 };
 #[cfg(not(target_os = "wasi"))]
-use super::io::{Termios, Winsize};
+use super::io::{Termios, Termios2, Winsize};
 use super::net::{RecvFlags, SendFlags};
-use super::process::{RawCpuSet, RawNonZeroPid, RawPid, RawUname, Resource};
+use super::process::{
+    NamespaceType, PidfdFlags, PidfdGetfdFlags, RawCpuSet, RawNonZeroPid, RawPid, RawSigSet,
+    RawUname, RemoteIoVec, Resource, SeccompNotif, SeccompNotifAddfd, SeccompNotifResp, Sigaction,
+    SigmaskHow, UnshareFlags, UserCapData, UserCapHeader, SECCOMP_IOCTL_NOTIF_ADDFD,
+    SECCOMP_IOCTL_NOTIF_ID_VALID, SECCOMP_IOCTL_NOTIF_RECV, SECCOMP_IOCTL_NOTIF_SEND,
+};
 use super::rand::GetRandomFlags;
 use super::reg::nr;
 use super::thread::{FutexFlags, FutexOperation};
-use super::time::{ClockId, Timespec};
+use super::time::{ClockId, Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags, Timespec};
 use crate::ffi::ZStr;
 use crate::io::{self, IoSlice, IoSliceMut, OwnedFd};
+use crate::io_uring::{IoUringParams, IoringEnterFlags, IoringRegisterOp};
 #[cfg(feature = "procfs")]
 use crate::path::DecInt;
 use crate::process::{
-    Cpuid, Gid, MembarrierCommand, MembarrierQuery, Pid, Rlimit, Uid, WaitOptions, WaitStatus,
+    Cpuid, Gid, MembarrierCommand, MembarrierQuery, Pid, ResGid, ResUid, Rlimit, Signal, Uid,
+    WaitId, WaitOptions, WaitStatus, WaitidOptions, WaitidStatus,
 };
 use crate::thread::NanosleepRelativeResult;
 use core::cmp;
@@ -72,16 +76,18 @@ use linux_raw_sys::general::{__ARM_NR_set_tls, __NR_mmap2};
 #[cfg(target_arch = "x86_64")]
 use linux_raw_sys::general::{__NR_arch_prctl, ARCH_SET_FS};
 use linux_raw_sys::general::{
-    __NR_chdir, __NR_clock_getres, __NR_clock_nanosleep, __NR_close, __NR_dup, __NR_dup3,
-    __NR_epoll_create1, __NR_epoll_ctl, __NR_exit, __NR_exit_group, __NR_fchdir, __NR_futex,
-    __NR_getcwd, __NR_getpid, __NR_getppid, __NR_getpriority, __NR_gettid, __NR_ioctl,
-    __NR_madvise, __NR_mlock, __NR_mprotect, __NR_munlock, __NR_munmap, __NR_nanosleep, __NR_pipe2,
-    __NR_prctl, __NR_pread64, __NR_preadv, __NR_pwrite64, __NR_pwritev, __NR_read, __NR_readv,
-    __NR_sched_getaffinity, __NR_sched_setaffinity, __NR_sched_yield, __NR_set_tid_address,
-    __NR_setpriority, __NR_uname, __NR_wait4, __NR_write, __NR_writev, __kernel_gid_t,
-    __kernel_pid_t, __kernel_timespec, __kernel_uid_t, epoll_event, EPOLL_CTL_ADD, EPOLL_CTL_DEL,
-    EPOLL_CTL_MOD, FIONBIO, FIONREAD, PR_SET_NAME, SIGCHLD, TCGETS, TIMER_ABSTIME, TIOCEXCL,
-    TIOCGWINSZ, TIOCNXCL,
+    __NR_capget, __NR_capset, __NR_chdir, __NR_clock_getres, __NR_clock_nanosleep, __NR_close,
+    __NR_dup, __NR_dup3, __NR_epoll_create1, __NR_epoll_ctl, __NR_exit, __NR_exit_group,
+    __NR_fchdir, __NR_futex, __NR_getcwd, __NR_getpgid, __NR_getpid, __NR_getppid,
+    __NR_getpriority, __NR_getsid, __NR_gettid, __NR_ioctl, __NR_kill, __NR_madvise, __NR_mlock,
+    __NR_mprotect, __NR_munlock, __NR_munmap, __NR_nanosleep, __NR_pipe2, __NR_prctl, __NR_pread64,
+    __NR_preadv, __NR_ptrace, __NR_pwrite64, __NR_pwritev, __NR_read, __NR_readv,
+    __NR_rt_sigaction, __NR_rt_sigprocmask, __NR_sched_getaffinity, __NR_sched_setaffinity,
+    __NR_sched_yield, __NR_set_tid_address, __NR_setpgid, __NR_setpriority, __NR_setsid,
+    __NR_tgkill, __NR_umask, __NR_uname, __NR_unshare, __NR_wait4, __NR_waitid, __NR_write,
+    __NR_writev, __kernel_gid_t, __kernel_pid_t, __kernel_timespec, __kernel_uid_t, epoll_event,
+    EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD, FIONBIO, FIONREAD, PR_SET_NAME, SIGCHLD, TCGETS,
+    TIMER_ABSTIME, TIOCEXCL, TIOCGWINSZ, TIOCNOTTY, TIOCNXCL, TIOCSCTTY, TIOCSWINSZ,
 };
 #[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
 use linux_raw_sys::general::{__NR_dup2, __NR_pipe, __NR_poll};
@@ -89,24 +95,45 @@ use linux_raw_sys::general::{__NR_dup2, __NR_pipe, __NR_poll};
 use linux_raw_sys::general::{__NR_getegid, __NR_geteuid, __NR_getgid, __NR_getuid};
 #[cfg(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))]
 use linux_raw_sys::general::{__NR_getegid32, __NR_geteuid32, __NR_getgid32, __NR_getuid32};
+#[cfg(not(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm")))]
+use linux_raw_sys::general::{
+    __NR_getgroups, __NR_getresgid, __NR_getresuid, __NR_setgid, __NR_setgroups, __NR_setresgid,
+    __NR_setresuid, __NR_setuid,
+};
+#[cfg(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))]
+use linux_raw_sys::general::{
+    __NR_getgroups32 as __NR_getgroups, __NR_getresgid32 as __NR_getresgid,
+    __NR_getresuid32 as __NR_getresuid, __NR_setgid32 as __NR_setgid,
+    __NR_setgroups32 as __NR_setgroups, __NR_setresgid32 as __NR_setresgid,
+    __NR_setresuid32 as __NR_setresuid, __NR_setuid32 as __NR_setuid,
+};
 #[cfg(target_arch = "x86")]
 use linux_raw_sys::general::{__NR_mmap2, __NR_set_thread_area};
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use linux_raw_sys::general::{__NR_ppoll, sigset_t};
-use linux_raw_sys::v5_11::general::__NR_mremap;
+use linux_raw_sys::v5_11::general::{__NR_mremap, __NR_pidfd_getfd};
 use linux_raw_sys::v5_4::general::{
-    __NR_clone, __NR_eventfd2, __NR_execve, __NR_execveat, __NR_getrandom, __NR_membarrier,
-    __NR_mlock2, __NR_preadv2, __NR_prlimit64, __NR_pwritev2, __NR_userfaultfd,
+    __NR_clone, __NR_eventfd2, __NR_execve, __NR_execveat, __NR_getrandom, __NR_io_uring_enter,
+    __NR_io_uring_register, __NR_io_uring_setup, __NR_membarrier, __NR_mlock2, __NR_pidfd_open,
+    __NR_pidfd_send_signal, __NR_preadv2, __NR_prlimit64, __NR_process_vm_readv,
+    __NR_process_vm_writev, __NR_pwritev2, __NR_seccomp, __NR_setns, __NR_signalfd4,
+    __NR_timerfd_create, __NR_userfaultfd, siginfo_t, P_ALL, P_PGID, P_PID, P_PIDFD,
 };
 #[cfg(target_pointer_width = "64")]
+use linux_raw_sys::v5_4::general::{__NR_timerfd_gettime, __NR_timerfd_settime};
+#[cfg(target_pointer_width = "64")]
 use {super::conv::loff_t_from_u64, linux_raw_sys::general::__NR_mmap};
 #[cfg(target_pointer_width = "32")]
 use {
     super::conv::{hi, lo},
     linux_raw_sys::{
-        general::__NR_getrlimit,
-        general::timespec as __kernel_old_timespec,
-        v5_4::general::{__NR_clock_getres_time64, __NR_clock_nanosleep_time64, __NR_futex_time64},
+        general::{__NR_getrlimit, __NR_setrlimit},
+        general::{itimerspec as __kernel_old_itimerspec, timespec as __kernel_old_timespec},
+        v5_4::general::{
+            __NR_clock_getres_time64, __NR_clock_nanosleep_time64, __NR_futex_time64,
+            __NR_timerfd_gettime, __NR_timerfd_gettime64, __NR_timerfd_settime,
+            __NR_timerfd_settime64,
+        },
     },
 };
 
@@ -928,6 +955,179 @@ pub(crate) fn clock_nanosleep_absolute(id: ClockId, req: &__kernel_timespec) ->
     }
 }
 
+#[inline]
+pub(crate) fn timerfd_create(clockid: TimerfdClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2_readonly(
+            nr(__NR_timerfd_create),
+            c_uint(clockid as c::c_uint),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn timerfd_settime(
+    fd: BorrowedFd<'_>,
+    flags: TimerfdTimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut result = MaybeUninit::<Itimerspec>::uninit();
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_timerfd_settime64),
+            borrowed_fd(fd),
+            c_uint(flags.bits()),
+            by_ref(new_value),
+            out(&mut result),
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Error::NOSYS {
+                timerfd_settime_old(fd, flags, new_value, &mut result)
+            } else {
+                Err(err)
+            }
+        })?;
+        Ok(result.assume_init())
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall4(
+            nr(__NR_timerfd_settime),
+            borrowed_fd(fd),
+            c_uint(flags.bits()),
+            by_ref(new_value),
+            out(&mut result),
+        ))?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn timerfd_settime_old(
+    fd: BorrowedFd<'_>,
+    flags: TimerfdTimerFlags,
+    new_value: &Itimerspec,
+    result: &mut MaybeUninit<Itimerspec>,
+) -> io::Result<()> {
+    let mut old_result = MaybeUninit::<__kernel_old_itimerspec>::uninit();
+
+    // Convert `new_value` to the old `__kernel_old_itimerspec` format.
+    let old_new_value = __kernel_old_itimerspec {
+        it_interval: __kernel_old_timespec {
+            tv_sec: new_value
+                .it_interval
+                .tv_sec
+                .try_into()
+                .map_err(|_| io::Error::INVAL)?,
+            tv_nsec: new_value
+                .it_interval
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Error::INVAL)?,
+        },
+        it_value: __kernel_old_timespec {
+            tv_sec: new_value
+                .it_value
+                .tv_sec
+                .try_into()
+                .map_err(|_| io::Error::INVAL)?,
+            tv_nsec: new_value
+                .it_value
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Error::INVAL)?,
+        },
+    };
+    ret(syscall4(
+        nr(__NR_timerfd_settime),
+        borrowed_fd(fd),
+        c_uint(flags.bits()),
+        by_ref(&old_new_value),
+        out(&mut old_result),
+    ))?;
+
+    // Convert the result back to the new `Itimerspec` format.
+    let old_result = old_result.assume_init();
+    *result.as_mut_ptr() = Itimerspec {
+        it_interval: __kernel_timespec {
+            tv_sec: old_result.it_interval.tv_sec.into(),
+            tv_nsec: old_result.it_interval.tv_nsec.into(),
+        },
+        it_value: __kernel_timespec {
+            tv_sec: old_result.it_value.tv_sec.into(),
+            tv_nsec: old_result.it_value.tv_nsec.into(),
+        },
+    };
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn timerfd_gettime(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
+    let mut result = MaybeUninit::<Itimerspec>::uninit();
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_timerfd_gettime64),
+            borrowed_fd(fd),
+            out(&mut result),
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Error::NOSYS {
+                timerfd_gettime_old(fd, &mut result)
+            } else {
+                Err(err)
+            }
+        })?;
+        Ok(result.assume_init())
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall2(
+            nr(__NR_timerfd_gettime),
+            borrowed_fd(fd),
+            out(&mut result),
+        ))?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn timerfd_gettime_old(
+    fd: BorrowedFd<'_>,
+    result: &mut MaybeUninit<Itimerspec>,
+) -> io::Result<()> {
+    let mut old_result = MaybeUninit::<__kernel_old_itimerspec>::uninit();
+    ret(syscall2(
+        nr(__NR_timerfd_gettime),
+        borrowed_fd(fd),
+        out(&mut old_result),
+    ))?;
+
+    // Convert the result to the new `Itimerspec` format.
+    let old_result = old_result.assume_init();
+    *result.as_mut_ptr() = Itimerspec {
+        it_interval: __kernel_timespec {
+            tv_sec: old_result.it_interval.tv_sec.into(),
+            tv_nsec: old_result.it_interval.tv_nsec.into(),
+        },
+        it_value: __kernel_timespec {
+            tv_sec: old_result.it_value.tv_sec.into(),
+            tv_nsec: old_result.it_value.tv_nsec.into(),
+        },
+    };
+    Ok(())
+}
+
 #[inline]
 pub(crate) fn getcwd(buf: &mut [u8]) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -995,6 +1195,35 @@ pub(crate) fn ioctl_tiocnxcl(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(syscall2(nr(__NR_ioctl), borrowed_fd(fd), c_uint(TIOCNXCL))) }
 }
 
+#[inline]
+pub(crate) fn ioctl_tiocswinsz(fd: BorrowedFd<'_>, winsize: &Winsize) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCSWINSZ),
+            by_ref(winsize),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_tiocsctty(fd: BorrowedFd<'_>, steal: bool) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCSCTTY),
+            c_uint(steal as u32),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_tiocnotty(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(syscall2(nr(__NR_ioctl), borrowed_fd(fd), c_uint(TIOCNOTTY))) }
+}
+
 #[inline]
 pub(crate) fn ioctl_tcgets(fd: BorrowedFd<'_>) -> io::Result<Termios> {
     unsafe {
@@ -1009,6 +1238,103 @@ pub(crate) fn ioctl_tcgets(fd: BorrowedFd<'_>) -> io::Result<Termios> {
     }
 }
 
+// linux-raw-sys doesn't define the `termios2` ioctl codes, so define them
+// here. These are `_IOR('T', 0x2A, struct termios2)` and
+// `_IOW('T', 0x2B, struct termios2)`, which have the same values on all
+// architectures supported by the `linux_raw` backend.
+const TCGETS2: c::c_uint = 0x802c_542a;
+const TCSETS2: c::c_uint = 0x402c_542b;
+
+#[inline]
+pub(crate) fn ioctl_tcgets2(fd: BorrowedFd<'_>) -> io::Result<Termios2> {
+    unsafe {
+        let mut result = MaybeUninit::<Termios2>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCGETS2),
+            out(&mut result),
+        ))
+        .map(|()| result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_tcsets2(fd: BorrowedFd<'_>, termios: &Termios2) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCSETS2),
+            by_ref(termios),
+        ))
+    }
+}
+
+// linux-raw-sys doesn't define the pseudoterminal ioctl codes either. These
+// are `_IOR('T', 0x30, unsigned int)`, `_IOW('T', 0x31, int)`, and
+// `_IO('T', 0x41)`.
+const TIOCGPTN: c::c_uint = 0x8004_5430;
+const TIOCSPTLCK: c::c_uint = 0x4004_5431;
+const TIOCGPTPEER: c::c_uint = 0x5441;
+
+#[inline]
+pub(crate) fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
+    openat(
+        crate::fs::cwd().as_fd(),
+        zstr!("/dev/ptmx"),
+        OFlags::from_bits_truncate(flags.bits()),
+        Mode::empty(),
+    )
+}
+
+#[inline]
+pub(crate) fn grantpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    // With devpts, the slave is created with the right ownership and
+    // permissions already, so like musl we just check that `fd` is a
+    // pseudoterminal master.
+    ioctl_tiocgptn(fd).map(|_| ())
+}
+
+#[inline]
+pub(crate) fn unlockpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    let unlock: c::c_int = 0;
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCSPTLCK),
+            by_ref(&unlock),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_tiocgptn(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    unsafe {
+        let mut result = MaybeUninit::<c::c_uint>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCGPTN),
+            out(&mut result),
+        ))
+        .map(|()| result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_tiocgptpeer(fd: BorrowedFd<'_>, flags: OpenptFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCGPTPEER),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn dup(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall1_readonly(nr(__NR_dup), borrowed_fd(fd))) }
@@ -1093,6 +1419,79 @@ pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd>
     ret_owned_fd(syscall1(nr(__NR_userfaultfd), c_uint(flags.bits())))
 }
 
+#[inline]
+pub(crate) fn signalfd(mask: &RawSigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall4_readonly(
+            nr(__NR_signalfd4),
+            c_int(-1),
+            by_ref(mask),
+            size_of::<RawSigSet, _>(),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &RawSigSet) -> io::Result<()> {
+    unsafe {
+        ret_discarded_fd(syscall4_readonly(
+            nr(__NR_signalfd4),
+            borrowed_fd(fd),
+            by_ref(mask),
+            size_of::<RawSigSet, _>(),
+            zero(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn io_uring_setup(entries: u32, params: &mut IoUringParams) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2(
+            nr(__NR_io_uring_setup),
+            c_uint(entries),
+            by_mut(params),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn io_uring_register(
+    fd: BorrowedFd<'_>,
+    opcode: IoringRegisterOp,
+    arg: *const c::c_void,
+    nr_args: u32,
+) -> io::Result<u32> {
+    ret_c_uint(syscall4(
+        nr(__NR_io_uring_register),
+        borrowed_fd(fd),
+        c_uint(opcode as u32),
+        const_void_star(arg),
+        c_uint(nr_args),
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn io_uring_enter(
+    fd: BorrowedFd<'_>,
+    to_submit: u32,
+    min_complete: u32,
+    flags: IoringEnterFlags,
+    arg: *const c::c_void,
+    size: usize,
+) -> io::Result<u32> {
+    ret_c_uint(syscall6(
+        nr(__NR_io_uring_enter),
+        borrowed_fd(fd),
+        c_uint(to_submit),
+        c_uint(min_complete),
+        c_uint(flags.bits()),
+        const_void_star(arg),
+        pass_usize(size),
+    ))
+}
+
 #[inline]
 pub(crate) fn getpid() -> Pid {
     unsafe {
@@ -1110,6 +1509,67 @@ pub(crate) fn getppid() -> Option<Pid> {
     }
 }
 
+#[inline]
+pub(crate) fn getpgrp() -> Option<Pid> {
+    // Not all architectures have `getpgrp`, so use `getpgid(0)`, which can't
+    // fail for the calling process.
+    unsafe {
+        let pgid: i32 =
+            ret_usize_infallible(syscall1_readonly(nr(__NR_getpgid), c_uint(0))) as __kernel_pid_t;
+        Pid::from_raw(pgid as u32)
+    }
+}
+
+#[inline]
+pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let pgid: i32 = ret_usize(syscall1_readonly(
+            nr(__NR_getpgid),
+            c_uint(Pid::as_raw(pid)),
+        ))? as __kernel_pid_t;
+        pid_from_raw(pgid)
+    }
+}
+
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_setpgid),
+            c_uint(Pid::as_raw(pid)),
+            c_uint(Pid::as_raw(pgid)),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let sid: i32 = ret_usize(syscall1_readonly(nr(__NR_getsid), c_uint(Pid::as_raw(pid))))?
+            as __kernel_pid_t;
+        pid_from_raw(sid)
+    }
+}
+
+#[inline]
+pub(crate) fn setsid() -> io::Result<Pid> {
+    unsafe {
+        let sid: i32 = ret_usize(syscall0_readonly(nr(__NR_setsid)))? as __kernel_pid_t;
+        pid_from_raw(sid)
+    }
+}
+
+/// The kernel reports a process group or session ID of zero when it isn't
+/// visible in the caller's PID namespace. `Pid` can't represent zero, so fail
+/// with `OPNOTSUPP` in that case.
+#[inline]
+fn pid_from_raw(pid: __kernel_pid_t) -> io::Result<Pid> {
+    match RawNonZeroPid::new(pid as u32) {
+        Some(pid) => Ok(unsafe { Pid::from_raw_nonzero(pid) }),
+        None => Err(io::Error::OPNOTSUPP),
+    }
+}
+
 #[inline]
 pub(crate) fn getgid() -> Gid {
     #[cfg(any(target_arch = "x86", target_arch = "sparc", target_arch = "arm"))]
@@ -1171,20 +1631,128 @@ pub(crate) fn geteuid() -> Uid {
 }
 
 #[inline]
-pub(crate) fn gettid() -> Pid {
+pub(crate) fn getresuid() -> ResUid {
+    let mut ruid = MaybeUninit::<c::c_uint>::uninit();
+    let mut euid = MaybeUninit::<c::c_uint>::uninit();
+    let mut suid = MaybeUninit::<c::c_uint>::uninit();
     unsafe {
-        let tid: i32 = ret_usize_infallible(syscall0_readonly(nr(__NR_gettid))) as __kernel_pid_t;
-        debug_assert_ne!(tid, 0);
-        Pid::from_raw_nonzero(RawNonZeroPid::new_unchecked(tid as u32))
+        ret_infallible(syscall3(
+            nr(__NR_getresuid),
+            out(&mut ruid),
+            out(&mut euid),
+            out(&mut suid),
+        ));
+        ResUid {
+            ruid: Uid::from_raw(ruid.assume_init()),
+            euid: Uid::from_raw(euid.assume_init()),
+            suid: Uid::from_raw(suid.assume_init()),
+        }
     }
 }
 
-#[cfg(feature = "procfs")]
 #[inline]
-pub(crate) fn ttyname(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
-    let fd_stat = fstat(fd)?;
+pub(crate) fn getresgid() -> ResGid {
+    let mut rgid = MaybeUninit::<c::c_uint>::uninit();
+    let mut egid = MaybeUninit::<c::c_uint>::uninit();
+    let mut sgid = MaybeUninit::<c::c_uint>::uninit();
+    unsafe {
+        ret_infallible(syscall3(
+            nr(__NR_getresgid),
+            out(&mut rgid),
+            out(&mut egid),
+            out(&mut sgid),
+        ));
+        ResGid {
+            rgid: Gid::from_raw(rgid.assume_init()),
+            egid: Gid::from_raw(egid.assume_init()),
+            sgid: Gid::from_raw(sgid.assume_init()),
+        }
+    }
+}
 
-    // Quick check: if `fd` isn't a character device, it's not a tty.
+#[inline]
+pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
+    let len = cmp::min(buf.len(), c::c_int::MAX as usize);
+    unsafe {
+        ret_usize(syscall2(
+            nr(__NR_getgroups),
+            pass_usize(len),
+            void_star(buf.as_mut_ptr().cast()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn umask(mode: Mode) -> Mode {
+    unsafe {
+        // `umask` always succeeds.
+        Mode::from_bits_truncate(ret_usize_infallible(syscall1_readonly(
+            nr(__NR_umask),
+            mode_as(mode),
+        )) as _)
+    }
+}
+
+#[inline]
+pub(crate) fn set_thread_uid(uid: Uid) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_setuid), c_uint(uid.as_raw()))) }
+}
+
+#[inline]
+pub(crate) fn set_thread_res_uid(ruid: Uid, euid: Uid, suid: Uid) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_setresuid),
+            c_uint(ruid.as_raw()),
+            c_uint(euid.as_raw()),
+            c_uint(suid.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn set_thread_gid(gid: Gid) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_setgid), c_uint(gid.as_raw()))) }
+}
+
+#[inline]
+pub(crate) fn set_thread_res_gid(rgid: Gid, egid: Gid, sgid: Gid) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_setresgid),
+            c_uint(rgid.as_raw()),
+            c_uint(egid.as_raw()),
+            c_uint(sgid.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn set_thread_groups(groups: &[Gid]) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_setgroups),
+            pass_usize(groups.len()),
+            slice_just_addr(groups),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn gettid() -> Pid {
+    unsafe {
+        let tid: i32 = ret_usize_infallible(syscall0_readonly(nr(__NR_gettid))) as __kernel_pid_t;
+        debug_assert_ne!(tid, 0);
+        Pid::from_raw_nonzero(RawNonZeroPid::new_unchecked(tid as u32))
+    }
+}
+
+#[cfg(feature = "procfs")]
+#[inline]
+pub(crate) fn ttyname(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
+    let fd_stat = fstat(fd)?;
+
+    // Quick check: if `fd` isn't a character device, it's not a tty.
     if (fd_stat.st_mode & Mode::IFMT.bits()) != Mode::IFCHR.bits() {
         return Err(crate::io::Error::NOTTY);
     }
@@ -1507,22 +2075,7 @@ pub(crate) fn getrlimit(limit: Resource) -> Rlimit {
             void_star(core::ptr::null_mut()),
             out(&mut result),
         )) {
-            Ok(()) => {
-                let result = result.assume_init();
-                let current =
-                    if result.rlim_cur == linux_raw_sys::v5_4::general::RLIM64_INFINITY as _ {
-                        None
-                    } else {
-                        Some(result.rlim_cur)
-                    };
-                let maximum =
-                    if result.rlim_max == linux_raw_sys::v5_4::general::RLIM64_INFINITY as _ {
-                        None
-                    } else {
-                        Some(result.rlim_max)
-                    };
-                Rlimit { current, maximum }
-            }
+            Ok(()) => rlimit_from_linux(result.assume_init()),
             Err(e) => {
                 debug_assert_eq!(e, io::Error::NOSYS);
                 let mut result = MaybeUninit::<linux_raw_sys::general::rlimit>::uninit();
@@ -1540,7 +2093,7 @@ pub(crate) fn getrlimit(limit: Resource) -> Rlimit {
                 let maximum = if result.rlim_max == linux_raw_sys::general::RLIM_INFINITY as _ {
                     None
                 } else {
-                    result.rlim_cur.try_into().ok()
+                    result.rlim_max.try_into().ok()
                 };
                 Rlimit { current, maximum }
             }
@@ -1555,21 +2108,325 @@ pub(crate) fn getrlimit(limit: Resource) -> Rlimit {
             void_star(core::ptr::null_mut()),
             out(&mut result),
         ));
-        let result = result.assume_init();
-        let current = if result.rlim_cur == linux_raw_sys::general::RLIM_INFINITY as _ {
-            None
-        } else {
-            Some(result.rlim_cur)
-        };
-        let maximum = if result.rlim_max == linux_raw_sys::general::RLIM_INFINITY as _ {
-            None
-        } else {
-            Some(result.rlim_max)
-        };
-        Rlimit { current, maximum }
+        rlimit_from_linux(result.assume_init())
     }
 }
 
+#[inline]
+pub(crate) fn setrlimit(limit: Resource, new: Rlimit) -> io::Result<()> {
+    let lim = rlimit_to_linux(new);
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        match ret(syscall4_readonly(
+            nr(__NR_prlimit64),
+            c_uint(0),
+            c_uint(limit as c::c_uint),
+            by_ref(&lim),
+            void_star(core::ptr::null_mut()),
+        )) {
+            Err(io::Error::NOSYS) => {
+                let lim = linux_raw_sys::general::rlimit {
+                    rlim_cur: match new.current {
+                        Some(r) => r.try_into().map_err(|_| io::Error::INVAL)?,
+                        None => linux_raw_sys::general::RLIM_INFINITY as _,
+                    },
+                    rlim_max: match new.maximum {
+                        Some(r) => r.try_into().map_err(|_| io::Error::INVAL)?,
+                        None => linux_raw_sys::general::RLIM_INFINITY as _,
+                    },
+                };
+                ret(syscall2_readonly(
+                    nr(__NR_setrlimit),
+                    c_uint(limit as c::c_uint),
+                    by_ref(&lim),
+                ))
+            }
+            otherwise => otherwise,
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall4_readonly(
+            nr(__NR_prlimit64),
+            c_uint(0),
+            c_uint(limit as c::c_uint),
+            by_ref(&lim),
+            void_star(core::ptr::null_mut()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn prlimit(
+    pid: Option<Pid>,
+    limit: Resource,
+    new: Option<Rlimit>,
+) -> io::Result<Rlimit> {
+    let lim = new.map(rlimit_to_linux);
+    let mut result = MaybeUninit::<linux_raw_sys::v5_4::general::rlimit64>::uninit();
+    unsafe {
+        ret(syscall4(
+            nr(__NR_prlimit64),
+            c_uint(Pid::as_raw(pid)),
+            c_uint(limit as c::c_uint),
+            opt_ref(lim.as_ref()),
+            out(&mut result),
+        ))?;
+        Ok(rlimit_from_linux(result.assume_init()))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn prctl(
+    option: c::c_int,
+    arg2: *mut c::c_void,
+    arg3: *mut c::c_void,
+    arg4: *mut c::c_void,
+    arg5: *mut c::c_void,
+) -> io::Result<usize> {
+    ret_usize(syscall5(
+        nr(__NR_prctl),
+        c_int(option),
+        void_star(arg2),
+        void_star(arg3),
+        void_star(arg4),
+        void_star(arg5),
+    ))
+}
+
+#[inline]
+pub(crate) fn capget(header: &mut UserCapHeader, data: &mut [UserCapData; 2]) -> io::Result<()> {
+    unsafe { ret(syscall2(nr(__NR_capget), by_mut(header), by_mut(data))) }
+}
+
+#[inline]
+pub(crate) fn capset(header: &mut UserCapHeader, data: &[UserCapData; 2]) -> io::Result<()> {
+    unsafe { ret(syscall2(nr(__NR_capset), by_mut(header), by_ref(data))) }
+}
+
+#[inline]
+pub(crate) unsafe fn seccomp(
+    operation: c::c_uint,
+    flags: c::c_uint,
+    args: *mut c::c_void,
+) -> io::Result<c::c_int> {
+    ret_c_int(syscall3(
+        nr(__NR_seccomp),
+        c_uint(operation),
+        c_uint(flags),
+        void_star(args),
+    ))
+}
+
+#[inline]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &[IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(&local[..cmp::min(local.len(), max_iov())]);
+    let (remote_addr, remote_len) = slice(&remote[..cmp::min(remote.len(), max_iov())]);
+
+    unsafe {
+        ret_usize(syscall6(
+            nr(__NR_process_vm_readv),
+            c_uint(pid.as_raw_nonzero().get()),
+            local_addr,
+            local_len,
+            remote_addr,
+            remote_len,
+            pass_usize(0),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(&local[..cmp::min(local.len(), max_iov())]);
+    let (remote_addr, remote_len) = slice(&remote[..cmp::min(remote.len(), max_iov())]);
+
+    unsafe {
+        ret_usize(syscall6_readonly(
+            nr(__NR_process_vm_writev),
+            c_uint(pid.as_raw_nonzero().get()),
+            local_addr,
+            local_len,
+            remote_addr,
+            remote_len,
+            pass_usize(0),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Option<Pid>,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<usize> {
+    ret_usize(syscall4(
+        nr(__NR_ptrace),
+        c_uint(request),
+        c_uint(Pid::as_raw(pid)),
+        void_star(addr),
+        void_star(data),
+    ))
+}
+
+#[inline]
+pub(crate) fn unshare(flags: UnshareFlags) -> io::Result<()> {
+    unsafe { ret(syscall1_readonly(nr(__NR_unshare), c_uint(flags.bits()))) }
+}
+
+#[inline]
+pub(crate) fn setns(fd: BorrowedFd<'_>, nstype: NamespaceType) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_setns),
+            borrowed_fd(fd),
+            c_uint(nstype.bits()),
+        ))
+    }
+}
+
+const NS_GET_USERNS: c::c_uint = 0xb701;
+const NS_GET_PARENT: c::c_uint = 0xb702;
+const NS_GET_NSTYPE: c::c_uint = 0xb703;
+const NS_GET_OWNER_UID: c::c_uint = 0xb704;
+
+#[inline]
+pub(crate) fn ioctl_ns_get_userns(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(NS_GET_USERNS),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_ns_get_parent(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(NS_GET_PARENT),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_ns_get_nstype(fd: BorrowedFd<'_>) -> io::Result<NamespaceType> {
+    unsafe {
+        let nstype = ret_c_int(syscall2_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(NS_GET_NSTYPE),
+        ))?;
+        Ok(NamespaceType::from_bits_truncate(nstype as c::c_uint))
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_ns_get_owner_uid(fd: BorrowedFd<'_>) -> io::Result<Uid> {
+    let mut uid = MaybeUninit::<__kernel_uid_t>::uninit();
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(NS_GET_OWNER_UID),
+            out(&mut uid),
+        ))?;
+        Ok(Uid::from_raw(uid.assume_init()))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_recv(fd: BorrowedFd<'_>, notif: &mut SeccompNotif) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(SECCOMP_IOCTL_NOTIF_RECV),
+            by_mut(notif),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_send(fd: BorrowedFd<'_>, resp: &SeccompNotifResp) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(SECCOMP_IOCTL_NOTIF_SEND),
+            by_ref(resp),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_id_valid(fd: BorrowedFd<'_>, id: u64) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(SECCOMP_IOCTL_NOTIF_ID_VALID),
+            by_ref(&id),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_addfd(
+    fd: BorrowedFd<'_>,
+    addfd: &SeccompNotifAddfd,
+) -> io::Result<RawFd> {
+    unsafe {
+        ret_c_int(syscall3_readonly(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(SECCOMP_IOCTL_NOTIF_ADDFD),
+            by_ref(addfd),
+        ))
+    }
+}
+
+/// Convert a Rust [`Rlimit`] to a C `rlimit64`.
+#[inline]
+fn rlimit_to_linux(lim: Rlimit) -> linux_raw_sys::v5_4::general::rlimit64 {
+    use linux_raw_sys::v5_4::general::{rlimit64, RLIM64_INFINITY};
+
+    rlimit64 {
+        rlim_cur: lim.current.unwrap_or(RLIM64_INFINITY as _),
+        rlim_max: lim.maximum.unwrap_or(RLIM64_INFINITY as _),
+    }
+}
+
+/// Convert a C `rlimit64` to a Rust [`Rlimit`].
+#[inline]
+fn rlimit_from_linux(lim: linux_raw_sys::v5_4::general::rlimit64) -> Rlimit {
+    use linux_raw_sys::v5_4::general::RLIM64_INFINITY;
+
+    let current = if lim.rlim_cur == RLIM64_INFINITY as _ {
+        None
+    } else {
+        Some(lim.rlim_cur)
+    };
+    let maximum = if lim.rlim_max == RLIM64_INFINITY as _ {
+        None
+    } else {
+        Some(lim.rlim_max)
+    };
+    Rlimit { current, maximum }
+}
+
 #[inline]
 pub(crate) unsafe fn fork() -> io::Result<Option<Pid>> {
     let pid = ret_c_uint(syscall5_readonly(
@@ -1645,6 +2502,164 @@ pub(crate) fn _waitpid(
     }
 }
 
+#[inline]
+pub(crate) fn waitid(id: WaitId<'_>, options: WaitidOptions) -> io::Result<Option<WaitidStatus>> {
+    // Get the id to wait on.
+    match id {
+        WaitId::All => _waitid(P_ALL, 0, options),
+        WaitId::Pid(pid) => _waitid(P_PID, pid.as_raw_nonzero().get(), options),
+        WaitId::Pgid(pgid) => _waitid(P_PGID, Pid::as_raw(pgid), options),
+        WaitId::PidFd(fd) => _waitid(P_PIDFD, fd.as_raw_fd() as _, options),
+    }
+}
+
+#[inline]
+fn _waitid(
+    idtype: c::c_uint,
+    id: c::c_uint,
+    options: WaitidOptions,
+) -> io::Result<Option<WaitidStatus>> {
+    // `waitid` can return successfully without initializing the struct (no
+    // children found when using `WNOHANG`)
+    let mut info = MaybeUninit::<siginfo_t>::zeroed();
+    unsafe {
+        ret(syscall5(
+            nr(__NR_waitid),
+            c_uint(idtype),
+            c_uint(id),
+            out(&mut info),
+            c_uint(options.bits()),
+            zero(),
+        ))?;
+        let info = info.assume_init().__bindgen_anon_1.__bindgen_anon_1;
+        let sigchld = info._sifields._sigchld;
+        Ok(RawNonZeroPid::new(sigchld._pid as _).map(|non_zero| {
+            WaitidStatus::new(
+                info.si_code,
+                Pid::from_raw_nonzero(non_zero),
+                Uid::from_raw(sigchld._uid),
+                sigchld._status,
+            )
+        }))
+    }
+}
+
+#[inline]
+pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall2_readonly(
+            nr(__NR_pidfd_open),
+            c_uint(pid.as_raw_nonzero().get()),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn pidfd_send_signal(fd: BorrowedFd<'_>, sig: Signal) -> io::Result<()> {
+    unsafe {
+        ret(syscall4_readonly(
+            nr(__NR_pidfd_send_signal),
+            borrowed_fd(fd),
+            c_int(sig.as_raw()),
+            zero(),
+            zero(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn pidfd_getfd(
+    fd: BorrowedFd<'_>,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall3_readonly(
+            nr(__NR_pidfd_getfd),
+            borrowed_fd(fd),
+            raw_fd(targetfd),
+            c_uint(flags.bits()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_kill),
+            c_uint(pid.as_raw_nonzero().get()),
+            c_int(sig.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_kill),
+            c_int(-(pid.as_raw_nonzero().get() as i32)),
+            c_int(sig.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn kill_current_process_group(sig: Signal) -> io::Result<()> {
+    unsafe {
+        ret(syscall2_readonly(
+            nr(__NR_kill),
+            c_uint(0),
+            c_int(sig.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe {
+        ret(syscall3_readonly(
+            nr(__NR_tgkill),
+            c_uint(tgid.as_raw_nonzero().get()),
+            c_uint(tid.as_raw_nonzero().get()),
+            c_int(sig.as_raw()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sigprocmask(
+    how: SigmaskHow,
+    set: Option<&RawSigSet>,
+    old: &mut RawSigSet,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall4(
+            nr(__NR_rt_sigprocmask),
+            c_uint(how as u32),
+            opt_ref(set),
+            by_mut(old),
+            size_of::<RawSigSet, _>(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn sigaction(sig: Signal, new: Option<Sigaction>) -> io::Result<Sigaction> {
+    let mut old = MaybeUninit::<Sigaction>::uninit();
+    let new = opt_ref(new.as_ref());
+    ret(syscall4(
+        nr(__NR_rt_sigaction),
+        c_int(sig.as_raw()),
+        new,
+        out(&mut old),
+        size_of::<RawSigSet, _>(),
+    ))?;
+    Ok(old.assume_init())
+}
+
 pub(crate) mod tls {
     #[cfg(target_arch = "x86")]
     use super::super::thread::tls::UserDesc;
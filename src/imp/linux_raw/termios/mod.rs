@@ -0,0 +1,11 @@
+mod types;
+
+pub(crate) mod syscalls;
+
+pub use types::{
+    Action, ControlModes, InputModes, LocalModes, OptionalActions, OutputModes, QueueSelector,
+    Speed, B0, B1000000, B110, B115200, B1152000, B1200, B134, B150, B1500000, B1800, B19200, B200,
+    B2000000, B230400, B2400, B2500000, B300, B3000000, B3500000, B38400, B4000000, B460800, B4800,
+    B50, B500000, B57600, B576000, B600, B75, B921600, B9600, VDISCARD, VEOF, VEOL, VEOL2, VERASE,
+    VINTR, VKILL, VLNEXT, VMIN, VQUIT, VREPRINT, VSTART, VSTOP, VSUSP, VSWTC, VTIME, VWERASE,
+};
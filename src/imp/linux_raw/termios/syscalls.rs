@@ -0,0 +1,195 @@
+//! Safe (where possible) wrappers around terminal system calls.
+//!
+//! # Safety
+//!
+//! This file performs raw system calls, and sometimes passes them
+//! uninitialized memory buffers. The signatures in this file are currently
+//! manually maintained and must correspond with the signatures of the actual
+//! Linux syscalls.
+#![allow(unsafe_code)]
+
+use super::super::arch::choose::syscall3;
+use super::super::c;
+use super::super::conv::{borrowed_fd, by_ref, c_uint, out, ret};
+use super::super::fd::BorrowedFd;
+use super::super::io::Termios;
+use super::super::process::RawNonZeroPid;
+use super::super::reg::nr;
+use super::{Action, OptionalActions, QueueSelector, Speed};
+use crate::io;
+use crate::process::Pid;
+use core::mem::MaybeUninit;
+use linux_raw_sys::general::{
+    __NR_ioctl, CBAUD, TCFLSH, TCGETS, TCSBRK, TCSETS, TCSETSF, TCSETSW, TCXONC, TIOCGPGRP,
+    TIOCGSID, TIOCSPGRP,
+};
+
+#[inline]
+pub(crate) fn tcgetattr(fd: BorrowedFd<'_>) -> io::Result<Termios> {
+    unsafe {
+        let mut result = MaybeUninit::<Termios>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCGETS),
+            out(&mut result),
+        ))
+        .map(|()| result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn tcsetattr(
+    fd: BorrowedFd<'_>,
+    optional_actions: OptionalActions,
+    termios: &Termios,
+) -> io::Result<()> {
+    let request = match optional_actions {
+        OptionalActions::Now => TCSETS,
+        OptionalActions::Drain => TCSETSW,
+        OptionalActions::Flush => TCSETSF,
+    };
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(request),
+            by_ref(termios),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcsendbreak(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCSBRK),
+            c_uint(0),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcdrain(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCSBRK),
+            c_uint(1),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcflush(fd: BorrowedFd<'_>, queue_selector: QueueSelector) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCFLSH),
+            c_uint(queue_selector as u32),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcflow(fd: BorrowedFd<'_>, action: Action) -> io::Result<()> {
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TCXONC),
+            c_uint(action as u32),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcgetpgrp(fd: BorrowedFd<'_>) -> io::Result<Pid> {
+    unsafe {
+        let mut result = MaybeUninit::<c::c_int>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCGPGRP),
+            out(&mut result),
+        ))?;
+        pid_from_ioctl(result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn tcsetpgrp(fd: BorrowedFd<'_>, pid: Pid) -> io::Result<()> {
+    let pid: c::c_int = pid.as_raw_nonzero().get() as _;
+    unsafe {
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCSPGRP),
+            by_ref(&pid),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn tcgetsid(fd: BorrowedFd<'_>) -> io::Result<Pid> {
+    unsafe {
+        let mut result = MaybeUninit::<c::c_int>::uninit();
+        ret(syscall3(
+            nr(__NR_ioctl),
+            borrowed_fd(fd),
+            c_uint(TIOCGSID),
+            out(&mut result),
+        ))?;
+        pid_from_ioctl(result.assume_init())
+    }
+}
+
+/// The kernel reports a process group or session ID of zero when there is
+/// none, or when it isn't visible in the caller's PID namespace. `Pid` can't
+/// represent zero, so fail with `OPNOTSUPP` in that case.
+#[inline]
+fn pid_from_ioctl(pid: c::c_int) -> io::Result<Pid> {
+    match RawNonZeroPid::new(pid as _) {
+        Some(pid) => Ok(unsafe { Pid::from_raw_nonzero(pid) }),
+        None => Err(io::Error::OPNOTSUPP),
+    }
+}
+
+// The speed functions don't make system calls; they just read and write the
+// `CBAUD` bits of `c_cflag`. As in musl, the input speed is always the same
+// as the output speed.
+
+#[inline]
+#[must_use]
+pub(crate) fn cfgetospeed(termios: &Termios) -> Speed {
+    termios.c_cflag & CBAUD
+}
+
+#[inline]
+#[must_use]
+pub(crate) fn cfgetispeed(termios: &Termios) -> Speed {
+    cfgetospeed(termios)
+}
+
+#[inline]
+pub(crate) fn cfsetospeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    if speed & !CBAUD != 0 {
+        return Err(io::Error::INVAL);
+    }
+    termios.c_cflag = (termios.c_cflag & !CBAUD) | speed;
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn cfsetispeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    // A speed of zero means "use the output speed".
+    if speed == 0 {
+        Ok(())
+    } else {
+        cfsetospeed(termios, speed)
+    }
+}
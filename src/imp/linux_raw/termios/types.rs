@@ -0,0 +1,376 @@
+use super::super::c;
+use bitflags::bitflags;
+
+/// `TCSA*` values for use with [`tcsetattr`].
+///
+/// [`tcsetattr`]: crate::termios::tcsetattr
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum OptionalActions {
+    /// `TCSANOW`—Make the change immediately.
+    Now = linux_raw_sys::general::TCSANOW,
+
+    /// `TCSADRAIN`—Make the change after all output has been transmitted.
+    Drain = linux_raw_sys::general::TCSADRAIN,
+
+    /// `TCSAFLUSH`—Discard any pending input and then make the change
+    /// after all output has been transmitted.
+    Flush = linux_raw_sys::general::TCSAFLUSH,
+}
+
+/// `TC*` values for use with [`tcflush`].
+///
+/// [`tcflush`]: crate::termios::tcflush
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum QueueSelector {
+    /// `TCIFLUSH`—Flush data received but not read.
+    IFlush = linux_raw_sys::general::TCIFLUSH,
+
+    /// `TCOFLUSH`—Flush data written but not transmitted.
+    OFlush = linux_raw_sys::general::TCOFLUSH,
+
+    /// `TCIOFLUSH`—`IFlush` and `OFlush` combined.
+    IOFlush = linux_raw_sys::general::TCIOFLUSH,
+}
+
+/// `TC*` values for use with [`tcflow`].
+///
+/// [`tcflow`]: crate::termios::tcflow
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum Action {
+    /// `TCOOFF`—Suspend output.
+    OOff = linux_raw_sys::general::TCOOFF,
+
+    /// `TCOON`—Restart suspended output.
+    OOn = linux_raw_sys::general::TCOON,
+
+    /// `TCIOFF`—Transmit a STOP character, intended to suspend input.
+    IOff = linux_raw_sys::general::TCIOFF,
+
+    /// `TCION`—Transmit a START character, intended to restart input.
+    IOn = linux_raw_sys::general::TCION,
+}
+
+bitflags! {
+    /// Flags for the `c_iflag` field of [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    pub struct InputModes: c::c_uint {
+        /// `IGNBRK`
+        const IGNBRK = linux_raw_sys::general::IGNBRK;
+        /// `BRKINT`
+        const BRKINT = linux_raw_sys::general::BRKINT;
+        /// `IGNPAR`
+        const IGNPAR = linux_raw_sys::general::IGNPAR;
+        /// `PARMRK`
+        const PARMRK = linux_raw_sys::general::PARMRK;
+        /// `INPCK`
+        const INPCK = linux_raw_sys::general::INPCK;
+        /// `ISTRIP`
+        const ISTRIP = linux_raw_sys::general::ISTRIP;
+        /// `INLCR`
+        const INLCR = linux_raw_sys::general::INLCR;
+        /// `IGNCR`
+        const IGNCR = linux_raw_sys::general::IGNCR;
+        /// `ICRNL`
+        const ICRNL = linux_raw_sys::general::ICRNL;
+        /// `IUCLC`
+        const IUCLC = linux_raw_sys::general::IUCLC;
+        /// `IXON`
+        const IXON = linux_raw_sys::general::IXON;
+        /// `IXANY`
+        const IXANY = linux_raw_sys::general::IXANY;
+        /// `IXOFF`
+        const IXOFF = linux_raw_sys::general::IXOFF;
+        /// `IMAXBEL`
+        const IMAXBEL = linux_raw_sys::general::IMAXBEL;
+        /// `IUTF8`
+        const IUTF8 = linux_raw_sys::general::IUTF8;
+    }
+}
+
+bitflags! {
+    /// Flags for the `c_oflag` field of [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    pub struct OutputModes: c::c_uint {
+        /// `OPOST`
+        const OPOST = linux_raw_sys::general::OPOST;
+        /// `OLCUC`
+        const OLCUC = linux_raw_sys::general::OLCUC;
+        /// `ONLCR`
+        const ONLCR = linux_raw_sys::general::ONLCR;
+        /// `OCRNL`
+        const OCRNL = linux_raw_sys::general::OCRNL;
+        /// `ONOCR`
+        const ONOCR = linux_raw_sys::general::ONOCR;
+        /// `ONLRET`
+        const ONLRET = linux_raw_sys::general::ONLRET;
+        /// `OFILL`
+        const OFILL = linux_raw_sys::general::OFILL;
+        /// `OFDEL`
+        const OFDEL = linux_raw_sys::general::OFDEL;
+        /// `NLDLY`
+        const NLDLY = linux_raw_sys::general::NLDLY;
+        /// `NL1`
+        const NL1 = linux_raw_sys::general::NL1;
+        /// `CRDLY`
+        const CRDLY = linux_raw_sys::general::CRDLY;
+        /// `CR1`
+        const CR1 = linux_raw_sys::general::CR1;
+        /// `CR2`
+        const CR2 = linux_raw_sys::general::CR2;
+        /// `CR3`
+        const CR3 = linux_raw_sys::general::CR3;
+        /// `TABDLY`
+        const TABDLY = linux_raw_sys::general::TABDLY;
+        /// `TAB1`
+        const TAB1 = linux_raw_sys::general::TAB1;
+        /// `TAB2`
+        const TAB2 = linux_raw_sys::general::TAB2;
+        /// `TAB3`
+        const TAB3 = linux_raw_sys::general::TAB3;
+        /// `BSDLY`
+        const BSDLY = linux_raw_sys::general::BSDLY;
+        /// `BS1`
+        const BS1 = linux_raw_sys::general::BS1;
+        /// `VTDLY`
+        const VTDLY = linux_raw_sys::general::VTDLY;
+        /// `VT1`
+        const VT1 = linux_raw_sys::general::VT1;
+        /// `FFDLY`
+        const FFDLY = linux_raw_sys::general::FFDLY;
+        /// `FF1`
+        const FF1 = linux_raw_sys::general::FF1;
+    }
+}
+
+bitflags! {
+    /// Flags for the `c_cflag` field of [`Termios`].
+    ///
+    /// The speed bits of `c_cflag` are accessed with [`cfgetospeed`] and
+    /// [`cfsetospeed`] instead.
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    /// [`cfgetospeed`]: crate::termios::cfgetospeed
+    /// [`cfsetospeed`]: crate::termios::cfsetospeed
+    pub struct ControlModes: c::c_uint {
+        /// `CSIZE`
+        const CSIZE = linux_raw_sys::general::CSIZE;
+        /// `CS6`
+        const CS6 = linux_raw_sys::general::CS6;
+        /// `CS7`
+        const CS7 = linux_raw_sys::general::CS7;
+        /// `CS8`
+        const CS8 = linux_raw_sys::general::CS8;
+        /// `CSTOPB`
+        const CSTOPB = linux_raw_sys::general::CSTOPB;
+        /// `CREAD`
+        const CREAD = linux_raw_sys::general::CREAD;
+        /// `PARENB`
+        const PARENB = linux_raw_sys::general::PARENB;
+        /// `PARODD`
+        const PARODD = linux_raw_sys::general::PARODD;
+        /// `HUPCL`
+        const HUPCL = linux_raw_sys::general::HUPCL;
+        /// `CLOCAL`
+        const CLOCAL = linux_raw_sys::general::CLOCAL;
+        /// `CRTSCTS`
+        const CRTSCTS = linux_raw_sys::general::CRTSCTS;
+        /// `CMSPAR`
+        const CMSPAR = linux_raw_sys::general::CMSPAR;
+    }
+}
+
+bitflags! {
+    /// Flags for the `c_lflag` field of [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios
+    pub struct LocalModes: c::c_uint {
+        /// `ISIG`
+        const ISIG = linux_raw_sys::general::ISIG;
+        /// `ICANON`
+        const ICANON = linux_raw_sys::general::ICANON;
+        /// `XCASE`
+        const XCASE = linux_raw_sys::general::XCASE;
+        /// `ECHO`
+        const ECHO = linux_raw_sys::general::ECHO;
+        /// `ECHOE`
+        const ECHOE = linux_raw_sys::general::ECHOE;
+        /// `ECHOK`
+        const ECHOK = linux_raw_sys::general::ECHOK;
+        /// `ECHONL`
+        const ECHONL = linux_raw_sys::general::ECHONL;
+        /// `NOFLSH`
+        const NOFLSH = linux_raw_sys::general::NOFLSH;
+        /// `TOSTOP`
+        const TOSTOP = linux_raw_sys::general::TOSTOP;
+        /// `ECHOCTL`
+        const ECHOCTL = linux_raw_sys::general::ECHOCTL;
+        /// `ECHOPRT`
+        const ECHOPRT = linux_raw_sys::general::ECHOPRT;
+        /// `ECHOKE`
+        const ECHOKE = linux_raw_sys::general::ECHOKE;
+        /// `FLUSHO`
+        const FLUSHO = linux_raw_sys::general::FLUSHO;
+        /// `PENDIN`
+        const PENDIN = linux_raw_sys::general::PENDIN;
+        /// `IEXTEN`
+        const IEXTEN = linux_raw_sys::general::IEXTEN;
+        /// `EXTPROC`
+        const EXTPROC = linux_raw_sys::v5_4::general::EXTPROC;
+    }
+}
+
+/// `speed_t`—A `B*` constant describing a terminal speed, for use with
+/// [`cfgetospeed`] and [`cfsetospeed`].
+///
+/// [`cfgetospeed`]: crate::termios::cfgetospeed
+/// [`cfsetospeed`]: crate::termios::cfsetospeed
+pub type Speed = linux_raw_sys::general::speed_t;
+
+/// `B0`
+pub const B0: Speed = linux_raw_sys::general::B0;
+
+/// `B50`
+pub const B50: Speed = linux_raw_sys::general::B50;
+
+/// `B75`
+pub const B75: Speed = linux_raw_sys::general::B75;
+
+/// `B110`
+pub const B110: Speed = linux_raw_sys::general::B110;
+
+/// `B134`
+pub const B134: Speed = linux_raw_sys::general::B134;
+
+/// `B150`
+pub const B150: Speed = linux_raw_sys::general::B150;
+
+/// `B200`
+pub const B200: Speed = linux_raw_sys::general::B200;
+
+/// `B300`
+pub const B300: Speed = linux_raw_sys::general::B300;
+
+/// `B600`
+pub const B600: Speed = linux_raw_sys::general::B600;
+
+/// `B1200`
+pub const B1200: Speed = linux_raw_sys::general::B1200;
+
+/// `B1800`
+pub const B1800: Speed = linux_raw_sys::general::B1800;
+
+/// `B2400`
+pub const B2400: Speed = linux_raw_sys::general::B2400;
+
+/// `B4800`
+pub const B4800: Speed = linux_raw_sys::general::B4800;
+
+/// `B9600`
+pub const B9600: Speed = linux_raw_sys::general::B9600;
+
+/// `B19200`
+pub const B19200: Speed = linux_raw_sys::general::B19200;
+
+/// `B38400`
+pub const B38400: Speed = linux_raw_sys::general::B38400;
+
+/// `B57600`
+pub const B57600: Speed = linux_raw_sys::general::B57600;
+
+/// `B115200`
+pub const B115200: Speed = linux_raw_sys::general::B115200;
+
+/// `B230400`
+pub const B230400: Speed = linux_raw_sys::general::B230400;
+
+/// `B460800`
+pub const B460800: Speed = linux_raw_sys::general::B460800;
+
+/// `B500000`
+pub const B500000: Speed = linux_raw_sys::general::B500000;
+
+/// `B576000`
+pub const B576000: Speed = linux_raw_sys::general::B576000;
+
+/// `B921600`
+pub const B921600: Speed = linux_raw_sys::general::B921600;
+
+/// `B1000000`
+pub const B1000000: Speed = linux_raw_sys::general::B1000000;
+
+/// `B1152000`
+pub const B1152000: Speed = linux_raw_sys::general::B1152000;
+
+/// `B1500000`
+pub const B1500000: Speed = linux_raw_sys::general::B1500000;
+
+/// `B2000000`
+pub const B2000000: Speed = linux_raw_sys::general::B2000000;
+
+/// `B2500000`
+pub const B2500000: Speed = linux_raw_sys::general::B2500000;
+
+/// `B3000000`
+pub const B3000000: Speed = linux_raw_sys::general::B3000000;
+
+/// `B3500000`
+pub const B3500000: Speed = linux_raw_sys::general::B3500000;
+
+/// `B4000000`
+pub const B4000000: Speed = linux_raw_sys::general::B4000000;
+
+/// `VINTR`
+pub const VINTR: usize = linux_raw_sys::general::VINTR as usize;
+
+/// `VQUIT`
+pub const VQUIT: usize = linux_raw_sys::general::VQUIT as usize;
+
+/// `VERASE`
+pub const VERASE: usize = linux_raw_sys::general::VERASE as usize;
+
+/// `VKILL`
+pub const VKILL: usize = linux_raw_sys::general::VKILL as usize;
+
+/// `VEOF`
+pub const VEOF: usize = linux_raw_sys::general::VEOF as usize;
+
+/// `VTIME`
+pub const VTIME: usize = linux_raw_sys::general::VTIME as usize;
+
+/// `VMIN`
+pub const VMIN: usize = linux_raw_sys::general::VMIN as usize;
+
+/// `VSWTC`
+pub const VSWTC: usize = linux_raw_sys::general::VSWTC as usize;
+
+/// `VSTART`
+pub const VSTART: usize = linux_raw_sys::general::VSTART as usize;
+
+/// `VSTOP`
+pub const VSTOP: usize = linux_raw_sys::general::VSTOP as usize;
+
+/// `VSUSP`
+pub const VSUSP: usize = linux_raw_sys::general::VSUSP as usize;
+
+/// `VEOL`
+pub const VEOL: usize = linux_raw_sys::general::VEOL as usize;
+
+/// `VREPRINT`
+pub const VREPRINT: usize = linux_raw_sys::general::VREPRINT as usize;
+
+/// `VDISCARD`
+pub const VDISCARD: usize = linux_raw_sys::general::VDISCARD as usize;
+
+/// `VWERASE`
+pub const VWERASE: usize = linux_raw_sys::general::VWERASE as usize;
+
+/// `VLNEXT`
+pub const VLNEXT: usize = linux_raw_sys::general::VLNEXT as usize;
+
+/// `VEOL2`
+pub const VEOL2: usize = linux_raw_sys::general::VEOL2 as usize;
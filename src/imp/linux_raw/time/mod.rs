@@ -1,3 +1,6 @@
 mod types;
 
-pub use types::{ClockId, DynamicClockId, Nsecs, Secs, Timespec};
+pub use types::{
+    ClockId, DynamicClockId, Itimerspec, Nsecs, Secs, TimerfdClockId, TimerfdFlags,
+    TimerfdTimerFlags, Timespec,
+};
@@ -1,4 +1,6 @@
+use super::super::c;
 use super::super::fd::BorrowedFd;
+use bitflags::bitflags;
 
 /// `struct timespec`
 pub type Timespec = linux_raw_sys::general::__kernel_timespec;
@@ -71,3 +73,66 @@ pub enum DynamicClockId<'a> {
     /// `CLOCK_BOOTTIME_ALARM`, available on Linux >= 2.6.39
     BoottimeAlarm,
 }
+
+/// `struct itimerspec` for use with [`timerfd_gettime`] and
+/// [`timerfd_settime`].
+///
+/// [`timerfd_gettime`]: crate::time::timerfd_gettime
+/// [`timerfd_settime`]: crate::time::timerfd_settime
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Itimerspec {
+    /// The interval between expirations, or zero for a one-shot timer.
+    pub it_interval: Timespec,
+    /// The time until the next expiration, or zero to disarm the timer.
+    pub it_value: Timespec,
+}
+
+/// `CLOCK_*` constants for use with [`timerfd_create`].
+///
+/// [`timerfd_create`]: crate::time::timerfd_create
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum TimerfdClockId {
+    /// `CLOCK_REALTIME`
+    Realtime = linux_raw_sys::general::CLOCK_REALTIME,
+
+    /// `CLOCK_MONOTONIC`
+    Monotonic = linux_raw_sys::general::CLOCK_MONOTONIC,
+
+    /// `CLOCK_BOOTTIME`
+    Boottime = linux_raw_sys::v5_4::general::CLOCK_BOOTTIME,
+
+    /// `CLOCK_REALTIME_ALARM`
+    RealtimeAlarm = linux_raw_sys::v5_4::general::CLOCK_REALTIME_ALARM,
+
+    /// `CLOCK_BOOTTIME_ALARM`
+    BoottimeAlarm = linux_raw_sys::v5_4::general::CLOCK_BOOTTIME_ALARM,
+}
+
+bitflags! {
+    /// `TFD_*` flags for use with [`timerfd_create`].
+    ///
+    /// [`timerfd_create`]: crate::time::timerfd_create
+    pub struct TimerfdFlags: c::c_uint {
+        /// `TFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::v5_4::general::TFD_NONBLOCK;
+
+        /// `TFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::v5_4::general::TFD_CLOEXEC;
+    }
+}
+
+bitflags! {
+    /// `TFD_TIMER_*` flags for use with [`timerfd_settime`].
+    ///
+    /// [`timerfd_settime`]: crate::time::timerfd_settime
+    pub struct TimerfdTimerFlags: c::c_uint {
+        /// `TFD_TIMER_ABSTIME`
+        const ABSTIME = linux_raw_sys::v5_4::general::TFD_TIMER_ABSTIME;
+
+        /// `TFD_TIMER_CANCEL_ON_SET`
+        const CANCEL_ON_SET = linux_raw_sys::v5_4::general::TFD_TIMER_CANCEL_ON_SET;
+    }
+}
@@ -1,5 +1,14 @@
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::io::Termios2;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+use crate::io::{OpenptFlags, OwnedFd};
 #[cfg(not(any(windows, target_os = "wasi")))]
 use crate::io::{Termios, Winsize};
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+use crate::process::Pid;
 use crate::{imp, io};
 use imp::fd::AsFd;
 #[cfg(windows)]
@@ -23,6 +32,41 @@ pub fn ioctl_tcgets<Fd: AsFd>(fd: &Fd) -> io::Result<Termios> {
     imp::syscalls::ioctl_tcgets(fd)
 }
 
+/// `ioctl(fd, TCGETS2)`—Get terminal attributes, including arbitrary
+/// input and output speeds.
+///
+/// The `c_ispeed` and `c_ospeed` fields of the result hold the numeric
+/// speeds, even if they were set with one of the standard `B*` constants.
+///
+/// # References
+///  - [Linux `ioctl_tty`]
+///
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn ioctl_tcgets2<Fd: AsFd>(fd: &Fd) -> io::Result<Termios2> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tcgets2(fd)
+}
+
+/// `ioctl(fd, TCSETS2, termios)`—Set terminal attributes, including
+/// arbitrary input and output speeds.
+///
+/// Use [`termios2_set_speed`] and related functions to set a numeric speed
+/// which doesn't have a `B*` constant.
+///
+/// # References
+///  - [Linux `ioctl_tty`]
+///
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+/// [`termios2_set_speed`]: crate::io::termios2_set_speed
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn ioctl_tcsets2<Fd: AsFd>(fd: &Fd, termios: &Termios2) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tcsets2(fd, termios)
+}
+
 /// `ioctl(fd, FIOCLEX)`—Set the close-on-exec flag.
 ///
 /// Also known as `fcntl(fd, F_SETFD, FD_CLOEXEC)`.
@@ -85,6 +129,136 @@ pub fn ioctl_tiocnxcl<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
     imp::syscalls::ioctl_tiocnxcl(fd)
 }
 
+/// `ioctl(fd, TIOCGPTPEER)`—Open the slave side of a pseudoterminal.
+///
+/// Unlike opening the path returned by [`ptsname`], this works even if the
+/// master's devpts instance isn't mounted in the caller's mount namespace.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+/// [`ptsname`]: crate::io::ptsname
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+#[inline]
+pub fn ioctl_tiocgptpeer<Fd: AsFd>(fd: &Fd, flags: OpenptFlags) -> io::Result<OwnedFd> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tiocgptpeer(fd, flags)
+}
+
+/// `ioctl(fd, TIOCSWINSZ, winsize)`—Set the current terminal window size.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+#[inline]
+pub fn ioctl_tiocswinsz<Fd: AsFd>(fd: &Fd, winsize: &Winsize) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tiocswinsz(fd, winsize)
+}
+
+/// `ioctl(fd, TIOCSCTTY, steal)`—Make a terminal the controlling terminal of
+/// the calling process's session.
+///
+/// If the terminal is already the controlling terminal of another session,
+/// it's taken from that session only if `steal` is true and the caller has
+/// `CAP_SYS_ADMIN`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+#[inline]
+pub fn ioctl_tiocsctty<Fd: AsFd>(fd: &Fd, steal: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tiocsctty(fd, steal)
+}
+
+/// `ioctl(fd, TIOCNOTTY)`—Give up a terminal as the controlling terminal of
+/// the calling process's session.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+#[inline]
+pub fn ioctl_tiocnotty<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_tiocnotty(fd)
+}
+
+/// `ioctl(fd, TIOCGPGRP)`—Get the foreground process group of a terminal.
+///
+/// Also known as [`tcgetpgrp`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+/// [`tcgetpgrp`]: crate::termios::tcgetpgrp
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+#[inline]
+pub fn ioctl_tiocgpgrp<Fd: AsFd>(fd: &Fd) -> io::Result<Pid> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcgetpgrp(fd)
+}
+
+/// `ioctl(fd, TIOCSPGRP, pid)`—Set the foreground process group of a
+/// terminal.
+///
+/// Also known as [`tcsetpgrp`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+/// [`tcsetpgrp`]: crate::termios::tcsetpgrp
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+#[inline]
+pub fn ioctl_tiocspgrp<Fd: AsFd>(fd: &Fd, pid: Pid) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsetpgrp(fd, pid)
+}
+
+/// `ioctl(fd, TIOCGSID)`—Get the session ID of the session for which a
+/// terminal is the controlling terminal.
+///
+/// Also known as [`tcgetsid`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+/// [`tcgetsid`]: crate::termios::tcgetsid
+#[cfg(any(
+    linux_raw,
+    all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
+))]
+#[inline]
+pub fn ioctl_tiocgsid<Fd: AsFd>(fd: &Fd) -> io::Result<Pid> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcgetsid(fd)
+}
+
 /// `ioctl(fd, FIONREAD)`—Returns the number of bytes ready to be read.
 ///
 /// The result of this function gets silently coerced into a C `int`
@@ -28,12 +28,18 @@ mod pipe;
 mod poll;
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 mod procfs;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod pty;
 #[cfg(not(windows))]
 mod read_write;
 #[cfg(not(feature = "std"))]
 mod seek_from;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod signalfd;
 #[cfg(not(windows))]
 mod stdio;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod termios2;
 #[cfg(not(windows))]
 mod tty;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
@@ -52,13 +58,20 @@ pub use ioctl::ioctl_fioclex;
 pub use ioctl::ioctl_fionbio;
 #[cfg(not(any(windows, target_os = "redox")))]
 pub use ioctl::ioctl_fionread;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+pub use ioctl::ioctl_tiocgptpeer;
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub use ioctl::{ioctl_tcgets, ioctl_tiocgwinsz};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use ioctl::{ioctl_tcgets2, ioctl_tcsets2};
 #[cfg(any(
     linux_raw,
     all(libc, not(any(windows, target_os = "redox", target_os = "wasi")))
 ))]
-pub use ioctl::{ioctl_tiocexcl, ioctl_tiocnxcl};
+pub use ioctl::{
+    ioctl_tiocexcl, ioctl_tiocgpgrp, ioctl_tiocgsid, ioctl_tiocnotty, ioctl_tiocnxcl,
+    ioctl_tiocsctty, ioctl_tiocspgrp, ioctl_tiocswinsz,
+};
 #[cfg(not(any(windows, target_os = "redox")))]
 pub use is_read_write::is_read_write;
 #[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
@@ -78,16 +91,27 @@ pub use pipe::pipe;
 pub use pipe::{pipe_with, PipeFlags};
 #[cfg(not(windows))]
 pub use poll::{poll, PollFd, PollFlags};
+#[cfg(all(
+    feature = "procfs",
+    any(linux_raw, all(libc, any(target_os = "android", target_os = "linux")))
+))]
+pub(crate) use procfs::open_proc_pid_file;
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 pub use procfs::proc_self_fd;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use pty::{grantpt, openpt, ptsname, unlockpt, OpenptFlags};
 #[cfg(not(windows))]
 pub use read_write::{pread, pwrite, read, readv, write, writev};
 #[cfg(not(any(windows, target_os = "redox")))]
 pub use read_write::{preadv, pwritev};
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use read_write::{preadv2, pwritev2, ReadWriteFlags};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use signalfd::{signalfd, signalfd_read, signalfd_set_mask, SignalfdFlags, SignalfdSiginfo};
 #[cfg(not(windows))]
 pub use stdio::{stderr, stdin, stdout, take_stderr, take_stdin, take_stdout};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use termios2::{termios2_set_ispeed, termios2_set_ospeed, termios2_set_speed};
 #[cfg(not(windows))]
 pub use tty::isatty;
 #[cfg(any(
@@ -100,6 +124,8 @@ pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
 
 #[cfg(any(linux_raw, not(any(windows, target_os = "wasi"))))]
 pub use imp::io::Termios;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use imp::io::Termios2;
 
 #[cfg(any(linux_raw, all(libc, not(any(windows, target_os = "wasi")))))]
 pub use imp::io::Winsize;
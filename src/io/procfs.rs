@@ -6,13 +6,14 @@
 //! is mounted, with actual `procfs`, and without any additional mount points
 //! on top of the paths we open.
 
+use crate::ffi::ZStr;
 use crate::fs::{
     cwd, fstat, fstatfs, major, openat, renameat, Mode, OFlags, Stat, PROC_SUPER_MAGIC,
 };
 use crate::imp::fd::{AsFd, BorrowedFd};
 use crate::io::{self, OwnedFd};
 use crate::path::DecInt;
-use crate::process::{getgid, getpid, getuid, Gid, RawGid, RawUid, Uid};
+use crate::process::{getgid, getpid, getuid, Gid, Pid, RawGid, RawUid, Uid};
 #[cfg(feature = "rustc-dep-of-std")]
 use core::lazy::OnceCell;
 #[cfg(not(feature = "rustc-dep-of-std"))]
@@ -300,6 +301,64 @@ pub fn proc_self_fd() -> io::Result<BorrowedFd<'static>> {
         .map(|(owned, _stat)| owned.as_fd())
 }
 
+/// Opens Linux's `/proc/<pid>/<name>` file, using the current process if
+/// `pid` is `None`.
+///
+/// This ensures that `/proc` and `/proc/<pid>` are `procfs`, that nothing is
+/// mounted on top of them, and that the file is also `procfs`. Unlike
+/// [`proc_self_fd`], it doesn't check the directories' owners, since this is
+/// used to write the id maps of a new user namespace, in which the owners
+/// aren't mapped yet.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+pub(crate) fn open_proc_pid_file(
+    pid: Option<Pid>,
+    name: &ZStr,
+    oflags: OFlags,
+) -> io::Result<OwnedFd> {
+    let dir_oflags = OFlags::NOFOLLOW
+        | OFlags::PATH
+        | OFlags::DIRECTORY
+        | OFlags::CLOEXEC
+        | OFlags::NOCTTY
+        | OFlags::NOATIME;
+
+    // Open "/proc". Don't use `proc`, as it checks that "/proc" is owned by
+    // root.
+    let proc = openat(&cwd(), zstr!("/proc"), dir_oflags, Mode::empty())
+        .map_err(|_err| io::Error::NOTSUP)?;
+    let proc_stat = fstat(&proc)?;
+    check_procfs(proc.as_fd())?;
+    check_proc_root(proc.as_fd(), &proc_stat)?;
+
+    // Open "/proc/<pid>". Don't use `proc_self`, as it's cached, and this may
+    // be called in a child process after a `fork`.
+    let pid = pid.unwrap_or_else(getpid);
+    let dir = openat(
+        &proc,
+        DecInt::new(pid.as_raw_nonzero().get()),
+        dir_oflags,
+        Mode::empty(),
+    )?;
+    let dir_stat = fstat(&dir)?;
+    check_procfs(dir.as_fd())?;
+    check_proc_subdir(dir.as_fd(), &dir_stat, Some(&proc_stat))?;
+
+    // Open "/proc/<pid>/<name>".
+    let file = openat(
+        &dir,
+        name,
+        oflags | OFlags::NOFOLLOW | OFlags::CLOEXEC | OFlags::NOCTTY,
+        Mode::empty(),
+    )?;
+    check_procfs(file.as_fd())?;
+
+    Ok(file)
+}
+
 type StaticFd = OnceCell<(OwnedFd, Stat)>;
 
 #[inline]
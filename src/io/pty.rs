@@ -0,0 +1,99 @@
+//! Pseudoterminal operations.
+
+use crate::ffi::ZString;
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use alloc::vec::Vec;
+use imp::fd::{AsFd, BorrowedFd};
+
+pub use imp::io::OpenptFlags;
+
+/// `posix_openpt(flags)`—Open a pseudoterminal master.
+///
+/// On Linux, this opens `/dev/ptmx`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_openpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/posix_openpt.3.html
+#[inline]
+#[doc(alias = "posix_openpt")]
+pub fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::openpt(flags)
+}
+
+/// `grantpt(fd)`—Grant access to the slave side of a pseudoterminal.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/grantpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/grantpt.3.html
+#[inline]
+pub fn grantpt<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::grantpt(fd)
+}
+
+/// `unlockpt(fd)`—Unlock the slave side of a pseudoterminal.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/unlockpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/unlockpt.3.html
+#[inline]
+pub fn unlockpt<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::unlockpt(fd)
+}
+
+/// `ptsname(fd)`—Return the name of the slave side of a pseudoterminal.
+///
+/// This uses `ioctl(fd, TIOCGPTN)` to get the slave's number, so it's
+/// thread-safe. If `reuse` is non-empty, reuse its buffer to store the result
+/// if possible.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/ptsname.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ptsname.3.html
+#[inline]
+#[doc(alias = "ptsname_r")]
+pub fn ptsname<Fd: AsFd, B: Into<Vec<u8>>>(fd: &Fd, reuse: B) -> io::Result<ZString> {
+    let fd = fd.as_fd();
+    _ptsname(fd, reuse.into())
+}
+
+fn _ptsname(fd: BorrowedFd<'_>, mut buffer: Vec<u8>) -> io::Result<ZString> {
+    let index = imp::syscalls::ioctl_tiocgptn(fd)?;
+
+    buffer.clear();
+    buffer.extend_from_slice(b"/dev/pts/");
+    push_decimal(&mut buffer, index);
+    Ok(ZString::new(buffer).unwrap())
+}
+
+/// Append the decimal digits of `n` to `buffer`.
+///
+/// This is simple enough to do by hand that `ptsname` doesn't need the `itoa`
+/// feature, or a `String` allocation.
+fn push_decimal(buffer: &mut Vec<u8>, mut n: u32) {
+    let mut digits = [0_u8; 10];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    buffer.extend_from_slice(&digits[start..]);
+}
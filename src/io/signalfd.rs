@@ -0,0 +1,239 @@
+//! The Linux `signalfd` API.
+//!
+//! # Safety
+//!
+//! `signalfd_read` reads `SignalfdSiginfo` records directly into
+//! uninitialized memory; the kernel fully initializes each record it returns.
+#![allow(unsafe_code)]
+
+use crate::fd::AsFd;
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::process::{Pid, SigSet, Signal, Uid};
+use core::mem::{size_of, MaybeUninit};
+use core::slice;
+
+pub use imp::io::SignalfdFlags;
+
+/// `signalfd(-1, mask, flags)`—Creates a file descriptor for accepting
+/// signals.
+///
+/// The signals in `mask` should usually be blocked with [`sigprocmask`], so
+/// that they are not handled according to their default dispositions.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+/// [`sigprocmask`]: crate::process::sigprocmask
+#[inline]
+pub fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::signalfd(&mask.sig_set, flags)
+}
+
+/// `signalfd(fd, mask, 0)`—Replaces the set of signals accepted by an
+/// existing signalfd file descriptor.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+#[doc(alias = "signalfd")]
+pub fn signalfd_set_mask<Fd: AsFd>(fd: &Fd, mask: &SigSet) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::signalfd_set_mask(fd, &mask.sig_set)
+}
+
+/// Reads one pending signal from a signalfd file descriptor.
+///
+/// If the file descriptor is non-blocking and no signals are pending, this
+/// fails with [`io::Error::AGAIN`]. If `fd` isn't a signalfd file descriptor
+/// and returns a partial record, this fails with [`io::Error::IO`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+pub fn signalfd_read<Fd: AsFd>(fd: &Fd) -> io::Result<SignalfdSiginfo> {
+    let mut info = MaybeUninit::<SignalfdSiginfo>::uninit();
+    let fd = fd.as_fd();
+    let buf = unsafe {
+        slice::from_raw_parts_mut(info.as_mut_ptr().cast::<u8>(), size_of::<SignalfdSiginfo>())
+    };
+    let nread = imp::syscalls::read(fd, buf)?;
+    if nread != size_of::<SignalfdSiginfo>() {
+        return Err(io::Error::IO);
+    }
+    Ok(unsafe { info.assume_init() })
+}
+
+/// A `struct signalfd_siginfo`, describing a signal read from a signalfd file
+/// descriptor with [`signalfd_read`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct SignalfdSiginfo {
+    ssi_signo: u32,
+    ssi_errno: i32,
+    ssi_code: i32,
+    ssi_pid: u32,
+    ssi_uid: u32,
+    ssi_fd: i32,
+    ssi_tid: u32,
+    ssi_band: u32,
+    ssi_overrun: u32,
+    ssi_trapno: u32,
+    ssi_status: i32,
+    ssi_int: i32,
+    ssi_ptr: u64,
+    ssi_utime: u64,
+    ssi_stime: u64,
+    ssi_addr: u64,
+    ssi_addr_lsb: u16,
+    __pad2: u16,
+    ssi_syscall: i32,
+    ssi_call_addr: u64,
+    ssi_arch: u32,
+    __pad: [u8; 28],
+}
+
+impl SignalfdSiginfo {
+    /// `ssi_signo`—The signal.
+    #[inline]
+    pub fn signal(&self) -> Signal {
+        // Safety: The kernel only reports valid signal numbers.
+        unsafe { Signal::from_raw_unchecked(self.ssi_signo as i32) }
+    }
+
+    /// `ssi_errno`—An error number associated with the signal; usually zero.
+    #[inline]
+    pub fn errno(&self) -> i32 {
+        self.ssi_errno
+    }
+
+    /// `ssi_code`—The signal code, such as `SI_USER` or `CLD_EXITED`.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        self.ssi_code
+    }
+
+    /// `ssi_pid`—The process ID of the sender, for signals sent by `kill`
+    /// or `sigqueue`, or of the child, for `SIGCHLD`.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        // Safety: The kernel reports a valid process ID, or zero.
+        unsafe { Pid::from_raw(self.ssi_pid as _) }
+    }
+
+    /// `ssi_uid`—The real user ID of the sender, or of the child, for
+    /// `SIGCHLD`.
+    #[inline]
+    pub fn uid(&self) -> Uid {
+        // Safety: The kernel reports a valid user ID.
+        unsafe { Uid::from_raw(self.ssi_uid as _) }
+    }
+
+    /// `ssi_fd`—The file descriptor, for `SIGIO`.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.ssi_fd
+    }
+
+    /// `ssi_tid`—The kernel timer ID, for POSIX timer signals.
+    #[inline]
+    pub fn tid(&self) -> u32 {
+        self.ssi_tid
+    }
+
+    /// `ssi_band`—The band event, for `SIGIO`.
+    #[inline]
+    pub fn band(&self) -> u32 {
+        self.ssi_band
+    }
+
+    /// `ssi_overrun`—The overrun count, for POSIX timer signals.
+    #[inline]
+    pub fn overrun(&self) -> u32 {
+        self.ssi_overrun
+    }
+
+    /// `ssi_trapno`—The trap number that caused a hardware-generated signal.
+    #[inline]
+    pub fn trapno(&self) -> u32 {
+        self.ssi_trapno
+    }
+
+    /// `ssi_status`—The exit status or signal number, for `SIGCHLD`.
+    #[inline]
+    pub fn status(&self) -> i32 {
+        self.ssi_status
+    }
+
+    /// `ssi_int`—The integer sent with `sigqueue`.
+    #[inline]
+    pub fn int(&self) -> i32 {
+        self.ssi_int
+    }
+
+    /// `ssi_ptr`—The pointer value sent with `sigqueue`.
+    #[inline]
+    pub fn ptr(&self) -> u64 {
+        self.ssi_ptr
+    }
+
+    /// `ssi_utime`—The user CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn utime(&self) -> u64 {
+        self.ssi_utime
+    }
+
+    /// `ssi_stime`—The system CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn stime(&self) -> u64 {
+        self.ssi_stime
+    }
+
+    /// `ssi_addr`—The address that generated a hardware-generated signal.
+    #[inline]
+    pub fn addr(&self) -> u64 {
+        self.ssi_addr
+    }
+
+    /// `ssi_addr_lsb`—The least significant bit of the address, for
+    /// `SIGBUS` memory errors.
+    #[inline]
+    pub fn addr_lsb(&self) -> u16 {
+        self.ssi_addr_lsb
+    }
+
+    /// `ssi_syscall`—The system call number, for `SIGSYS` from seccomp.
+    #[inline]
+    pub fn syscall(&self) -> i32 {
+        self.ssi_syscall
+    }
+
+    /// `ssi_call_addr`—The address of the system call instruction, for
+    /// `SIGSYS` from seccomp.
+    #[inline]
+    pub fn call_addr(&self) -> u64 {
+        self.ssi_call_addr
+    }
+
+    /// `ssi_arch`—The `AUDIT_ARCH_*` value of the system call, for `SIGSYS`
+    /// from seccomp.
+    #[inline]
+    pub fn arch(&self) -> u32 {
+        self.ssi_arch
+    }
+}
+
+#[test]
+fn signalfd_siginfo_layout() {
+    assert_eq!(size_of::<SignalfdSiginfo>(), 128);
+}
@@ -0,0 +1,52 @@
+use crate::imp::io::{BOTHER, CBAUD, CIBAUD, IBSHIFT};
+use crate::io::Termios2;
+
+/// Set the output speed of a [`Termios2`] to an arbitrary numeric value.
+///
+/// This sets the `CBAUD` bits of `c_cflag` to `BOTHER` and stores `speed`
+/// in `c_ospeed`. Use [`ioctl_tcsets2`] to apply it to a terminal.
+///
+/// # References
+///  - [Linux `ioctl_tty`]
+///
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+/// [`ioctl_tcsets2`]: crate::io::ioctl_tcsets2
+#[inline]
+pub fn termios2_set_ospeed(termios: &mut Termios2, speed: u32) {
+    termios.c_cflag = (termios.c_cflag & !CBAUD) | BOTHER;
+    termios.c_ospeed = speed;
+}
+
+/// Set the input speed of a [`Termios2`] to an arbitrary numeric value.
+///
+/// This sets the `CIBAUD` bits of `c_cflag` to `BOTHER` and stores `speed`
+/// in `c_ispeed`. Use [`ioctl_tcsets2`] to apply it to a terminal.
+///
+/// # References
+///  - [Linux `ioctl_tty`]
+///
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+/// [`ioctl_tcsets2`]: crate::io::ioctl_tcsets2
+#[inline]
+pub fn termios2_set_ispeed(termios: &mut Termios2, speed: u32) {
+    termios.c_cflag = (termios.c_cflag & !CIBAUD) | (BOTHER << IBSHIFT);
+    termios.c_ispeed = speed;
+}
+
+/// Set both the input and output speeds of a [`Termios2`] to an arbitrary
+/// numeric value.
+///
+/// The `CIBAUD` bits of `c_cflag` are cleared, so the kernel uses the output
+/// speed for input too. Use [`ioctl_tcsets2`] to apply it to a terminal.
+///
+/// # References
+///  - [Linux `ioctl_tty`]
+///
+/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+/// [`ioctl_tcsets2`]: crate::io::ioctl_tcsets2
+#[inline]
+pub fn termios2_set_speed(termios: &mut Termios2, speed: u32) {
+    termios2_set_ospeed(termios, speed);
+    termios.c_cflag &= !CIBAUD;
+    termios.c_ispeed = speed;
+}
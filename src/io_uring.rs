@@ -0,0 +1,929 @@
+//! Linux [io_uring].
+//!
+//! This API is very low-level. The main adaptations it makes from the raw
+//! Linux io_uring API are the use of appropriately-sized `bitflags`, `enum`,
+//! `Result`, `OwnedFd`, and `AsFd` types, and an [`IoUringRings`] type which
+//! maps the shared submission and completion rings and unmaps them on drop.
+//!
+//! # Safety
+//!
+//! io_uring operates on raw pointers and raw file descriptors. Rustix does
+//! not attempt to provide a safe API for submitting operations, because
+//! the kernel accesses the buffers referenced by submission queue entries
+//! asynchronously.
+//!
+//! [io_uring]: https://en.wikipedia.org/wiki/io_uring
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, RawFd};
+use crate::imp;
+use crate::io::{self, MapFlags, OwnedFd, ProtFlags};
+use bitflags::bitflags;
+use core::ffi::c_void;
+use core::mem::{align_of, size_of};
+use core::ptr::{null_mut, NonNull};
+use core::sync::atomic::AtomicU32;
+
+/// `io_uring_setup(entries, params)`—Setup a context for performing
+/// asynchronous I/O.
+///
+/// On success, `params` is updated with the sizes and ring offsets needed
+/// to map the rings; see [`IoUringRings::new`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man.archlinux.org/man/io_uring_setup.2.en
+#[inline]
+pub fn io_uring_setup(entries: u32, params: &mut IoUringParams) -> io::Result<OwnedFd> {
+    imp::syscalls::io_uring_setup(entries, params)
+}
+
+/// `io_uring_register(fd, opcode, arg, nr_args)`—Register files or user
+/// buffers for asynchronous I/O.
+///
+/// # Safety
+///
+/// io_uring operates on raw pointers and raw file descriptors. Users are
+/// responsible for ensuring that memory and resources are only accessed in
+/// valid ways.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+#[inline]
+pub unsafe fn io_uring_register<Fd: AsFd>(
+    fd: &Fd,
+    opcode: IoringRegisterOp,
+    arg: *const c_void,
+    nr_args: u32,
+) -> io::Result<u32> {
+    let fd = fd.as_fd();
+    imp::syscalls::io_uring_register(fd, opcode, arg, nr_args)
+}
+
+/// `io_uring_enter(fd, to_submit, min_complete, flags, arg, size)`—Initiate
+/// and/or complete asynchronous I/O.
+///
+/// # Safety
+///
+/// io_uring operates on raw pointers and raw file descriptors. Users are
+/// responsible for ensuring that memory and resources are only accessed in
+/// valid ways.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man.archlinux.org/man/io_uring_enter.2.en
+#[inline]
+pub unsafe fn io_uring_enter<Fd: AsFd>(
+    fd: &Fd,
+    to_submit: u32,
+    min_complete: u32,
+    flags: IoringEnterFlags,
+    arg: *const c_void,
+    size: usize,
+) -> io::Result<u32> {
+    let fd = fd.as_fd();
+    imp::syscalls::io_uring_enter(fd, to_submit, min_complete, flags, arg, size)
+}
+
+/// `IORING_OFF_SQ_RING`—The `mmap` offset of the submission queue ring.
+pub const IORING_OFF_SQ_RING: u64 = 0;
+
+/// `IORING_OFF_CQ_RING`—The `mmap` offset of the completion queue ring.
+pub const IORING_OFF_CQ_RING: u64 = 0x0800_0000;
+
+/// `IORING_OFF_SQES`—The `mmap` offset of the submission queue entries.
+pub const IORING_OFF_SQES: u64 = 0x1000_0000;
+
+bitflags! {
+    /// `IORING_SETUP_*` flags for use with [`IoUringParams`].
+    #[derive(Default)]
+    pub struct IoringSetupFlags: u32 {
+        /// `IORING_SETUP_IOPOLL`
+        const IOPOLL = 1 << 0;
+        /// `IORING_SETUP_SQPOLL`
+        const SQPOLL = 1 << 1;
+        /// `IORING_SETUP_SQ_AFF`
+        const SQ_AFF = 1 << 2;
+        /// `IORING_SETUP_CQSIZE`
+        const CQSIZE = 1 << 3;
+        /// `IORING_SETUP_CLAMP`
+        const CLAMP = 1 << 4;
+        /// `IORING_SETUP_ATTACH_WQ`
+        const ATTACH_WQ = 1 << 5;
+        /// `IORING_SETUP_R_DISABLED` (since Linux 5.10)
+        const R_DISABLED = 1 << 6;
+        /// `IORING_SETUP_SUBMIT_ALL` (since Linux 5.18)
+        const SUBMIT_ALL = 1 << 7;
+        /// `IORING_SETUP_COOP_TASKRUN` (since Linux 5.19)
+        const COOP_TASKRUN = 1 << 8;
+        /// `IORING_SETUP_TASKRUN_FLAG` (since Linux 5.19)
+        const TASKRUN_FLAG = 1 << 9;
+        /// `IORING_SETUP_SQE128` (since Linux 5.19)
+        const SQE128 = 1 << 10;
+        /// `IORING_SETUP_CQE32` (since Linux 5.19)
+        const CQE32 = 1 << 11;
+        /// `IORING_SETUP_SINGLE_ISSUER` (since Linux 6.0)
+        const SINGLE_ISSUER = 1 << 12;
+        /// `IORING_SETUP_DEFER_TASKRUN` (since Linux 6.1)
+        const DEFER_TASKRUN = 1 << 13;
+    }
+}
+
+bitflags! {
+    /// `IORING_FEAT_*` flags reported in [`IoUringParams::features`].
+    #[derive(Default)]
+    pub struct IoringFeatureFlags: u32 {
+        /// `IORING_FEAT_SINGLE_MMAP`
+        const SINGLE_MMAP = 1 << 0;
+        /// `IORING_FEAT_NODROP`
+        const NODROP = 1 << 1;
+        /// `IORING_FEAT_SUBMIT_STABLE`
+        const SUBMIT_STABLE = 1 << 2;
+        /// `IORING_FEAT_RW_CUR_POS`
+        const RW_CUR_POS = 1 << 3;
+        /// `IORING_FEAT_CUR_PERSONALITY`
+        const CUR_PERSONALITY = 1 << 4;
+        /// `IORING_FEAT_FAST_POLL`
+        const FAST_POLL = 1 << 5;
+        /// `IORING_FEAT_POLL_32BITS`
+        const POLL_32BITS = 1 << 6;
+        /// `IORING_FEAT_SQPOLL_NONFIXED`
+        const SQPOLL_NONFIXED = 1 << 7;
+        /// `IORING_FEAT_EXT_ARG`
+        const EXT_ARG = 1 << 8;
+        /// `IORING_FEAT_NATIVE_WORKERS`
+        const NATIVE_WORKERS = 1 << 9;
+        /// `IORING_FEAT_RSRC_TAGS`
+        const RSRC_TAGS = 1 << 10;
+        /// `IORING_FEAT_CQE_SKIP`
+        const CQE_SKIP = 1 << 11;
+        /// `IORING_FEAT_LINKED_FILE`
+        const LINKED_FILE = 1 << 12;
+    }
+}
+
+bitflags! {
+    /// `IORING_ENTER_*` flags for use with [`io_uring_enter`].
+    #[derive(Default)]
+    pub struct IoringEnterFlags: u32 {
+        /// `IORING_ENTER_GETEVENTS`
+        const GETEVENTS = 1 << 0;
+        /// `IORING_ENTER_SQ_WAKEUP`
+        const SQ_WAKEUP = 1 << 1;
+        /// `IORING_ENTER_SQ_WAIT`
+        const SQ_WAIT = 1 << 2;
+        /// `IORING_ENTER_EXT_ARG`
+        const EXT_ARG = 1 << 3;
+        /// `IORING_ENTER_REGISTERED_RING`
+        const REGISTERED_RING = 1 << 4;
+    }
+}
+
+bitflags! {
+    /// `IOSQE_*` flags for use with [`IoUringSqe`].
+    #[derive(Default)]
+    pub struct IoringSqeFlags: u8 {
+        /// `1 << IOSQE_FIXED_FILE_BIT`
+        const FIXED_FILE = 1 << 0;
+        /// `1 << IOSQE_IO_DRAIN_BIT`
+        const IO_DRAIN = 1 << 1;
+        /// `1 << IOSQE_IO_LINK_BIT`
+        const IO_LINK = 1 << 2;
+        /// `1 << IOSQE_IO_HARDLINK_BIT`
+        const IO_HARDLINK = 1 << 3;
+        /// `1 << IOSQE_ASYNC_BIT`
+        const ASYNC = 1 << 4;
+        /// `1 << IOSQE_BUFFER_SELECT_BIT`
+        const BUFFER_SELECT = 1 << 5;
+        /// `1 << IOSQE_CQE_SKIP_SUCCESS_BIT`
+        const CQE_SKIP_SUCCESS = 1 << 6;
+    }
+}
+
+bitflags! {
+    /// `IORING_CQE_F_*` flags for use with [`IoUringCqe`].
+    #[derive(Default)]
+    pub struct IoringCqeFlags: u32 {
+        /// `IORING_CQE_F_BUFFER`
+        const BUFFER = 1 << 0;
+        /// `IORING_CQE_F_MORE`
+        const MORE = 1 << 1;
+        /// `IORING_CQE_F_SOCK_NONEMPTY`
+        const SOCK_NONEMPTY = 1 << 2;
+        /// `IORING_CQE_F_NOTIF`
+        const NOTIF = 1 << 3;
+    }
+}
+
+bitflags! {
+    /// `IORING_SQ_*` flags for the submission queue ring's flags word.
+    #[derive(Default)]
+    pub struct IoringSqFlags: u32 {
+        /// `IORING_SQ_NEED_WAKEUP`
+        const NEED_WAKEUP = 1 << 0;
+        /// `IORING_SQ_CQ_OVERFLOW`
+        const CQ_OVERFLOW = 1 << 1;
+        /// `IORING_SQ_TASKRUN`
+        const TASKRUN = 1 << 2;
+    }
+}
+
+bitflags! {
+    /// `IORING_CQ_*` flags for the completion queue ring's flags word.
+    #[derive(Default)]
+    pub struct IoringCqFlags: u32 {
+        /// `IORING_CQ_EVENTFD_DISABLED`
+        const EVENTFD_DISABLED = 1 << 0;
+    }
+}
+
+bitflags! {
+    /// `IORING_FSYNC_*` flags for use with [`IoringOp::Fsync`].
+    #[derive(Default)]
+    pub struct IoringFsyncFlags: u32 {
+        /// `IORING_FSYNC_DATASYNC`
+        const DATASYNC = 1 << 0;
+    }
+}
+
+bitflags! {
+    /// `IORING_TIMEOUT_*` flags for use with [`IoringOp::Timeout`] and
+    /// [`IoringOp::LinkTimeout`].
+    #[derive(Default)]
+    pub struct IoringTimeoutFlags: u32 {
+        /// `IORING_TIMEOUT_ABS`
+        const ABS = 1 << 0;
+        /// `IORING_TIMEOUT_UPDATE`
+        const UPDATE = 1 << 1;
+        /// `IORING_TIMEOUT_BOOTTIME`
+        const BOOTTIME = 1 << 2;
+        /// `IORING_TIMEOUT_REALTIME`
+        const REALTIME = 1 << 3;
+        /// `IORING_LINK_TIMEOUT_UPDATE`
+        const LINK_TIMEOUT_UPDATE = 1 << 4;
+        /// `IORING_TIMEOUT_ETIME_SUCCESS`
+        const ETIME_SUCCESS = 1 << 5;
+    }
+}
+
+/// `IORING_OP_*` constants for use with [`IoUringSqe`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum IoringOp {
+    /// `IORING_OP_NOP`
+    Nop = 0,
+    /// `IORING_OP_READV`
+    Readv = 1,
+    /// `IORING_OP_WRITEV`
+    Writev = 2,
+    /// `IORING_OP_FSYNC`
+    Fsync = 3,
+    /// `IORING_OP_READ_FIXED`
+    ReadFixed = 4,
+    /// `IORING_OP_WRITE_FIXED`
+    WriteFixed = 5,
+    /// `IORING_OP_POLL_ADD`
+    PollAdd = 6,
+    /// `IORING_OP_POLL_REMOVE`
+    PollRemove = 7,
+    /// `IORING_OP_SYNC_FILE_RANGE`
+    SyncFileRange = 8,
+    /// `IORING_OP_SENDMSG`
+    Sendmsg = 9,
+    /// `IORING_OP_RECVMSG`
+    Recvmsg = 10,
+    /// `IORING_OP_TIMEOUT`
+    Timeout = 11,
+    /// `IORING_OP_TIMEOUT_REMOVE`
+    TimeoutRemove = 12,
+    /// `IORING_OP_ACCEPT`
+    Accept = 13,
+    /// `IORING_OP_ASYNC_CANCEL`
+    AsyncCancel = 14,
+    /// `IORING_OP_LINK_TIMEOUT`
+    LinkTimeout = 15,
+    /// `IORING_OP_CONNECT`
+    Connect = 16,
+    /// `IORING_OP_FALLOCATE`
+    Fallocate = 17,
+    /// `IORING_OP_OPENAT`
+    Openat = 18,
+    /// `IORING_OP_CLOSE`
+    Close = 19,
+    /// `IORING_OP_FILES_UPDATE`
+    FilesUpdate = 20,
+    /// `IORING_OP_STATX`
+    Statx = 21,
+    /// `IORING_OP_READ`
+    Read = 22,
+    /// `IORING_OP_WRITE`
+    Write = 23,
+    /// `IORING_OP_FADVISE`
+    Fadvise = 24,
+    /// `IORING_OP_MADVISE`
+    Madvise = 25,
+    /// `IORING_OP_SEND`
+    Send = 26,
+    /// `IORING_OP_RECV`
+    Recv = 27,
+    /// `IORING_OP_OPENAT2`
+    Openat2 = 28,
+    /// `IORING_OP_EPOLL_CTL`
+    EpollCtl = 29,
+    /// `IORING_OP_SPLICE`
+    Splice = 30,
+    /// `IORING_OP_PROVIDE_BUFFERS`
+    ProvideBuffers = 31,
+    /// `IORING_OP_REMOVE_BUFFERS`
+    RemoveBuffers = 32,
+    /// `IORING_OP_TEE`
+    Tee = 33,
+    /// `IORING_OP_SHUTDOWN`
+    Shutdown = 34,
+    /// `IORING_OP_RENAMEAT`
+    Renameat = 35,
+    /// `IORING_OP_UNLINKAT`
+    Unlinkat = 36,
+    /// `IORING_OP_MKDIRAT`
+    Mkdirat = 37,
+    /// `IORING_OP_SYMLINKAT`
+    Symlinkat = 38,
+    /// `IORING_OP_LINKAT`
+    Linkat = 39,
+    /// `IORING_OP_MSG_RING`
+    MsgRing = 40,
+    /// `IORING_OP_FSETXATTR`
+    Fsetxattr = 41,
+    /// `IORING_OP_SETXATTR`
+    Setxattr = 42,
+    /// `IORING_OP_FGETXATTR`
+    Fgetxattr = 43,
+    /// `IORING_OP_GETXATTR`
+    Getxattr = 44,
+    /// `IORING_OP_SOCKET`
+    Socket = 45,
+    /// `IORING_OP_URING_CMD`
+    UringCmd = 46,
+    /// `IORING_OP_SEND_ZC`
+    SendZc = 47,
+    /// `IORING_OP_SENDMSG_ZC`
+    SendmsgZc = 48,
+}
+
+impl Default for IoringOp {
+    #[inline]
+    fn default() -> Self {
+        Self::Nop
+    }
+}
+
+/// `IORING_REGISTER_*` and `IORING_UNREGISTER_*` constants for use with
+/// [`io_uring_register`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum IoringRegisterOp {
+    /// `IORING_REGISTER_BUFFERS`
+    RegisterBuffers = 0,
+    /// `IORING_UNREGISTER_BUFFERS`
+    UnregisterBuffers = 1,
+    /// `IORING_REGISTER_FILES`
+    RegisterFiles = 2,
+    /// `IORING_UNREGISTER_FILES`
+    UnregisterFiles = 3,
+    /// `IORING_REGISTER_EVENTFD`
+    RegisterEventfd = 4,
+    /// `IORING_UNREGISTER_EVENTFD`
+    UnregisterEventfd = 5,
+    /// `IORING_REGISTER_FILES_UPDATE`
+    RegisterFilesUpdate = 6,
+    /// `IORING_REGISTER_EVENTFD_ASYNC`
+    RegisterEventfdAsync = 7,
+    /// `IORING_REGISTER_PROBE`
+    RegisterProbe = 8,
+    /// `IORING_REGISTER_PERSONALITY`
+    RegisterPersonality = 9,
+    /// `IORING_UNREGISTER_PERSONALITY`
+    UnregisterPersonality = 10,
+    /// `IORING_REGISTER_RESTRICTIONS`
+    RegisterRestrictions = 11,
+    /// `IORING_REGISTER_ENABLE_RINGS`
+    RegisterEnableRings = 12,
+    /// `IORING_REGISTER_FILES2`
+    RegisterFiles2 = 13,
+    /// `IORING_REGISTER_FILES_UPDATE2`
+    RegisterFilesUpdate2 = 14,
+    /// `IORING_REGISTER_BUFFERS2`
+    RegisterBuffers2 = 15,
+    /// `IORING_REGISTER_BUFFERS_UPDATE`
+    RegisterBuffersUpdate = 16,
+    /// `IORING_REGISTER_IOWQ_AFF`
+    RegisterIowqAff = 17,
+    /// `IORING_UNREGISTER_IOWQ_AFF`
+    UnregisterIowqAff = 18,
+    /// `IORING_REGISTER_IOWQ_MAX_WORKERS`
+    RegisterIowqMaxWorkers = 19,
+    /// `IORING_REGISTER_RING_FDS`
+    RegisterRingFds = 20,
+    /// `IORING_UNREGISTER_RING_FDS`
+    UnregisterRingFds = 21,
+    /// `IORING_REGISTER_PBUF_RING`
+    RegisterPbufRing = 22,
+    /// `IORING_UNREGISTER_PBUF_RING`
+    UnregisterPbufRing = 23,
+    /// `IORING_REGISTER_SYNC_CANCEL`
+    RegisterSyncCancel = 24,
+    /// `IORING_REGISTER_FILE_ALLOC_RANGE`
+    RegisterFileAllocRange = 25,
+}
+
+/// `struct io_sqring_offsets`—Offsets of the fields of the submission queue
+/// ring, relative to the start of the ring mapping.
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IoSqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    pub array: u32,
+    pub resv1: u32,
+    pub resv2: u64,
+}
+
+/// `struct io_cqring_offsets`—Offsets of the fields of the completion queue
+/// ring, relative to the start of the ring mapping.
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IoCqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    pub cqes: u32,
+    pub flags: u32,
+    pub resv1: u32,
+    pub resv2: u64,
+}
+
+/// `struct io_uring_params`—Parameters passed to and returned from
+/// [`io_uring_setup`].
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IoUringParams {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    pub flags: IoringSetupFlags,
+    pub sq_thread_cpu: u32,
+    pub sq_thread_idle: u32,
+    pub features: IoringFeatureFlags,
+    pub wq_fd: u32,
+    pub resv: [u32; 3],
+    pub sq_off: IoSqringOffsets,
+    pub cq_off: IoCqringOffsets,
+}
+
+/// `struct io_uring_sqe`—A submission queue entry.
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct IoUringSqe {
+    pub opcode: IoringOp,
+    pub flags: IoringSqeFlags,
+    pub ioprio: u16,
+    pub fd: RawFd,
+    pub off_or_addr2: off_or_addr2_union,
+    pub addr_or_splice_off_in: addr_or_splice_off_in_union,
+    pub len: u32,
+    pub op_flags: op_flags_union,
+    pub user_data: u64,
+    pub buf: buf_union,
+    pub personality: u16,
+    pub splice_fd_in_or_file_index: u32,
+    pub addr3: u64,
+    pub __pad2: [u64; 1],
+}
+
+/// The `off`/`addr2` field of [`IoUringSqe`].
+#[allow(missing_docs)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union off_or_addr2_union {
+    pub off: u64,
+    pub addr2: u64,
+}
+
+/// The `addr`/`splice_off_in` field of [`IoUringSqe`].
+#[allow(missing_docs)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union addr_or_splice_off_in_union {
+    pub addr: u64,
+    pub splice_off_in: u64,
+}
+
+/// The per-opcode flags field of [`IoUringSqe`].
+#[allow(missing_docs)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union op_flags_union {
+    pub rw_flags: u32,
+    pub fsync_flags: IoringFsyncFlags,
+    pub poll_events: u16,
+    pub poll32_events: u32,
+    pub sync_range_flags: u32,
+    pub msg_flags: u32,
+    pub timeout_flags: IoringTimeoutFlags,
+    pub accept_flags: u32,
+    pub cancel_flags: u32,
+    pub open_flags: u32,
+    pub statx_flags: u32,
+    pub fadvise_advice: u32,
+    pub splice_flags: u32,
+    pub rename_flags: u32,
+    pub unlink_flags: u32,
+    pub hardlink_flags: u32,
+    pub xattr_flags: u32,
+}
+
+/// The `buf_index`/`buf_group` field of [`IoUringSqe`].
+#[allow(missing_docs)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union buf_union {
+    pub buf_index: u16,
+    pub buf_group: u16,
+}
+
+impl Default for off_or_addr2_union {
+    #[inline]
+    fn default() -> Self {
+        Self { off: 0 }
+    }
+}
+
+impl Default for addr_or_splice_off_in_union {
+    #[inline]
+    fn default() -> Self {
+        Self { addr: 0 }
+    }
+}
+
+impl Default for op_flags_union {
+    #[inline]
+    fn default() -> Self {
+        Self { rw_flags: 0 }
+    }
+}
+
+impl Default for buf_union {
+    #[inline]
+    fn default() -> Self {
+        Self { buf_index: 0 }
+    }
+}
+
+/// `struct io_uring_cqe`—A completion queue entry.
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IoUringCqe {
+    pub user_data: u64,
+    pub res: i32,
+    pub flags: IoringCqeFlags,
+}
+
+/// The shared submission and completion rings of an io_uring instance,
+/// mapped into the current process with [`mmap`].
+///
+/// The mappings are unmapped when this is dropped.
+///
+/// [`mmap`]: crate::io::mmap
+pub struct IoUringRings {
+    sq_ring: Mapping,
+    cq_ring: Option<Mapping>,
+    sqes: Mapping,
+    sq_off: IoSqringOffsets,
+    cq_off: IoCqringOffsets,
+    sq_entries: u32,
+    cq_entries: u32,
+    sqe_size: usize,
+    cqe_size: usize,
+}
+
+/// A single `mmap`ed region, unmapped on drop.
+struct Mapping {
+    ptr: NonNull<c_void>,
+    len: usize,
+}
+
+impl Mapping {
+    fn new<Fd: AsFd>(fd: &Fd, len: usize, offset: u64) -> io::Result<Self> {
+        // Safety: We pass a null hint address, so this creates a fresh
+        // mapping which nothing else refers to.
+        let ptr = unsafe {
+            io::mmap(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::SHARED | MapFlags::POPULATE,
+                fd,
+                offset,
+            )?
+        };
+        Ok(Self {
+            ptr: NonNull::new(ptr).ok_or(io::Error::INVAL)?,
+            len,
+        })
+    }
+
+    /// Return a pointer to the `T` at byte offset `offset`.
+    #[inline]
+    fn at<T>(&self, offset: u32) -> *mut T {
+        debug_assert!(offset as usize + size_of::<T>() <= self.len);
+        // Safety: `IoUringRings::new` checked that all the offsets we use
+        // are within the mapping.
+        unsafe { self.ptr.as_ptr().cast::<u8>().add(offset as usize).cast() }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // Safety: We own this mapping, and all references into it borrow
+        // from the `IoUringRings` which owns us.
+        unsafe {
+            let _ = io::munmap(self.ptr.as_ptr(), self.len);
+        }
+    }
+}
+
+impl IoUringRings {
+    /// Map the submission queue ring, completion queue ring, and submission
+    /// queue entries of the io_uring instance `fd`, using the sizes and
+    /// offsets that [`io_uring_setup`] returned in `params`.
+    ///
+    /// If `params.features` contains [`IoringFeatureFlags::SINGLE_MMAP`],
+    /// the two rings share a single mapping. If `params.flags` contains
+    /// [`IoringSetupFlags::SQE128`] or [`IoringSetupFlags::CQE32`], the
+    /// entries are indexed with the doubled sizes.
+    ///
+    /// # Safety
+    ///
+    /// The rings and entries are shared memory which [`IoUringRings::sqe_mut`]
+    /// hands out `&mut` references into, so nothing else may access them
+    /// while the returned `IoUringRings` exists. In particular, this must not
+    /// be called again for the same io_uring instance, through `fd` or any
+    /// other file descriptor referring to it, until the returned value has
+    /// been dropped.
+    pub unsafe fn new<Fd: AsFd>(fd: &Fd, params: &IoUringParams) -> io::Result<Self> {
+        let sq_entries = params.sq_entries;
+        let cq_entries = params.cq_entries;
+        if !sq_entries.is_power_of_two() || !cq_entries.is_power_of_two() {
+            return Err(io::Error::INVAL);
+        }
+        let sq_off = params.sq_off;
+        let cq_off = params.cq_off;
+
+        // `SQE128` and `CQE32` double the size of each entry, with the extra
+        // space following the usual fields.
+        let sqe_size = if params.flags.contains(IoringSetupFlags::SQE128) {
+            2 * size_of::<IoUringSqe>()
+        } else {
+            size_of::<IoUringSqe>()
+        };
+        let cqe_size = if params.flags.contains(IoringSetupFlags::CQE32) {
+            2 * size_of::<IoUringCqe>()
+        } else {
+            size_of::<IoUringCqe>()
+        };
+
+        let sq_len = sq_off.array as usize + sq_entries as usize * size_of::<u32>();
+        let cq_len = cq_off.cqes as usize + cq_entries as usize * cqe_size;
+        let sqes_len = sq_entries as usize * sqe_size;
+
+        // Every field we access must lie within its ring.
+        let sq_fields = [
+            sq_off.head,
+            sq_off.tail,
+            sq_off.ring_mask,
+            sq_off.ring_entries,
+            sq_off.flags,
+            sq_off.dropped,
+        ];
+        let cq_fields = [
+            cq_off.head,
+            cq_off.tail,
+            cq_off.ring_mask,
+            cq_off.ring_entries,
+            cq_off.overflow,
+            cq_off.flags,
+        ];
+        if sq_fields
+            .iter()
+            .any(|off| *off as usize + size_of::<u32>() > sq_len)
+            || cq_fields
+                .iter()
+                .any(|off| *off as usize + size_of::<u32>() > cq_len)
+            || sq_off.array as usize & (align_of::<AtomicU32>() - 1) != 0
+            || cq_off.cqes as usize & (align_of::<IoUringCqe>() - 1) != 0
+        {
+            return Err(io::Error::INVAL);
+        }
+
+        let (sq_ring, cq_ring) = if params.features.contains(IoringFeatureFlags::SINGLE_MMAP) {
+            let len = sq_len.max(cq_len);
+            (Mapping::new(fd, len, IORING_OFF_SQ_RING)?, None)
+        } else {
+            (
+                Mapping::new(fd, sq_len, IORING_OFF_SQ_RING)?,
+                Some(Mapping::new(fd, cq_len, IORING_OFF_CQ_RING)?),
+            )
+        };
+        let sqes = Mapping::new(fd, sqes_len, IORING_OFF_SQES)?;
+
+        Ok(Self {
+            sq_ring,
+            cq_ring,
+            sqes,
+            sq_off,
+            cq_off,
+            sq_entries,
+            cq_entries,
+            sqe_size,
+            cqe_size,
+        })
+    }
+
+    #[inline]
+    fn cq_ring(&self) -> &Mapping {
+        self.cq_ring.as_ref().unwrap_or(&self.sq_ring)
+    }
+
+    #[inline]
+    fn sq_word(&self, offset: u32) -> &AtomicU32 {
+        // Safety: The offset was checked in `new`, the kernel aligns ring
+        // fields, and the mapping lives as long as `self`.
+        unsafe { &*self.sq_ring.at::<AtomicU32>(offset) }
+    }
+
+    #[inline]
+    fn cq_word(&self, offset: u32) -> &AtomicU32 {
+        // Safety: See `sq_word`.
+        unsafe { &*self.cq_ring().at::<AtomicU32>(offset) }
+    }
+
+    /// The number of entries in the submission queue.
+    #[inline]
+    pub fn sq_entries(&self) -> u32 {
+        self.sq_entries
+    }
+
+    /// The number of entries in the completion queue.
+    #[inline]
+    pub fn cq_entries(&self) -> u32 {
+        self.cq_entries
+    }
+
+    /// The submission queue head, advanced by the kernel as it consumes
+    /// entries.
+    #[inline]
+    pub fn sq_head(&self) -> &AtomicU32 {
+        self.sq_word(self.sq_off.head)
+    }
+
+    /// The submission queue tail, advanced by the application as it
+    /// submits entries.
+    #[inline]
+    pub fn sq_tail(&self) -> &AtomicU32 {
+        self.sq_word(self.sq_off.tail)
+    }
+
+    /// The submission queue flags word; see [`IoringSqFlags`].
+    #[inline]
+    pub fn sq_flags(&self) -> &AtomicU32 {
+        self.sq_word(self.sq_off.flags)
+    }
+
+    /// The number of invalid submission queue entries the kernel dropped.
+    #[inline]
+    pub fn sq_dropped(&self) -> &AtomicU32 {
+        self.sq_word(self.sq_off.dropped)
+    }
+
+    /// The submission queue index array, which maps ring slots to indices
+    /// into the submission queue entries.
+    #[inline]
+    pub fn sq_array(&self) -> &[AtomicU32] {
+        // Safety: `new` sized the mapping to hold `sq_entries` words at
+        // `sq_off.array`, and checked its alignment.
+        unsafe {
+            core::slice::from_raw_parts(
+                self.sq_ring.at::<AtomicU32>(self.sq_off.array),
+                self.sq_entries as usize,
+            )
+        }
+    }
+
+    /// Return a mutable reference to the submission queue entry at `index`,
+    /// modulo the number of entries.
+    ///
+    /// Entries must not be modified after they are submitted, until the
+    /// kernel has consumed them. With [`IoringSetupFlags::SQE128`], this is
+    /// the first half of the entry.
+    #[inline]
+    pub fn sqe_mut(&mut self, index: u32) -> &mut IoUringSqe {
+        let index = (index & (self.sq_entries - 1)) as usize;
+        // Safety: `new` sized the mapping to hold `sq_entries` entries of
+        // `sqe_size` bytes, and we masked `index` into range. `&mut self`
+        // ensures exclusivity on our side, and `new`'s caller ensured it
+        // on every other side.
+        unsafe {
+            &mut *self
+                .sqes
+                .ptr
+                .as_ptr()
+                .cast::<u8>()
+                .add(index * self.sqe_size)
+                .cast::<IoUringSqe>()
+        }
+    }
+
+    /// The completion queue head, advanced by the application as it
+    /// consumes entries.
+    #[inline]
+    pub fn cq_head(&self) -> &AtomicU32 {
+        self.cq_word(self.cq_off.head)
+    }
+
+    /// The completion queue tail, advanced by the kernel as it posts
+    /// entries.
+    #[inline]
+    pub fn cq_tail(&self) -> &AtomicU32 {
+        self.cq_word(self.cq_off.tail)
+    }
+
+    /// The completion queue flags word; see [`IoringCqFlags`].
+    #[inline]
+    pub fn cq_flags(&self) -> &AtomicU32 {
+        self.cq_word(self.cq_off.flags)
+    }
+
+    /// The number of completion events the kernel dropped due to overflow.
+    #[inline]
+    pub fn cq_overflow(&self) -> &AtomicU32 {
+        self.cq_word(self.cq_off.overflow)
+    }
+
+    /// Read the completion queue entry at `index`, modulo the number of
+    /// entries.
+    ///
+    /// Only entries between [`cq_head`] and [`cq_tail`] (loaded with
+    /// `Acquire` ordering) hold completed events. With
+    /// [`IoringSetupFlags::CQE32`], this is the first half of the entry.
+    ///
+    /// [`cq_head`]: Self::cq_head
+    /// [`cq_tail`]: Self::cq_tail
+    #[inline]
+    pub fn cqe(&self, index: u32) -> IoUringCqe {
+        let index = (index & (self.cq_entries - 1)) as usize;
+        // Safety: `new` sized the mapping to hold `cq_entries` entries of
+        // `cqe_size` bytes at `cq_off.cqes`, and we masked `index` into
+        // range.
+        unsafe {
+            self.cq_ring()
+                .at::<u8>(self.cq_off.cqes)
+                .add(index * self.cqe_size)
+                .cast::<IoUringCqe>()
+                .read_volatile()
+        }
+    }
+}
+
+// Safety: The rings are plain shared memory, accessed through atomics or
+// through methods which require `&mut self`.
+unsafe impl Send for IoUringRings {}
+unsafe impl Sync for IoUringRings {}
+
+#[test]
+fn io_uring_layouts() {
+    assert_eq!(size_of::<IoUringSqe>(), 64);
+    assert_eq!(size_of::<IoUringCqe>(), 16);
+    assert_eq!(size_of::<IoSqringOffsets>(), 40);
+    assert_eq!(size_of::<IoCqringOffsets>(), 40);
+    assert_eq!(size_of::<IoUringParams>(), 120);
+}
@@ -135,6 +135,8 @@ pub mod ffi;
 #[cfg(not(windows))]
 pub mod fs;
 pub mod io;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+pub mod io_uring;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))] // WASI doesn't support `net` yet.
 pub mod net;
 #[cfg(not(windows))]
@@ -143,6 +145,8 @@ pub mod path;
 pub mod process;
 #[cfg(not(windows))]
 pub mod rand;
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub mod termios;
 #[cfg(not(windows))]
 pub mod thread;
 #[cfg(not(windows))]
@@ -0,0 +1,89 @@
+//! The Linux `capget` and `capset` syscalls.
+
+use crate::imp::c::c_int;
+use crate::imp::process::{UserCapData, UserCapHeader};
+use crate::process::{Capability, Pid};
+use crate::{imp, io};
+
+/// `_LINUX_CAPABILITY_VERSION_3`, which uses two `UserCapData` structs to
+/// hold 64 bits per set.
+const LINUX_CAPABILITY_VERSION_3: u32 = 0x2008_0522;
+
+/// The effective, permitted, and inheritable capability sets of a thread,
+/// used in [`capabilities`] and [`set_capabilities`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/capabilities.7.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapabilitySets {
+    /// The capabilities used by the kernel for permission checks.
+    pub effective: Capability,
+    /// The limiting superset of `effective` and `inheritable`.
+    pub permitted: Capability,
+    /// The capabilities preserved across an `execve`.
+    pub inheritable: Capability,
+}
+
+/// `capget(header, data)`—Get the capability sets of a thread.
+///
+/// If `pid` is `None`, this returns the capabilities of the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/capget.2.html
+#[inline]
+#[doc(alias = "capget")]
+pub fn capabilities(pid: Option<Pid>) -> io::Result<CapabilitySets> {
+    let mut header = UserCapHeader {
+        version: LINUX_CAPABILITY_VERSION_3,
+        pid: Pid::as_raw(pid) as c_int,
+    };
+    let mut data = [UserCapData::default(), UserCapData::default()];
+    imp::syscalls::capget(&mut header, &mut data)?;
+
+    let bits =
+        |lo: u32, hi: u32| Capability::from_bits_truncate(u64::from(lo) | (u64::from(hi) << 32));
+    Ok(CapabilitySets {
+        effective: bits(data[0].effective, data[1].effective),
+        permitted: bits(data[0].permitted, data[1].permitted),
+        inheritable: bits(data[0].inheritable, data[1].inheritable),
+    })
+}
+
+/// `capset(header, data)`—Set the capability sets of a thread.
+///
+/// Linux only permits `pid` to be `None` or the calling thread's own id.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/capset.2.html
+#[inline]
+#[doc(alias = "capset")]
+pub fn set_capabilities(pid: Option<Pid>, sets: CapabilitySets) -> io::Result<()> {
+    let mut header = UserCapHeader {
+        version: LINUX_CAPABILITY_VERSION_3,
+        pid: Pid::as_raw(pid) as c_int,
+    };
+    let (effective, permitted, inheritable) = (
+        sets.effective.bits(),
+        sets.permitted.bits(),
+        sets.inheritable.bits(),
+    );
+    let data = [
+        UserCapData {
+            effective: effective as u32,
+            permitted: permitted as u32,
+            inheritable: inheritable as u32,
+        },
+        UserCapData {
+            effective: (effective >> 32) as u32,
+            permitted: (permitted >> 32) as u32,
+            inheritable: (inheritable >> 32) as u32,
+        },
+    ];
+    imp::syscalls::capset(&mut header, &data)
+}
@@ -8,7 +8,8 @@
 //! [`FromRawFd::from_raw_fd`]: https://doc.rust-lang.org/std/os/unix/io/trait.FromRawFd.html#tymethod.from_raw_fd
 #![allow(unsafe_code)]
 
-use crate::imp;
+use crate::{imp, io};
+use alloc::vec::Vec;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 use imp::process::RawCpuid;
 
@@ -39,6 +40,32 @@ pub struct Gid(RawGid);
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
 pub struct Pid(RawNonZeroPid);
 
+/// The real, effective, and saved user IDs of a process, as returned by
+/// [`getresuid`].
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ResUid {
+    /// The real user ID.
+    pub ruid: Uid,
+    /// The effective user ID.
+    pub euid: Uid,
+    /// The saved set-user-ID.
+    pub suid: Uid,
+}
+
+/// The real, effective, and saved group IDs of a process, as returned by
+/// [`getresgid`].
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ResGid {
+    /// The real group ID.
+    pub rgid: Gid,
+    /// The effective group ID.
+    pub egid: Gid,
+    /// The saved set-group-ID.
+    pub sgid: Gid,
+}
+
 /// A Linux CPU ID.
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 #[repr(transparent)]
@@ -203,6 +230,58 @@ pub fn getegid() -> Gid {
     imp::syscalls::getegid()
 }
 
+/// `getresuid()`—Returns the process' real, effective, and saved user IDs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getresuid.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[must_use]
+pub fn getresuid() -> ResUid {
+    imp::syscalls::getresuid()
+}
+
+/// `getresgid()`—Returns the process' real, effective, and saved group IDs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getresgid.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[must_use]
+pub fn getresgid() -> ResGid {
+    imp::syscalls::getresgid()
+}
+
+/// `getgroups()`—Returns the process' supplementary group IDs.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getgroups.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getgroups.2.html
+pub fn getgroups() -> io::Result<Vec<Gid>> {
+    let mut buffer = Vec::new();
+
+    // The list can change between calls, so retry if it grows.
+    loop {
+        let len = imp::syscalls::getgroups(&mut [])?;
+        buffer.resize(len, Gid::ROOT);
+        match imp::syscalls::getgroups(&mut buffer) {
+            Ok(len) => {
+                buffer.truncate(len);
+                return Ok(buffer);
+            }
+            Err(io::Error::INVAL) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
 /// `getpid()`—Returns the process' ID.
 ///
 /// # References
@@ -230,3 +309,84 @@ pub fn getpid() -> Pid {
 pub fn getppid() -> Option<Pid> {
     imp::syscalls::getppid()
 }
+
+/// `getpgrp()`—Returns the process' group ID.
+///
+/// This returns `None` if the process group is in an ancestor PID namespace,
+/// and so isn't visible to the calling process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getpgrp.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getpgrp.2.html
+#[inline]
+#[must_use]
+pub fn getpgrp() -> Option<Pid> {
+    imp::syscalls::getpgrp()
+}
+
+/// `getpgid(pid)`—Returns the process group ID of the given process.
+///
+/// A `pid` of `None` means the calling process. This fails with
+/// [`io::Error::OPNOTSUPP`] if the process group is in an ancestor PID
+/// namespace, and so isn't visible to the calling process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getpgid.2.html
+#[inline]
+pub fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
+    imp::syscalls::getpgid(pid)
+}
+
+/// `setpgid(pid, pgid)`—Sets the process group ID of the given process.
+///
+/// A `pid` of `None` means the calling process, and a `pgid` of `None` means
+/// to use the process ID of the process identified by `pid`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setpgid.2.html
+#[inline]
+pub fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    imp::syscalls::setpgid(pid, pgid)
+}
+
+/// `getsid(pid)`—Returns the session ID of the given process.
+///
+/// A `pid` of `None` means the calling process. This fails with
+/// [`io::Error::OPNOTSUPP`] if the session is in an ancestor PID namespace,
+/// and so isn't visible to the calling process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getsid.2.html
+#[inline]
+pub fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    imp::syscalls::getsid(pid)
+}
+
+/// `setsid()`—Creates a new session, with the calling process as its leader,
+/// and returns the new session ID.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setsid.2.html
+#[inline]
+pub fn setsid() -> io::Result<Pid> {
+    imp::syscalls::setsid()
+}
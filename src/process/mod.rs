@@ -3,14 +3,26 @@
 use crate::{imp, io};
 
 mod auxv;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod capabilities;
 #[cfg(not(target_os = "wasi"))]
 mod chdir;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 mod membarrier;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod namespace;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod pidfd;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod prctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod process_vm;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod ptrace;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 #[cfg(any(
@@ -20,6 +32,21 @@ mod rlimit;
     target_os = "dragonfly"
 ))]
 mod sched;
+#[cfg(all(
+    any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub(crate) mod seccomp;
+#[cfg(not(target_os = "wasi"))]
+mod signal;
+#[cfg(not(target_os = "wasi"))]
+mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
 #[cfg(not(target_os = "wasi"))]
@@ -39,6 +66,8 @@ pub use auxv::page_size;
     )
 ))]
 pub use auxv::{linux_execfn, linux_hwcap};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use capabilities::{capabilities, set_capabilities, CapabilitySets};
 #[cfg(not(target_os = "wasi"))]
 pub use chdir::chdir;
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
@@ -49,13 +78,39 @@ pub use chdir::getcwd;
 pub use id::Cpuid;
 #[cfg(not(target_os = "wasi"))]
 pub use id::{
-    getegid, geteuid, getgid, getpid, getppid, getuid, Gid, Pid, RawGid, RawNonZeroPid, RawPid,
-    RawUid, Uid,
+    getegid, geteuid, getgid, getgroups, getpgid, getpgrp, getpid, getppid, getsid, getuid,
+    setpgid, setsid, Gid, Pid, RawGid, RawNonZeroPid, RawPid, RawUid, Uid,
 };
 #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use id::{getresgid, getresuid, ResGid, ResUid};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
 pub use membarrier::{
     membarrier, membarrier_cpu, membarrier_query, MembarrierCommand, MembarrierQuery,
 };
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+pub use namespace::{
+    ioctl_ns_get_nstype, ioctl_ns_get_owner_uid, ioctl_ns_get_parent, ioctl_ns_get_userns,
+};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use namespace::{setns, unshare, NamespaceType, UnshareFlags};
+#[cfg(all(
+    feature = "procfs",
+    any(linux_raw, all(libc, any(target_os = "android", target_os = "linux")))
+))]
+pub use namespace::{write_gid_map, write_setgroups, write_uid_map, IdMap, SetgroupsPolicy};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use pidfd::{
+    pidfd_getfd, pidfd_open, pidfd_send_signal, waitid_pidfd, PidfdFlags, PidfdGetfdFlags,
+};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use prctl::{
+    ambient_capabilities, bounding_set_capabilities, clear_ambient_capabilities,
+    drop_bounding_set_capabilities, dumpable_behavior, is_child_subreaper, keep_capabilities,
+    lower_ambient_capabilities, no_new_privs, parent_process_death_signal,
+    raise_ambient_capabilities, set_child_subreaper, set_dumpable_behavior, set_keep_capabilities,
+    set_no_new_privs, set_parent_process_death_signal, set_thread_name, set_timer_slack,
+    thread_name, timer_slack, Capability, DumpableBehavior,
+};
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 pub use priority::nice;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
@@ -63,8 +118,30 @@ pub use priority::{
     getpriority_pgrp, getpriority_process, getpriority_user, setpriority_pgrp, setpriority_process,
     setpriority_user,
 };
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use process_vm::{process_vm_readv, process_vm_writev, RemoteIoVec};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use ptrace::{
+    ptrace_attach, ptrace_cont, ptrace_detach, ptrace_get_syscall_info, ptrace_geteventmsg,
+    ptrace_getregset, ptrace_interrupt, ptrace_peekdata, ptrace_pokedata, ptrace_seize,
+    ptrace_setoptions, ptrace_singlestep, ptrace_syscall, ptrace_traceme, PtraceEvent,
+    PtraceOptions, PtraceSyscallInfo, PtraceSyscallOp, RegisterSet,
+};
+#[cfg(all(
+    any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub use ptrace::{ptrace_getregs, UserRegs};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use rlimit::prlimit;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
-pub use rlimit::{getrlimit, Resource, Rlimit};
+pub use rlimit::{getrlimit, setrlimit, Resource, Rlimit};
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -72,8 +149,35 @@ pub use rlimit::{getrlimit, Resource, Rlimit};
     target_os = "dragonfly"
 ))]
 pub use sched::{sched_getaffinity, sched_setaffinity, CpuSet};
+#[cfg(all(
+    any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+pub use seccomp::{
+    seccomp_get_action_avail, seccomp_notif_addfd, seccomp_notif_id_valid, seccomp_notif_recv,
+    seccomp_notif_send, seccomp_set_mode_filter, SeccompAction, SeccompData, SeccompFilter,
+    SeccompFilterFlags, SeccompNotif, SeccompNotifAddfdFlags, SeccompNotifResp, SockFilter,
+    Syscall,
+};
+#[cfg(not(target_os = "wasi"))]
+pub use signal::Signal;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use signal::{
+    kill_current_process_group, kill_process, kill_process_group, sigprocmask, tgkill, SigSet,
+    SigmaskHow,
+};
+#[cfg(not(target_os = "wasi"))]
+pub use umask::umask;
 #[cfg(not(target_os = "wasi"))]
 pub use uname::{uname, Uname};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use wait::{WaitId, WaitidOptions, WaitidStatus};
 #[cfg(not(target_os = "wasi"))]
 pub use wait::{WaitOptions, WaitStatus};
 
@@ -173,3 +277,24 @@ pub fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<Wai
 pub fn wait(waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
     imp::syscalls::wait(waitopts)
 }
+
+/// `waitid(idtype, id, options)`—Wait for one or more processes to change
+/// state.
+///
+/// On success, returns the status of the selected process.
+///
+/// If `NOHANG` was specified in the options, and no selected process changed
+/// state, returns `None`. If `NOWAIT` was specified, the process is left in a
+/// waitable state, so that it can be waited on again.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/waitid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/waitid.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn waitid<'a>(id: WaitId<'a>, options: WaitidOptions) -> io::Result<Option<WaitidStatus>> {
+    imp::syscalls::waitid(id, options)
+}
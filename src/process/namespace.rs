@@ -0,0 +1,213 @@
+//! Linux namespaces.
+
+#[cfg(feature = "procfs")]
+use crate::ffi::ZStr;
+#[cfg(feature = "procfs")]
+use crate::fs::OFlags;
+use crate::imp::fd::AsFd;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+use crate::io::OwnedFd;
+#[cfg(feature = "procfs")]
+use crate::process::Pid;
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+use crate::process::Uid;
+use crate::{imp, io};
+#[cfg(feature = "procfs")]
+use alloc::string::String;
+#[cfg(feature = "procfs")]
+use core::fmt::Write;
+
+pub use imp::process::{NamespaceType, UnshareFlags};
+
+/// `unshare(flags)`—Disassociate parts of the calling thread's execution
+/// context, such as namespaces, from other threads and processes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/unshare.2.html
+#[inline]
+pub fn unshare(flags: UnshareFlags) -> io::Result<()> {
+    imp::syscalls::unshare(flags)
+}
+
+/// `setns(fd, nstype)`—Move the calling thread into the namespace referred
+/// to by `fd`.
+///
+/// If `nstype` is empty, `fd` may refer to a namespace of any type; otherwise
+/// it must refer to a namespace of the given type.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setns.2.html
+#[inline]
+pub fn setns<Fd: AsFd>(fd: &Fd, nstype: NamespaceType) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::setns(fd, nstype)
+}
+
+/// `ioctl(fd, NS_GET_USERNS)`—Return the user namespace which owns the
+/// namespace referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ns.2.html
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+#[inline]
+#[doc(alias = "NS_GET_USERNS")]
+pub fn ioctl_ns_get_userns<Fd: AsFd>(fd: &Fd) -> io::Result<OwnedFd> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_ns_get_userns(fd)
+}
+
+/// `ioctl(fd, NS_GET_PARENT)`—Return the parent of the PID or user namespace
+/// referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ns.2.html
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+#[inline]
+#[doc(alias = "NS_GET_PARENT")]
+pub fn ioctl_ns_get_parent<Fd: AsFd>(fd: &Fd) -> io::Result<OwnedFd> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_ns_get_parent(fd)
+}
+
+/// `ioctl(fd, NS_GET_NSTYPE)`—Return the type of the namespace referred to
+/// by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ns.2.html
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+#[inline]
+#[doc(alias = "NS_GET_NSTYPE")]
+pub fn ioctl_ns_get_nstype<Fd: AsFd>(fd: &Fd) -> io::Result<NamespaceType> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_ns_get_nstype(fd)
+}
+
+/// `ioctl(fd, NS_GET_OWNER_UID, &uid)`—Return the user id of the owner of
+/// the user namespace referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ns.2.html
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+#[inline]
+#[doc(alias = "NS_GET_OWNER_UID")]
+pub fn ioctl_ns_get_owner_uid<Fd: AsFd>(fd: &Fd) -> io::Result<Uid> {
+    let fd = fd.as_fd();
+    imp::syscalls::ioctl_ns_get_owner_uid(fd)
+}
+
+/// A line of a user namespace's `uid_map` or `gid_map` file, for use with
+/// [`write_uid_map`] and [`write_gid_map`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/user_namespaces.7.html
+#[cfg(feature = "procfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdMap {
+    /// The first id of the range, inside the namespace.
+    pub inside: u32,
+    /// The first id of the range, in the namespace of the writing process.
+    pub outside: u32,
+    /// The number of ids in the range.
+    pub count: u32,
+}
+
+/// The contents of a user namespace's `setgroups` file, for use with
+/// [`write_setgroups`].
+#[cfg(feature = "procfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetgroupsPolicy {
+    /// `allow`—Allow `setgroups` calls in the namespace.
+    Allow,
+    /// `deny`—Deny `setgroups` calls in the namespace.
+    Deny,
+}
+
+/// Write `/proc/<pid>/uid_map`—Set the user id mapping of the user namespace
+/// of process `pid`, or of the current process if `pid` is `None`.
+///
+/// The mapping may only be written once.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/user_namespaces.7.html
+#[cfg(feature = "procfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn write_uid_map(pid: Option<Pid>, map: &[IdMap]) -> io::Result<()> {
+    write_proc_file(pid, zstr!("uid_map"), &id_map_contents(map))
+}
+
+/// Write `/proc/<pid>/gid_map`—Set the group id mapping of the user
+/// namespace of process `pid`, or of the current process if `pid` is `None`.
+///
+/// The mapping may only be written once. Unprivileged processes must first
+/// deny `setgroups` with [`write_setgroups`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/user_namespaces.7.html
+#[cfg(feature = "procfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn write_gid_map(pid: Option<Pid>, map: &[IdMap]) -> io::Result<()> {
+    write_proc_file(pid, zstr!("gid_map"), &id_map_contents(map))
+}
+
+/// Write `/proc/<pid>/setgroups`—Allow or deny `setgroups` in the user
+/// namespace of process `pid`, or of the current process if `pid` is `None`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/user_namespaces.7.html
+#[cfg(feature = "procfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn write_setgroups(pid: Option<Pid>, policy: SetgroupsPolicy) -> io::Result<()> {
+    let contents = match policy {
+        SetgroupsPolicy::Allow => "allow",
+        SetgroupsPolicy::Deny => "deny",
+    };
+    write_proc_file(pid, zstr!("setgroups"), contents)
+}
+
+#[cfg(feature = "procfs")]
+fn id_map_contents(map: &[IdMap]) -> String {
+    let mut contents = String::new();
+    for entry in map {
+        writeln!(
+            contents,
+            "{} {} {}",
+            entry.inside, entry.outside, entry.count
+        )
+        .unwrap();
+    }
+    contents
+}
+
+/// Write `contents` to `/proc/<pid>/<name>` in a single `write`, as the
+/// kernel requires for these files.
+#[cfg(feature = "procfs")]
+fn write_proc_file(pid: Option<Pid>, name: &ZStr, contents: &str) -> io::Result<()> {
+    let file = io::open_proc_pid_file(pid, name, OFlags::WRONLY)?;
+    let written = io::write(&file, contents.as_bytes())?;
+    if written != contents.len() {
+        return Err(io::Error::IO);
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::fd::{AsFd, RawFd};
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::process::{Pid, Signal, WaitId, WaitOptions, WaitStatus, WaitidOptions};
+
+pub use imp::process::{PidfdFlags, PidfdGetfdFlags};
+
+/// `pidfd_open(pid, flags)`—Creates a file descriptor referring to a
+/// process.
+///
+/// The file descriptor becomes readable, in [`poll`] or [`Epoll`], when the
+/// process terminates.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_open.2.html
+/// [`poll`]: crate::io::poll
+/// [`Epoll`]: crate::io::epoll::Epoll
+#[inline]
+pub fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::pidfd_open(pid, flags)
+}
+
+/// `pidfd_send_signal(pidfd, sig, NULL, 0)`—Sends a signal to the process
+/// referred to by a pidfd.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html
+#[inline]
+pub fn pidfd_send_signal<Fd: AsFd>(pidfd: &Fd, sig: Signal) -> io::Result<()> {
+    let pidfd = pidfd.as_fd();
+    imp::syscalls::pidfd_send_signal(pidfd, sig)
+}
+
+/// `pidfd_getfd(pidfd, targetfd, flags)`—Duplicates a file descriptor from
+/// the process referred to by a pidfd into the calling process.
+///
+/// The new file descriptor has the close-on-exec flag set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html
+#[inline]
+pub fn pidfd_getfd<Fd: AsFd>(
+    pidfd: &Fd,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    let pidfd = pidfd.as_fd();
+    imp::syscalls::pidfd_getfd(pidfd, targetfd, flags)
+}
+
+/// `waitid(P_PIDFD, pidfd, &info, waitopts | WEXITED)`—Wait for the child
+/// process referred to by a pidfd to change state.
+///
+/// Unlike [`waitpid`], this can't be confused by the process ID being
+/// reused.
+///
+/// On success, returns the status of the process.
+///
+/// If `NOHANG` was specified in the options, and the process didn't change
+/// state, returns `None`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/waitid.2.html
+/// [`waitpid`]: crate::process::waitpid
+#[inline]
+#[doc(alias = "waitid")]
+pub fn waitid_pidfd<Fd: AsFd>(pidfd: &Fd, waitopts: WaitOptions) -> io::Result<Option<WaitStatus>> {
+    let pidfd = pidfd.as_fd();
+    let options = WaitidOptions::from_bits_truncate(waitopts.bits()) | WaitidOptions::EXITED;
+    Ok(imp::syscalls::waitid(WaitId::PidFd(pidfd), options)?
+        .map(|status| WaitStatus::from_sigchld(status.raw_code(), status.raw_status())))
+}
@@ -0,0 +1,529 @@
+//! Typed wrappers for the Linux `prctl` syscall.
+//!
+//! # Safety
+//!
+//! `prctl` takes untyped arguments, so each function here passes pointers
+//! and integers of the type its option expects.
+#![allow(unsafe_code)]
+
+use crate::ffi::{ZStr, ZString};
+use crate::imp::c::{c_int, c_void};
+use crate::process::Signal;
+use crate::{imp, io};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::mem::MaybeUninit;
+use core::num::NonZeroU64;
+use core::ptr::null_mut;
+
+const PR_SET_PDEATHSIG: c_int = 1;
+const PR_GET_PDEATHSIG: c_int = 2;
+const PR_GET_DUMPABLE: c_int = 3;
+const PR_SET_DUMPABLE: c_int = 4;
+const PR_GET_KEEPCAPS: c_int = 7;
+const PR_SET_KEEPCAPS: c_int = 8;
+const PR_SET_NAME: c_int = 15;
+const PR_GET_NAME: c_int = 16;
+const PR_CAPBSET_READ: c_int = 23;
+const PR_CAPBSET_DROP: c_int = 24;
+const PR_SET_TIMERSLACK: c_int = 29;
+const PR_GET_TIMERSLACK: c_int = 30;
+const PR_SET_CHILD_SUBREAPER: c_int = 36;
+const PR_GET_CHILD_SUBREAPER: c_int = 37;
+const PR_SET_NO_NEW_PRIVS: c_int = 38;
+const PR_GET_NO_NEW_PRIVS: c_int = 39;
+const PR_CAP_AMBIENT: c_int = 47;
+
+const PR_CAP_AMBIENT_IS_SET: usize = 1;
+const PR_CAP_AMBIENT_RAISE: usize = 2;
+const PR_CAP_AMBIENT_LOWER: usize = 3;
+const PR_CAP_AMBIENT_CLEAR_ALL: usize = 4;
+
+/// The maximum length of a thread name set with `PR_SET_NAME`, including the
+/// NUL terminator.
+const TASK_COMM_LEN: usize = 16;
+
+#[inline]
+unsafe fn prctl_1arg(option: c_int) -> io::Result<usize> {
+    imp::syscalls::prctl(option, null_mut(), null_mut(), null_mut(), null_mut())
+}
+
+#[inline]
+unsafe fn prctl_2args(option: c_int, arg2: *mut c_void) -> io::Result<usize> {
+    imp::syscalls::prctl(option, arg2, null_mut(), null_mut(), null_mut())
+}
+
+#[inline]
+unsafe fn prctl_3args(option: c_int, arg2: *mut c_void, arg3: *mut c_void) -> io::Result<usize> {
+    imp::syscalls::prctl(option, arg2, arg3, null_mut(), null_mut())
+}
+
+#[inline]
+unsafe fn prctl_get_at_arg2<T>(option: c_int) -> io::Result<T> {
+    let mut value = MaybeUninit::<T>::uninit();
+    prctl_2args(option, value.as_mut_ptr().cast())?;
+    Ok(value.assume_init())
+}
+
+/// `prctl(PR_GET_PDEATHSIG, ...)`—Get the signal the calling thread will
+/// receive when its parent dies.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_PDEATHSIG")]
+pub fn parent_process_death_signal() -> io::Result<Option<Signal>> {
+    let raw = unsafe { prctl_get_at_arg2::<c_int>(PR_GET_PDEATHSIG)? };
+    Ok(Signal::from_raw(raw))
+}
+
+/// `prctl(PR_SET_PDEATHSIG, signal)`—Set the signal the calling thread will
+/// receive when its parent dies.
+///
+/// `None` clears the signal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_PDEATHSIG")]
+pub fn set_parent_process_death_signal(signal: Option<Signal>) -> io::Result<()> {
+    let raw = signal.map_or(0, Signal::as_raw) as usize;
+    unsafe { prctl_2args(PR_SET_PDEATHSIG, raw as *mut _).map(|_| ()) }
+}
+
+/// The value of the "dumpable" attribute, used in [`dumpable_behavior`] and
+/// [`set_dumpable_behavior`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum DumpableBehavior {
+    /// Not dumpable.
+    #[doc(alias = "SUID_DUMP_DISABLE")]
+    NotDumpable = 0,
+    /// Dumpable.
+    #[doc(alias = "SUID_DUMP_USER")]
+    Dumpable = 1,
+    /// Dumpable but only readable by root.
+    #[doc(alias = "SUID_DUMP_ROOT")]
+    DumpableReadableOnlyByRoot = 2,
+}
+
+/// `prctl(PR_GET_DUMPABLE)`—Get the "dumpable" attribute of the calling
+/// process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_DUMPABLE")]
+pub fn dumpable_behavior() -> io::Result<DumpableBehavior> {
+    match unsafe { prctl_1arg(PR_GET_DUMPABLE)? } {
+        0 => Ok(DumpableBehavior::NotDumpable),
+        1 => Ok(DumpableBehavior::Dumpable),
+        2 => Ok(DumpableBehavior::DumpableReadableOnlyByRoot),
+        _ => Err(io::Error::RANGE),
+    }
+}
+
+/// `prctl(PR_SET_DUMPABLE, value)`—Set the "dumpable" attribute of the
+/// calling process.
+///
+/// Linux only accepts [`DumpableBehavior::NotDumpable`] and
+/// [`DumpableBehavior::Dumpable`] here.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_DUMPABLE")]
+pub fn set_dumpable_behavior(behavior: DumpableBehavior) -> io::Result<()> {
+    unsafe { prctl_2args(PR_SET_DUMPABLE, behavior as usize as *mut _).map(|_| ()) }
+}
+
+/// `prctl(PR_GET_KEEPCAPS)`—Get the "keep capabilities" flag of the calling
+/// thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_KEEPCAPS")]
+pub fn keep_capabilities() -> io::Result<bool> {
+    unsafe { prctl_1arg(PR_GET_KEEPCAPS).map(|r| r != 0) }
+}
+
+/// `prctl(PR_SET_KEEPCAPS, value)`—Set the "keep capabilities" flag of the
+/// calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_KEEPCAPS")]
+pub fn set_keep_capabilities(enable: bool) -> io::Result<()> {
+    unsafe { prctl_2args(PR_SET_KEEPCAPS, enable as usize as *mut _).map(|_| ()) }
+}
+
+/// `prctl(PR_GET_NAME, ...)`—Get the name of the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_NAME")]
+pub fn thread_name() -> io::Result<ZString> {
+    let mut buffer = [0_u8; TASK_COMM_LEN];
+    unsafe { prctl_2args(PR_GET_NAME, buffer.as_mut_ptr().cast())? };
+
+    let len = buffer
+        .iter()
+        .position(|b| *b == b'\0')
+        .unwrap_or(buffer.len());
+    Ok(ZString::new(Vec::from(&buffer[..len])).unwrap())
+}
+
+/// `prctl(PR_SET_NAME, name)`—Set the name of the calling thread.
+///
+/// Names longer than 15 bytes are truncated by the kernel.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_NAME")]
+pub fn set_thread_name(name: &ZStr) -> io::Result<()> {
+    unsafe { prctl_2args(PR_SET_NAME, name.as_ptr() as *mut _).map(|_| ()) }
+}
+
+/// `prctl(PR_GET_TIMERSLACK)`—Get the "current" timer slack value of the
+/// calling thread, in nanoseconds.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_TIMERSLACK")]
+pub fn timer_slack() -> io::Result<u64> {
+    unsafe { prctl_1arg(PR_GET_TIMERSLACK).map(|r| r as u64) }
+}
+
+/// `prctl(PR_SET_TIMERSLACK, value)`—Set the "current" timer slack value of
+/// the calling thread, in nanoseconds.
+///
+/// `None` resets it to the thread's "default" timer slack value.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_TIMERSLACK")]
+pub fn set_timer_slack(value: Option<NonZeroU64>) -> io::Result<()> {
+    let value: usize = value
+        .map_or(0, NonZeroU64::get)
+        .try_into()
+        .map_err(|_| io::Error::INVAL)?;
+    unsafe { prctl_2args(PR_SET_TIMERSLACK, value as *mut _).map(|_| ()) }
+}
+
+/// `prctl(PR_GET_CHILD_SUBREAPER, ...)`—Get the "child subreaper" attribute
+/// of the calling process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_CHILD_SUBREAPER")]
+pub fn is_child_subreaper() -> io::Result<bool> {
+    let raw = unsafe { prctl_get_at_arg2::<c_int>(PR_GET_CHILD_SUBREAPER)? };
+    Ok(raw != 0)
+}
+
+/// `prctl(PR_SET_CHILD_SUBREAPER, value)`—Set the "child subreaper"
+/// attribute of the calling process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_CHILD_SUBREAPER")]
+pub fn set_child_subreaper(enable: bool) -> io::Result<()> {
+    unsafe { prctl_2args(PR_SET_CHILD_SUBREAPER, enable as usize as *mut _).map(|_| ()) }
+}
+
+/// `prctl(PR_GET_NO_NEW_PRIVS)`—Get the "no new privs" attribute of the
+/// calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_GET_NO_NEW_PRIVS")]
+pub fn no_new_privs() -> io::Result<bool> {
+    unsafe { prctl_1arg(PR_GET_NO_NEW_PRIVS).map(|r| r != 0) }
+}
+
+/// `prctl(PR_SET_NO_NEW_PRIVS, value)`—Set the "no new privs" attribute of
+/// the calling thread.
+///
+/// Once set, this attribute cannot be unset.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_SET_NO_NEW_PRIVS")]
+pub fn set_no_new_privs(no_new_privs: bool) -> io::Result<()> {
+    unsafe { prctl_2args(PR_SET_NO_NEW_PRIVS, no_new_privs as usize as *mut _).map(|_| ()) }
+}
+
+bitflags::bitflags! {
+    /// A set of Linux capabilities, with each capability `CAP_*` stored at
+    /// bit number `CAP_*`.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man7/capabilities.7.html
+    pub struct Capability: u64 {
+        /// `CAP_CHOWN`
+        const CHOWN = 1 << 0;
+        /// `CAP_DAC_OVERRIDE`
+        const DAC_OVERRIDE = 1 << 1;
+        /// `CAP_DAC_READ_SEARCH`
+        const DAC_READ_SEARCH = 1 << 2;
+        /// `CAP_FOWNER`
+        const FOWNER = 1 << 3;
+        /// `CAP_FSETID`
+        const FSETID = 1 << 4;
+        /// `CAP_KILL`
+        const KILL = 1 << 5;
+        /// `CAP_SETGID`
+        const SETGID = 1 << 6;
+        /// `CAP_SETUID`
+        const SETUID = 1 << 7;
+        /// `CAP_SETPCAP`
+        const SETPCAP = 1 << 8;
+        /// `CAP_LINUX_IMMUTABLE`
+        const LINUX_IMMUTABLE = 1 << 9;
+        /// `CAP_NET_BIND_SERVICE`
+        const NET_BIND_SERVICE = 1 << 10;
+        /// `CAP_NET_BROADCAST`
+        const NET_BROADCAST = 1 << 11;
+        /// `CAP_NET_ADMIN`
+        const NET_ADMIN = 1 << 12;
+        /// `CAP_NET_RAW`
+        const NET_RAW = 1 << 13;
+        /// `CAP_IPC_LOCK`
+        const IPC_LOCK = 1 << 14;
+        /// `CAP_IPC_OWNER`
+        const IPC_OWNER = 1 << 15;
+        /// `CAP_SYS_MODULE`
+        const SYS_MODULE = 1 << 16;
+        /// `CAP_SYS_RAWIO`
+        const SYS_RAWIO = 1 << 17;
+        /// `CAP_SYS_CHROOT`
+        const SYS_CHROOT = 1 << 18;
+        /// `CAP_SYS_PTRACE`
+        const SYS_PTRACE = 1 << 19;
+        /// `CAP_SYS_PACCT`
+        const SYS_PACCT = 1 << 20;
+        /// `CAP_SYS_ADMIN`
+        const SYS_ADMIN = 1 << 21;
+        /// `CAP_SYS_BOOT`
+        const SYS_BOOT = 1 << 22;
+        /// `CAP_SYS_NICE`
+        const SYS_NICE = 1 << 23;
+        /// `CAP_SYS_RESOURCE`
+        const SYS_RESOURCE = 1 << 24;
+        /// `CAP_SYS_TIME`
+        const SYS_TIME = 1 << 25;
+        /// `CAP_SYS_TTY_CONFIG`
+        const SYS_TTY_CONFIG = 1 << 26;
+        /// `CAP_MKNOD`
+        const MKNOD = 1 << 27;
+        /// `CAP_LEASE`
+        const LEASE = 1 << 28;
+        /// `CAP_AUDIT_WRITE`
+        const AUDIT_WRITE = 1 << 29;
+        /// `CAP_AUDIT_CONTROL`
+        const AUDIT_CONTROL = 1 << 30;
+        /// `CAP_SETFCAP`
+        const SETFCAP = 1 << 31;
+        /// `CAP_MAC_OVERRIDE`
+        const MAC_OVERRIDE = 1 << 32;
+        /// `CAP_MAC_ADMIN`
+        const MAC_ADMIN = 1 << 33;
+        /// `CAP_SYSLOG`
+        const SYSLOG = 1 << 34;
+        /// `CAP_WAKE_ALARM`
+        const WAKE_ALARM = 1 << 35;
+        /// `CAP_BLOCK_SUSPEND`
+        const BLOCK_SUSPEND = 1 << 36;
+        /// `CAP_AUDIT_READ` (since Linux 3.16)
+        const AUDIT_READ = 1 << 37;
+        /// `CAP_PERFMON` (since Linux 5.8)
+        const PERFMON = 1 << 38;
+        /// `CAP_BPF` (since Linux 5.8)
+        const BPF = 1 << 39;
+        /// `CAP_CHECKPOINT_RESTORE` (since Linux 5.9)
+        const CHECKPOINT_RESTORE = 1 << 40;
+    }
+}
+
+/// Call `f` with the number of each capability in `capabilities`, stopping at
+/// the first error.
+fn for_each_capability(
+    capabilities: Capability,
+    mut f: impl FnMut(usize) -> io::Result<()>,
+) -> io::Result<()> {
+    let bits = capabilities.bits();
+    for cap in 0..64 {
+        if bits & (1 << cap) != 0 {
+            f(cap)?;
+        }
+    }
+    Ok(())
+}
+
+/// Return the subset of all known capabilities for which `f` returns `true`.
+///
+/// Capabilities the kernel doesn't know about, which it reports with
+/// `EINVAL`, are treated as absent.
+fn filter_capabilities(mut f: impl FnMut(usize) -> io::Result<bool>) -> io::Result<Capability> {
+    let mut result = Capability::empty();
+    for_each_capability(Capability::all(), |cap| {
+        match f(cap) {
+            Ok(true) => result |= Capability::from_bits_truncate(1 << cap),
+            Ok(false) | Err(io::Error::INVAL) => (),
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    })?;
+    Ok(result)
+}
+
+/// `prctl(PR_CAPBSET_READ, cap)`—Get the calling thread's capability bounding
+/// set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[doc(alias = "PR_CAPBSET_READ")]
+pub fn bounding_set_capabilities() -> io::Result<Capability> {
+    filter_capabilities(|cap| unsafe {
+        prctl_2args(PR_CAPBSET_READ, cap as *mut _).map(|r| r != 0)
+    })
+}
+
+/// `prctl(PR_CAPBSET_DROP, cap)`—Drop capabilities from the calling thread's
+/// capability bounding set.
+///
+/// Each capability in `capabilities` is dropped in turn; if one fails, the
+/// ones before it have already been dropped.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[doc(alias = "PR_CAPBSET_DROP")]
+pub fn drop_bounding_set_capabilities(capabilities: Capability) -> io::Result<()> {
+    for_each_capability(capabilities, |cap| unsafe {
+        prctl_2args(PR_CAPBSET_DROP, cap as *mut _).map(|_| ())
+    })
+}
+
+/// `prctl(PR_CAP_AMBIENT, PR_CAP_AMBIENT_IS_SET, cap)`—Get the calling
+/// thread's ambient capability set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[doc(alias = "PR_CAP_AMBIENT")]
+#[doc(alias = "PR_CAP_AMBIENT_IS_SET")]
+pub fn ambient_capabilities() -> io::Result<Capability> {
+    filter_capabilities(|cap| unsafe {
+        prctl_3args(
+            PR_CAP_AMBIENT,
+            PR_CAP_AMBIENT_IS_SET as *mut _,
+            cap as *mut _,
+        )
+        .map(|r| r != 0)
+    })
+}
+
+/// `prctl(PR_CAP_AMBIENT, PR_CAP_AMBIENT_RAISE, cap)`—Add capabilities to the
+/// calling thread's ambient capability set.
+///
+/// Each capability must already be in both the permitted and inheritable
+/// sets. They are raised in turn; if one fails, the ones before it have
+/// already been raised.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[doc(alias = "PR_CAP_AMBIENT_RAISE")]
+pub fn raise_ambient_capabilities(capabilities: Capability) -> io::Result<()> {
+    for_each_capability(capabilities, |cap| unsafe {
+        prctl_3args(
+            PR_CAP_AMBIENT,
+            PR_CAP_AMBIENT_RAISE as *mut _,
+            cap as *mut _,
+        )
+        .map(|_| ())
+    })
+}
+
+/// `prctl(PR_CAP_AMBIENT, PR_CAP_AMBIENT_LOWER, cap)`—Remove capabilities from
+/// the calling thread's ambient capability set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[doc(alias = "PR_CAP_AMBIENT_LOWER")]
+pub fn lower_ambient_capabilities(capabilities: Capability) -> io::Result<()> {
+    for_each_capability(capabilities, |cap| unsafe {
+        prctl_3args(
+            PR_CAP_AMBIENT,
+            PR_CAP_AMBIENT_LOWER as *mut _,
+            cap as *mut _,
+        )
+        .map(|_| ())
+    })
+}
+
+/// `prctl(PR_CAP_AMBIENT, PR_CAP_AMBIENT_CLEAR_ALL)`—Clear the calling
+/// thread's ambient capability set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+#[doc(alias = "PR_CAP_AMBIENT_CLEAR_ALL")]
+pub fn clear_ambient_capabilities() -> io::Result<()> {
+    unsafe { prctl_2args(PR_CAP_AMBIENT, PR_CAP_AMBIENT_CLEAR_ALL as *mut _).map(|_| ()) }
+}
@@ -0,0 +1,53 @@
+//! The Linux `process_vm_readv` and `process_vm_writev` syscalls.
+
+use crate::imp;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::process::Pid;
+
+pub use imp::process::RemoteIoVec;
+
+impl RemoteIoVec {
+    /// Construct a `RemoteIoVec` for the `len` bytes at `base`.
+    #[inline]
+    pub const fn new(base: usize, len: usize) -> Self {
+        Self { base, len }
+    }
+}
+
+/// `process_vm_readv(pid, local, remote, 0)`—Reads from the memory of
+/// another process into multiple buffers.
+///
+/// Returns the number of bytes read, which may be less than requested if a
+/// remote range is only partially readable.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_readv.2.html
+#[inline]
+pub fn process_vm_readv(
+    pid: Pid,
+    local: &[IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    imp::syscalls::process_vm_readv(pid, local, remote)
+}
+
+/// `process_vm_writev(pid, local, remote, 0)`—Writes to the memory of
+/// another process from multiple buffers.
+///
+/// Returns the number of bytes written, which may be less than requested if
+/// a remote range is only partially writable.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_writev.2.html
+#[inline]
+pub fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    imp::syscalls::process_vm_writev(pid, local, remote)
+}
@@ -0,0 +1,673 @@
+//! Typed wrappers for the Linux `ptrace` syscall.
+//!
+//! # Safety
+//!
+//! `ptrace` takes untyped arguments, so each function here passes pointers
+//! and integers of the type its request expects.
+#![allow(unsafe_code)]
+
+use crate::imp::c::{c_uint, c_void};
+use crate::process::{Pid, Signal};
+use crate::{imp, io};
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::null_mut;
+
+const PTRACE_TRACEME: c_uint = 0;
+const PTRACE_PEEKDATA: c_uint = 2;
+const PTRACE_POKEDATA: c_uint = 5;
+const PTRACE_CONT: c_uint = 7;
+const PTRACE_SINGLESTEP: c_uint = 9;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm"))]
+const PTRACE_GETREGS: c_uint = 12;
+const PTRACE_ATTACH: c_uint = 16;
+const PTRACE_DETACH: c_uint = 17;
+const PTRACE_SYSCALL: c_uint = 24;
+const PTRACE_SETOPTIONS: c_uint = 0x4200;
+const PTRACE_GETEVENTMSG: c_uint = 0x4201;
+const PTRACE_GETREGSET: c_uint = 0x4204;
+const PTRACE_SEIZE: c_uint = 0x4206;
+const PTRACE_INTERRUPT: c_uint = 0x4207;
+const PTRACE_GET_SYSCALL_INFO: c_uint = 0x420e;
+
+const PTRACE_SYSCALL_INFO_NONE: u8 = 0;
+const PTRACE_SYSCALL_INFO_ENTRY: u8 = 1;
+const PTRACE_SYSCALL_INFO_EXIT: u8 = 2;
+const PTRACE_SYSCALL_INFO_SECCOMP: u8 = 3;
+
+bitflags::bitflags! {
+    /// `PTRACE_O_*` constants for use with [`ptrace_setoptions`] and
+    /// [`ptrace_seize`].
+    pub struct PtraceOptions: u32 {
+        /// `PTRACE_O_TRACESYSGOOD`—Set bit 7 of the signal number in
+        /// syscall stops.
+        const TRACESYSGOOD = 0x01;
+        /// `PTRACE_O_TRACEFORK`
+        const TRACEFORK = 0x02;
+        /// `PTRACE_O_TRACEVFORK`
+        const TRACEVFORK = 0x04;
+        /// `PTRACE_O_TRACECLONE`
+        const TRACECLONE = 0x08;
+        /// `PTRACE_O_TRACEEXEC`
+        const TRACEEXEC = 0x10;
+        /// `PTRACE_O_TRACEVFORKDONE`
+        const TRACEVFORKDONE = 0x20;
+        /// `PTRACE_O_TRACEEXIT`
+        const TRACEEXIT = 0x40;
+        /// `PTRACE_O_TRACESECCOMP` (since Linux 3.5)
+        const TRACESECCOMP = 0x80;
+        /// `PTRACE_O_EXITKILL` (since Linux 3.8)
+        const EXITKILL = 0x10_0000;
+        /// `PTRACE_O_SUSPEND_SECCOMP` (since Linux 4.3)
+        const SUSPEND_SECCOMP = 0x20_0000;
+    }
+}
+
+/// A `PTRACE_EVENT_*` value, reported by [`WaitStatus::ptrace_event`].
+///
+/// [`WaitStatus::ptrace_event`]: crate::process::WaitStatus::ptrace_event
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum PtraceEvent {
+    /// `PTRACE_EVENT_FORK`
+    Fork = 1,
+    /// `PTRACE_EVENT_VFORK`
+    Vfork = 2,
+    /// `PTRACE_EVENT_CLONE`
+    Clone = 3,
+    /// `PTRACE_EVENT_EXEC`
+    Exec = 4,
+    /// `PTRACE_EVENT_VFORK_DONE`
+    VforkDone = 5,
+    /// `PTRACE_EVENT_EXIT`
+    Exit = 6,
+    /// `PTRACE_EVENT_SECCOMP`
+    Seccomp = 7,
+    /// `PTRACE_EVENT_STOP`—A group-stop or [`ptrace_interrupt`] stop of a
+    /// tracee attached with [`ptrace_seize`].
+    Stop = 128,
+}
+
+impl PtraceEvent {
+    /// Convert a raw `PTRACE_EVENT_*` value into a `PtraceEvent`.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Fork),
+            2 => Some(Self::Vfork),
+            3 => Some(Self::Clone),
+            4 => Some(Self::Exec),
+            5 => Some(Self::VforkDone),
+            6 => Some(Self::Exit),
+            7 => Some(Self::Seccomp),
+            128 => Some(Self::Stop),
+            _ => None,
+        }
+    }
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee, as
+/// returned by [`ptrace_getregs`].
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee, as
+/// returned by [`ptrace_getregs`].
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+/// `struct user_regs`—The general-purpose registers of a tracee, as returned
+/// by [`ptrace_getregs`].
+///
+/// `uregs` holds `r0` through `r15`, then `cpsr` and `ORIG_r0`.
+#[cfg(target_arch = "arm")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct UserRegs {
+    /// The registers, in `ARM_*` order.
+    pub uregs: [u32; 18],
+}
+
+/// `struct user_pt_regs`—The general-purpose registers of a tracee, as
+/// returned by [`ptrace_getregs`].
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct UserRegs {
+    /// `x0` through `x30`.
+    pub regs: [u64; 31],
+    /// The stack pointer.
+    pub sp: u64,
+    /// The program counter.
+    pub pc: u64,
+    /// The processor state.
+    pub pstate: u64,
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee, as
+/// returned by [`ptrace_getregs`].
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub pc: u64,
+    pub ra: u64,
+    pub sp: u64,
+    pub gp: u64,
+    pub tp: u64,
+    pub t0: u64,
+    pub t1: u64,
+    pub t2: u64,
+    pub s0: u64,
+    pub s1: u64,
+    pub a0: u64,
+    pub a1: u64,
+    pub a2: u64,
+    pub a3: u64,
+    pub a4: u64,
+    pub a5: u64,
+    pub a6: u64,
+    pub a7: u64,
+    pub s2: u64,
+    pub s3: u64,
+    pub s4: u64,
+    pub s5: u64,
+    pub s6: u64,
+    pub s7: u64,
+    pub s8: u64,
+    pub s9: u64,
+    pub s10: u64,
+    pub s11: u64,
+    pub t3: u64,
+    pub t4: u64,
+    pub t5: u64,
+    pub t6: u64,
+}
+
+/// An `NT_*` register set, for use with [`ptrace_getregset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum RegisterSet {
+    /// `NT_PRSTATUS`—The general-purpose registers.
+    #[doc(alias = "NT_PRSTATUS")]
+    GeneralPurpose = 1,
+    /// `NT_PRFPREG`—The floating-point registers.
+    #[doc(alias = "NT_PRFPREG")]
+    FloatingPoint = 2,
+}
+
+/// `struct iovec`, as used by `PTRACE_GETREGSET`.
+#[repr(C)]
+struct Iovec {
+    base: *mut c_void,
+    len: usize,
+}
+
+/// The syscall-related information about a tracee's stop, returned by
+/// [`ptrace_get_syscall_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PtraceSyscallInfo {
+    /// The `AUDIT_ARCH_*` value of the syscall's ABI.
+    pub arch: u32,
+    /// The tracee's instruction pointer.
+    pub instruction_pointer: u64,
+    /// The tracee's stack pointer.
+    pub stack_pointer: u64,
+    /// The kind of stop, and the syscall details it provides.
+    pub op: PtraceSyscallOp,
+}
+
+/// The kind of a tracee's stop, as reported in [`PtraceSyscallInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PtraceSyscallOp {
+    /// `PTRACE_SYSCALL_INFO_NONE`—The stop is not a syscall stop.
+    None,
+    /// `PTRACE_SYSCALL_INFO_ENTRY`—A syscall-entry stop.
+    Entry {
+        /// The syscall number.
+        nr: u64,
+        /// The syscall arguments.
+        args: [u64; 6],
+    },
+    /// `PTRACE_SYSCALL_INFO_EXIT`—A syscall-exit stop.
+    Exit {
+        /// The syscall's return value, or the negated errno value.
+        rval: i64,
+        /// Whether `rval` is an error.
+        is_error: bool,
+    },
+    /// `PTRACE_SYSCALL_INFO_SECCOMP`—A `PTRACE_EVENT_SECCOMP` stop.
+    Seccomp {
+        /// The syscall number.
+        nr: u64,
+        /// The syscall arguments.
+        args: [u64; 6],
+        /// The data of the filter's `SECCOMP_RET_TRACE` action.
+        ret_data: u32,
+    },
+}
+
+/// `struct ptrace_syscall_info`
+#[repr(C)]
+struct RawPtraceSyscallInfo {
+    op: u8,
+    _pad: [u8; 3],
+    arch: u32,
+    instruction_pointer: u64,
+    stack_pointer: u64,
+    data: RawPtraceSyscallInfoData,
+}
+
+#[repr(C)]
+union RawPtraceSyscallInfoData {
+    entry: RawPtraceSyscallInfoEntry,
+    exit: RawPtraceSyscallInfoExit,
+    seccomp: RawPtraceSyscallInfoSeccomp,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RawPtraceSyscallInfoEntry {
+    nr: u64,
+    args: [u64; 6],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RawPtraceSyscallInfoExit {
+    rval: i64,
+    is_error: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RawPtraceSyscallInfoSeccomp {
+    nr: u64,
+    args: [u64; 6],
+    ret_data: u32,
+}
+
+#[inline]
+fn signal_data(signal: Option<Signal>) -> *mut c_void {
+    signal.map_or(0, Signal::as_raw) as usize as *mut _
+}
+
+/// `ptrace(PTRACE_TRACEME, 0, 0, 0)`—Make the calling thread a tracee of its
+/// parent.
+///
+/// This is typically called in a child process before `execve`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_TRACEME")]
+pub fn ptrace_traceme() -> io::Result<()> {
+    unsafe { imp::syscalls::ptrace(PTRACE_TRACEME, None, null_mut(), null_mut()).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_ATTACH, pid, 0, 0)`—Attach to the thread `pid`, stopping it
+/// with `SIGSTOP`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_ATTACH")]
+pub fn ptrace_attach(pid: Pid) -> io::Result<()> {
+    unsafe { imp::syscalls::ptrace(PTRACE_ATTACH, Some(pid), null_mut(), null_mut()).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_SEIZE, pid, 0, options)`—Attach to the thread `pid`
+/// without stopping it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SEIZE")]
+pub fn ptrace_seize(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    let data = options.bits() as usize as *mut _;
+    unsafe { imp::syscalls::ptrace(PTRACE_SEIZE, Some(pid), null_mut(), data).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_INTERRUPT, pid, 0, 0)`—Stop the tracee `pid`, which must
+/// have been attached with [`ptrace_seize`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_INTERRUPT")]
+pub fn ptrace_interrupt(pid: Pid) -> io::Result<()> {
+    unsafe {
+        imp::syscalls::ptrace(PTRACE_INTERRUPT, Some(pid), null_mut(), null_mut()).map(|_| ())
+    }
+}
+
+/// `ptrace(PTRACE_DETACH, pid, 0, signal)`—Detach from the stopped tracee
+/// `pid` and restart it, delivering `signal` if it is not `None`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_DETACH")]
+pub fn ptrace_detach(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    let data = signal_data(signal);
+    unsafe { imp::syscalls::ptrace(PTRACE_DETACH, Some(pid), null_mut(), data).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_CONT, pid, 0, signal)`—Restart the stopped tracee `pid`,
+/// delivering `signal` if it is not `None`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_CONT")]
+pub fn ptrace_cont(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    let data = signal_data(signal);
+    unsafe { imp::syscalls::ptrace(PTRACE_CONT, Some(pid), null_mut(), data).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_SYSCALL, pid, 0, signal)`—Restart the stopped tracee
+/// `pid`, stopping it again at the next syscall entry or exit.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SYSCALL")]
+pub fn ptrace_syscall(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    let data = signal_data(signal);
+    unsafe { imp::syscalls::ptrace(PTRACE_SYSCALL, Some(pid), null_mut(), data).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_SINGLESTEP, pid, 0, signal)`—Restart the stopped tracee
+/// `pid`, stopping it again after a single instruction.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SINGLESTEP")]
+pub fn ptrace_singlestep(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    let data = signal_data(signal);
+    unsafe { imp::syscalls::ptrace(PTRACE_SINGLESTEP, Some(pid), null_mut(), data).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_SETOPTIONS, pid, 0, options)`—Set the tracing options of
+/// the stopped tracee `pid`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SETOPTIONS")]
+pub fn ptrace_setoptions(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    let data = options.bits() as usize as *mut _;
+    unsafe { imp::syscalls::ptrace(PTRACE_SETOPTIONS, Some(pid), null_mut(), data).map(|_| ()) }
+}
+
+/// `ptrace(PTRACE_GETEVENTMSG, pid, 0, &msg)`—Get the message of the
+/// `PTRACE_EVENT_*` stop the tracee `pid` is in.
+///
+/// For fork, vfork, and clone events this is the new thread's id; for exit
+/// events it is the exit status, encoded as in [`WaitStatus`].
+///
+/// [`WaitStatus`]: crate::process::WaitStatus
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_GETEVENTMSG")]
+pub fn ptrace_geteventmsg(pid: Pid) -> io::Result<u64> {
+    let mut msg = MaybeUninit::<usize>::uninit();
+    unsafe {
+        imp::syscalls::ptrace(
+            PTRACE_GETEVENTMSG,
+            Some(pid),
+            null_mut(),
+            msg.as_mut_ptr().cast(),
+        )?;
+        Ok(msg.assume_init() as u64)
+    }
+}
+
+/// `ptrace(PTRACE_PEEKDATA, pid, addr, &word)`—Read a word at `addr` in the
+/// memory of the stopped tracee `pid`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_PEEKDATA")]
+pub fn ptrace_peekdata(pid: Pid, addr: usize) -> io::Result<usize> {
+    let mut word = MaybeUninit::<usize>::uninit();
+    unsafe {
+        imp::syscalls::ptrace(
+            PTRACE_PEEKDATA,
+            Some(pid),
+            addr as *mut _,
+            word.as_mut_ptr().cast(),
+        )?;
+        Ok(word.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_POKEDATA, pid, addr, word)`—Write a word at `addr` in the
+/// memory of the stopped tracee `pid`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_POKEDATA")]
+pub fn ptrace_pokedata(pid: Pid, addr: usize, word: usize) -> io::Result<()> {
+    unsafe {
+        imp::syscalls::ptrace(PTRACE_POKEDATA, Some(pid), addr as *mut _, word as *mut _)
+            .map(|_| ())
+    }
+}
+
+/// `ptrace(PTRACE_GETREGS, pid, 0, &regs)`—Get the general-purpose registers
+/// of the stopped tracee `pid`.
+///
+/// On architectures without `PTRACE_GETREGS`, this uses `PTRACE_GETREGSET`
+/// with `NT_PRSTATUS`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+))]
+#[inline]
+#[doc(alias = "PTRACE_GETREGS")]
+pub fn ptrace_getregs(pid: Pid) -> io::Result<UserRegs> {
+    let mut regs = MaybeUninit::<UserRegs>::uninit();
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm"))]
+    unsafe {
+        imp::syscalls::ptrace(
+            PTRACE_GETREGS,
+            Some(pid),
+            null_mut(),
+            regs.as_mut_ptr().cast(),
+        )?;
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm")))]
+    unsafe {
+        let mut iov = Iovec {
+            base: regs.as_mut_ptr().cast(),
+            len: size_of::<UserRegs>(),
+        };
+        imp::syscalls::ptrace(
+            PTRACE_GETREGSET,
+            Some(pid),
+            RegisterSet::GeneralPurpose as usize as *mut _,
+            (&mut iov as *mut Iovec).cast(),
+        )?;
+        if iov.len != size_of::<UserRegs>() {
+            return Err(io::Error::IO);
+        }
+    }
+
+    Ok(unsafe { regs.assume_init() })
+}
+
+/// `ptrace(PTRACE_GETREGSET, pid, set, &iov)`—Read the register set `set` of
+/// the stopped tracee `pid` into `buf`.
+///
+/// Returns the number of bytes written to `buf`. The layout of the data is
+/// architecture-specific.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_GETREGSET")]
+pub fn ptrace_getregset(pid: Pid, set: RegisterSet, buf: &mut [u8]) -> io::Result<usize> {
+    let mut iov = Iovec {
+        base: buf.as_mut_ptr().cast(),
+        len: buf.len(),
+    };
+    unsafe {
+        imp::syscalls::ptrace(
+            PTRACE_GETREGSET,
+            Some(pid),
+            set as usize as *mut _,
+            (&mut iov as *mut Iovec).cast(),
+        )?;
+    }
+    Ok(iov.len)
+}
+
+/// `ptrace(PTRACE_GET_SYSCALL_INFO, pid, size, &info)`—Get information about
+/// the syscall that caused the stopped tracee `pid` to stop (since Linux
+/// 5.3).
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_GET_SYSCALL_INFO")]
+pub fn ptrace_get_syscall_info(pid: Pid) -> io::Result<PtraceSyscallInfo> {
+    let mut raw = MaybeUninit::<RawPtraceSyscallInfo>::zeroed();
+    let raw = unsafe {
+        imp::syscalls::ptrace(
+            PTRACE_GET_SYSCALL_INFO,
+            Some(pid),
+            size_of::<RawPtraceSyscallInfo>() as *mut _,
+            raw.as_mut_ptr().cast(),
+        )?;
+        raw.assume_init()
+    };
+
+    // Safety: The kernel fills in the union member indicated by `op`.
+    let op = unsafe {
+        match raw.op {
+            PTRACE_SYSCALL_INFO_NONE => PtraceSyscallOp::None,
+            PTRACE_SYSCALL_INFO_ENTRY => PtraceSyscallOp::Entry {
+                nr: raw.data.entry.nr,
+                args: raw.data.entry.args,
+            },
+            PTRACE_SYSCALL_INFO_EXIT => PtraceSyscallOp::Exit {
+                rval: raw.data.exit.rval,
+                is_error: raw.data.exit.is_error != 0,
+            },
+            PTRACE_SYSCALL_INFO_SECCOMP => PtraceSyscallOp::Seccomp {
+                nr: raw.data.seccomp.nr,
+                args: raw.data.seccomp.args,
+                ret_data: raw.data.seccomp.ret_data,
+            },
+            _ => return Err(io::Error::IO),
+        }
+    };
+    Ok(PtraceSyscallInfo {
+        arch: raw.arch,
+        instruction_pointer: raw.instruction_pointer,
+        stack_pointer: raw.stack_pointer,
+        op,
+    })
+}
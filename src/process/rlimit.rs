@@ -1,9 +1,14 @@
-use crate::imp;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::process::Pid;
+use crate::{imp, io};
 
 pub use crate::imp::process::Resource;
 
-/// `struct rlimit`—Current and maximum values used in [`getrlimit`].
-#[derive(Debug)]
+/// `struct rlimit`—Current and maximum values used in [`getrlimit`],
+/// [`setrlimit`], and [`prlimit`].
+///
+/// A value of `None` corresponds to `RLIM_INFINITY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Rlimit {
     /// Current effective, "soft", limit.
     pub current: Option<u64>,
@@ -23,3 +28,32 @@ pub struct Rlimit {
 pub fn getrlimit(resource: Resource) -> Rlimit {
     imp::syscalls::getrlimit(resource)
 }
+
+/// `setrlimit(resource, new)`—Set a process resource limit value.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setrlimit.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setrlimit.2.html
+#[inline]
+pub fn setrlimit(resource: Resource, new: Rlimit) -> io::Result<()> {
+    imp::syscalls::setrlimit(resource, new)
+}
+
+/// `prlimit(pid, resource, new)`—Get and set a process resource limit value.
+///
+/// If `new` is `Some`, the limit is set to it. In either case, the previous
+/// value is returned. If `pid` is `None`, this operates on the current
+/// process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prlimit.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn prlimit(pid: Option<Pid>, resource: Resource, new: Option<Rlimit>) -> io::Result<Rlimit> {
+    imp::syscalls::prlimit(pid, resource, new)
+}
@@ -0,0 +1,609 @@
+//! The Linux `seccomp` syscall, a builder for classic BPF filters, and the
+//! user-notification ioctls.
+//!
+//! # Safety
+//!
+//! `seccomp` takes an untyped argument pointer, and a successful
+//! `SECCOMP_FILTER_FLAG_NEW_LISTENER` call returns a new file descriptor.
+#![allow(unsafe_code)]
+
+use crate::imp;
+use crate::imp::c::{c_uint, c_void};
+use crate::imp::fd::{AsFd, AsRawFd, FromRawFd, RawFd};
+use crate::io::{self, DupFlags, OwnedFd};
+use crate::process::Pid;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use imp::process::SeccompNotifAddfd;
+
+const SECCOMP_SET_MODE_FILTER: c_uint = 1;
+const SECCOMP_GET_ACTION_AVAIL: c_uint = 2;
+
+/// The mask of a `SECCOMP_RET_*` value which excludes its data.
+const SECCOMP_RET_ACTION_FULL: u32 = 0xffff_0000;
+
+const BPF_LD: u16 = 0x00;
+const BPF_JMP: u16 = 0x05;
+const BPF_RET: u16 = 0x06;
+const BPF_W: u16 = 0x00;
+const BPF_ABS: u16 = 0x20;
+const BPF_JEQ: u16 = 0x10;
+const BPF_JGE: u16 = 0x30;
+const BPF_K: u16 = 0x00;
+
+/// The offsets of the `nr` and `arch` fields of `struct seccomp_data`.
+const SECCOMP_DATA_NR: u32 = 0;
+const SECCOMP_DATA_ARCH: u32 = 4;
+
+/// The `AUDIT_ARCH_*` value that the kernel reports for the current
+/// architecture.
+#[cfg(target_arch = "x86")]
+const AUDIT_ARCH: u32 = 0x4000_0003;
+#[cfg(target_arch = "x86_64")]
+const AUDIT_ARCH: u32 = 0xc000_003e;
+#[cfg(target_arch = "arm")]
+const AUDIT_ARCH: u32 = 0x4000_0028;
+#[cfg(target_arch = "aarch64")]
+const AUDIT_ARCH: u32 = 0xc000_00b7;
+#[cfg(target_arch = "riscv64")]
+const AUDIT_ARCH: u32 = 0xc000_00f3;
+
+/// `__X32_SYSCALL_BIT`—x32 syscalls share `AUDIT_ARCH_X86_64`, and are
+/// distinguished by this bit in the syscall number.
+const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+pub use imp::process::{SeccompData, SeccompNotif, SeccompNotifResp};
+
+bitflags::bitflags! {
+    /// `SECCOMP_FILTER_FLAG_*` constants for use with
+    /// [`seccomp_set_mode_filter`].
+    pub struct SeccompFilterFlags: u32 {
+        /// `SECCOMP_FILTER_FLAG_TSYNC`
+        const TSYNC = 1 << 0;
+        /// `SECCOMP_FILTER_FLAG_LOG` (since Linux 4.14)
+        const LOG = 1 << 1;
+        /// `SECCOMP_FILTER_FLAG_SPEC_ALLOW` (since Linux 4.17)
+        const SPEC_ALLOW = 1 << 2;
+        /// `SECCOMP_FILTER_FLAG_NEW_LISTENER` (since Linux 5.0)
+        const NEW_LISTENER = 1 << 3;
+    }
+}
+
+/// A `SECCOMP_RET_*` action for a filter to return.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeccompAction {
+    /// `SECCOMP_RET_KILL_PROCESS` (since Linux 4.14)
+    KillProcess,
+    /// `SECCOMP_RET_KILL_THREAD`
+    #[doc(alias = "SECCOMP_RET_KILL")]
+    KillThread,
+    /// `SECCOMP_RET_TRAP`
+    Trap,
+    /// `SECCOMP_RET_ERRNO | errno`
+    Errno(u16),
+    /// `SECCOMP_RET_USER_NOTIF` (since Linux 5.0)
+    UserNotif,
+    /// `SECCOMP_RET_TRACE | data`
+    Trace(u16),
+    /// `SECCOMP_RET_LOG` (since Linux 4.14)
+    Log,
+    /// `SECCOMP_RET_ALLOW`
+    Allow,
+}
+
+impl SeccompAction {
+    /// Return the raw `SECCOMP_RET_*` value, including any data.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        match self {
+            Self::KillProcess => 0x8000_0000,
+            Self::KillThread => 0x0000_0000,
+            Self::Trap => 0x0003_0000,
+            Self::Errno(errno) => 0x0005_0000 | errno as u32,
+            Self::UserNotif => 0x7fc0_0000,
+            Self::Trace(data) => 0x7ff0_0000 | data as u32,
+            Self::Log => 0x7ffc_0000,
+            Self::Allow => 0x7fff_0000,
+        }
+    }
+}
+
+/// `struct sock_filter`—A classic BPF instruction.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SockFilter {
+    /// The opcode.
+    pub code: u16,
+    /// The offset to jump to if a jump's condition is true.
+    pub jt: u8,
+    /// The offset to jump to if a jump's condition is false.
+    pub jf: u8,
+    /// The generic operand.
+    pub k: u32,
+}
+
+impl SockFilter {
+    /// `BPF_STMT(code, k)`—A non-jump instruction.
+    #[inline]
+    pub const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    /// `BPF_JUMP(code, k, jt, jf)`—A jump instruction.
+    #[inline]
+    pub const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+}
+
+/// `struct sock_fprog`
+#[repr(C)]
+struct SockFprog {
+    len: u16,
+    filter: *const SockFilter,
+}
+
+/// A syscall number for the current architecture, for use with
+/// [`SeccompFilter::syscall`].
+///
+/// This has associated constants for commonly filtered syscalls, using the
+/// kernel's `__NR_*` numbers. Other syscalls can be named with
+/// [`Syscall::from_raw`]. On x86, the socket syscalls aren't named here, as
+/// older kernels and libcs use `socketcall` for them instead.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Syscall(u32);
+
+impl Syscall {
+    /// `__NR_bpf`
+    pub const BPF: Self = Self(imp::process::__NR_bpf as _);
+    /// `__NR_brk`
+    pub const BRK: Self = Self(imp::process::__NR_brk as _);
+    /// `__NR_chdir`
+    pub const CHDIR: Self = Self(imp::process::__NR_chdir as _);
+    /// `__NR_chroot`
+    pub const CHROOT: Self = Self(imp::process::__NR_chroot as _);
+    /// `__NR_clone`
+    pub const CLONE: Self = Self(imp::process::__NR_clone as _);
+    /// `__NR_close`
+    pub const CLOSE: Self = Self(imp::process::__NR_close as _);
+    /// `__NR_dup`
+    pub const DUP: Self = Self(imp::process::__NR_dup as _);
+    /// `__NR_dup3`
+    pub const DUP3: Self = Self(imp::process::__NR_dup3 as _);
+    /// `__NR_execve`
+    pub const EXECVE: Self = Self(imp::process::__NR_execve as _);
+    /// `__NR_execveat`
+    pub const EXECVEAT: Self = Self(imp::process::__NR_execveat as _);
+    /// `__NR_exit`
+    pub const EXIT: Self = Self(imp::process::__NR_exit as _);
+    /// `__NR_exit_group`
+    pub const EXIT_GROUP: Self = Self(imp::process::__NR_exit_group as _);
+    /// `__NR_fchdir`
+    pub const FCHDIR: Self = Self(imp::process::__NR_fchdir as _);
+    /// `__NR_fchmod`
+    pub const FCHMOD: Self = Self(imp::process::__NR_fchmod as _);
+    /// `__NR_fchmodat`
+    pub const FCHMODAT: Self = Self(imp::process::__NR_fchmodat as _);
+    /// `__NR_fchownat`
+    pub const FCHOWNAT: Self = Self(imp::process::__NR_fchownat as _);
+    /// `__NR_getpgid`
+    pub const GETPGID: Self = Self(imp::process::__NR_getpgid as _);
+    /// `__NR_getpid`
+    pub const GETPID: Self = Self(imp::process::__NR_getpid as _);
+    /// `__NR_getppid`
+    pub const GETPPID: Self = Self(imp::process::__NR_getppid as _);
+    /// `__NR_getrandom`
+    pub const GETRANDOM: Self = Self(imp::process::__NR_getrandom as _);
+    /// `__NR_gettid`
+    pub const GETTID: Self = Self(imp::process::__NR_gettid as _);
+    /// `__NR_ioctl`
+    pub const IOCTL: Self = Self(imp::process::__NR_ioctl as _);
+    /// `__NR_kcmp`
+    pub const KCMP: Self = Self(imp::process::__NR_kcmp as _);
+    /// `__NR_keyctl`
+    pub const KEYCTL: Self = Self(imp::process::__NR_keyctl as _);
+    /// `__NR_kill`
+    pub const KILL: Self = Self(imp::process::__NR_kill as _);
+    /// `__NR_madvise`
+    pub const MADVISE: Self = Self(imp::process::__NR_madvise as _);
+    /// `__NR_memfd_create`
+    pub const MEMFD_CREATE: Self = Self(imp::process::__NR_memfd_create as _);
+    /// `__NR_mkdirat`
+    pub const MKDIRAT: Self = Self(imp::process::__NR_mkdirat as _);
+    /// `__NR_mount`
+    pub const MOUNT: Self = Self(imp::process::__NR_mount as _);
+    /// `__NR_mprotect`
+    pub const MPROTECT: Self = Self(imp::process::__NR_mprotect as _);
+    /// `__NR_mremap`
+    pub const MREMAP: Self = Self(imp::process::__NR_mremap as _);
+    /// `__NR_munmap`
+    pub const MUNMAP: Self = Self(imp::process::__NR_munmap as _);
+    /// `__NR_openat`
+    pub const OPENAT: Self = Self(imp::process::__NR_openat as _);
+    /// `__NR_perf_event_open`
+    pub const PERF_EVENT_OPEN: Self = Self(imp::process::__NR_perf_event_open as _);
+    /// `__NR_personality`
+    pub const PERSONALITY: Self = Self(imp::process::__NR_personality as _);
+    /// `__NR_pipe2`
+    pub const PIPE2: Self = Self(imp::process::__NR_pipe2 as _);
+    /// `__NR_pivot_root`
+    pub const PIVOT_ROOT: Self = Self(imp::process::__NR_pivot_root as _);
+    /// `__NR_prctl`
+    pub const PRCTL: Self = Self(imp::process::__NR_prctl as _);
+    /// `__NR_process_vm_readv`
+    pub const PROCESS_VM_READV: Self = Self(imp::process::__NR_process_vm_readv as _);
+    /// `__NR_process_vm_writev`
+    pub const PROCESS_VM_WRITEV: Self = Self(imp::process::__NR_process_vm_writev as _);
+    /// `__NR_ptrace`
+    pub const PTRACE: Self = Self(imp::process::__NR_ptrace as _);
+    /// `__NR_read`
+    pub const READ: Self = Self(imp::process::__NR_read as _);
+    /// `__NR_readv`
+    pub const READV: Self = Self(imp::process::__NR_readv as _);
+    /// `__NR_reboot`
+    pub const REBOOT: Self = Self(imp::process::__NR_reboot as _);
+    /// `__NR_seccomp`
+    pub const SECCOMP: Self = Self(imp::process::__NR_seccomp as _);
+    /// `__NR_setns`
+    pub const SETNS: Self = Self(imp::process::__NR_setns as _);
+    /// `__NR_tgkill`
+    pub const TGKILL: Self = Self(imp::process::__NR_tgkill as _);
+    /// `__NR_unlinkat`
+    pub const UNLINKAT: Self = Self(imp::process::__NR_unlinkat as _);
+    /// `__NR_unshare`
+    pub const UNSHARE: Self = Self(imp::process::__NR_unshare as _);
+    /// `__NR_userfaultfd`
+    pub const USERFAULTFD: Self = Self(imp::process::__NR_userfaultfd as _);
+    /// `__NR_write`
+    pub const WRITE: Self = Self(imp::process::__NR_write as _);
+    /// `__NR_writev`
+    pub const WRITEV: Self = Self(imp::process::__NR_writev as _);
+    /// `__NR_accept4`
+    #[cfg(not(target_arch = "x86"))]
+    pub const ACCEPT4: Self = Self(imp::process::__NR_accept4 as _);
+    /// `__NR_bind`
+    #[cfg(not(target_arch = "x86"))]
+    pub const BIND: Self = Self(imp::process::__NR_bind as _);
+    /// `__NR_connect`
+    #[cfg(not(target_arch = "x86"))]
+    pub const CONNECT: Self = Self(imp::process::__NR_connect as _);
+    /// `__NR_listen`
+    #[cfg(not(target_arch = "x86"))]
+    pub const LISTEN: Self = Self(imp::process::__NR_listen as _);
+    /// `__NR_recvfrom`
+    #[cfg(not(target_arch = "x86"))]
+    pub const RECVFROM: Self = Self(imp::process::__NR_recvfrom as _);
+    /// `__NR_recvmsg`
+    #[cfg(not(target_arch = "x86"))]
+    pub const RECVMSG: Self = Self(imp::process::__NR_recvmsg as _);
+    /// `__NR_sendmsg`
+    #[cfg(not(target_arch = "x86"))]
+    pub const SENDMSG: Self = Self(imp::process::__NR_sendmsg as _);
+    /// `__NR_sendto`
+    #[cfg(not(target_arch = "x86"))]
+    pub const SENDTO: Self = Self(imp::process::__NR_sendto as _);
+    /// `__NR_shutdown`
+    #[cfg(not(target_arch = "x86"))]
+    pub const SHUTDOWN: Self = Self(imp::process::__NR_shutdown as _);
+    /// `__NR_socket`
+    #[cfg(not(target_arch = "x86"))]
+    pub const SOCKET: Self = Self(imp::process::__NR_socket as _);
+    /// `__NR_socketpair`
+    #[cfg(not(target_arch = "x86"))]
+    pub const SOCKETPAIR: Self = Self(imp::process::__NR_socketpair as _);
+
+    /// Construct a `Syscall` from a raw syscall number for the current
+    /// architecture.
+    #[inline]
+    pub const fn from_raw(nr: u32) -> Self {
+        Self(nr)
+    }
+
+    /// Return the raw syscall number.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// A builder for seccomp filter programs which return an action depending on
+/// the syscall number.
+///
+/// The program starts by checking that the syscall uses the current
+/// architecture's ABI, and kills the process otherwise.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rustix::io;
+/// use rustix::process::{SeccompAction, SeccompFilter, Syscall};
+///
+/// let eperm = io::Error::PERM.raw_os_error() as u16;
+/// let program = SeccompFilter::new()
+///     .syscall(Syscall::GETPID, SeccompAction::Errno(eperm))
+///     .build(SeccompAction::Allow);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeccompFilter {
+    instructions: Vec<SockFilter>,
+}
+
+impl SeccompFilter {
+    /// Start a new filter program.
+    pub fn new() -> Self {
+        let mut instructions = alloc::vec![
+            SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, SECCOMP_DATA_ARCH),
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, AUDIT_ARCH, 1, 0),
+            SockFilter::stmt(BPF_RET | BPF_K, SeccompAction::KillProcess.as_raw()),
+            SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, SECCOMP_DATA_NR),
+        ];
+        if cfg!(target_arch = "x86_64") {
+            instructions.extend_from_slice(&[
+                SockFilter::jump(BPF_JMP | BPF_JGE | BPF_K, X32_SYSCALL_BIT, 0, 1),
+                SockFilter::stmt(BPF_RET | BPF_K, SeccompAction::KillProcess.as_raw()),
+            ]);
+        }
+        Self { instructions }
+    }
+
+    /// Return `action` for the syscall `nr`.
+    ///
+    /// Rules are checked in the order they're added.
+    pub fn syscall(mut self, nr: Syscall, action: SeccompAction) -> Self {
+        self.instructions.extend_from_slice(&[
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, nr.as_raw(), 0, 1),
+            SockFilter::stmt(BPF_RET | BPF_K, action.as_raw()),
+        ]);
+        self
+    }
+
+    /// Append a raw instruction.
+    ///
+    /// When it runs, the accumulator holds the syscall number.
+    pub fn instruction(mut self, instruction: SockFilter) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Finish the program, returning `default_action` for any syscall not
+    /// otherwise handled.
+    pub fn build(mut self, default_action: SeccompAction) -> Vec<SockFilter> {
+        self.instructions
+            .push(SockFilter::stmt(BPF_RET | BPF_K, default_action.as_raw()));
+        self.instructions
+    }
+}
+
+impl Default for SeccompFilter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, program)`—Install a seccomp
+/// filter for the calling thread.
+///
+/// The calling thread must have the "no new privs" attribute set, with
+/// [`set_no_new_privs`], or have `CAP_SYS_ADMIN`.
+///
+/// If `flags` contains [`SeccompFilterFlags::NEW_LISTENER`], this returns the
+/// notification listener file descriptor. If `flags` contains
+/// [`SeccompFilterFlags::TSYNC`] and another thread can't be synchronized,
+/// this fails with [`io::Error::SRCH`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+/// [`set_no_new_privs`]: crate::process::set_no_new_privs
+#[doc(alias = "SECCOMP_SET_MODE_FILTER")]
+pub fn seccomp_set_mode_filter(
+    flags: SeccompFilterFlags,
+    program: &[SockFilter],
+) -> io::Result<Option<OwnedFd>> {
+    let prog = SockFprog {
+        len: program.len().try_into().map_err(|_| io::Error::INVAL)?,
+        filter: program.as_ptr(),
+    };
+    unsafe {
+        let r = imp::syscalls::seccomp(
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            &prog as *const SockFprog as *mut c_void,
+        )?;
+        if flags.contains(SeccompFilterFlags::NEW_LISTENER) {
+            Ok(Some(OwnedFd::from(imp::fd::OwnedFd::from_raw_fd(r))))
+        } else if r != 0 {
+            // With `TSYNC`, a positive value is the id of a thread which
+            // couldn't be synchronized.
+            Err(io::Error::SRCH)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// `seccomp(SECCOMP_GET_ACTION_AVAIL, 0, action)`—Test whether the kernel
+/// supports a filter return action.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "SECCOMP_GET_ACTION_AVAIL")]
+pub fn seccomp_get_action_avail(action: SeccompAction) -> io::Result<bool> {
+    let mut raw = action.as_raw() & SECCOMP_RET_ACTION_FULL;
+    match unsafe {
+        imp::syscalls::seccomp(SECCOMP_GET_ACTION_AVAIL, 0, (&mut raw as *mut u32).cast())
+    } {
+        Ok(_) => Ok(true),
+        Err(io::Error::OPNOTSUPP) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+impl SeccompNotif {
+    /// Return the id of the thread which made the syscall, if it's visible in
+    /// the listener's PID namespace.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        // Safety: The kernel reports a valid thread id or 0.
+        unsafe { Pid::from_raw(self.pid as _) }
+    }
+}
+
+/// `SECCOMP_USER_NOTIF_FLAG_CONTINUE`
+const SECCOMP_USER_NOTIF_FLAG_CONTINUE: u32 = 1;
+
+impl SeccompNotifResp {
+    /// Respond to notification `id` by having the syscall return `val`.
+    #[inline]
+    pub const fn new_value(id: u64, val: i64) -> Self {
+        Self {
+            id,
+            val,
+            error: 0,
+            flags: 0,
+        }
+    }
+
+    /// Respond to notification `id` by having the syscall fail with `err`.
+    #[inline]
+    pub const fn new_error(id: u64, err: io::Error) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: -err.raw_os_error(),
+            flags: 0,
+        }
+    }
+
+    /// Respond to notification `id` by letting the kernel run the syscall.
+    ///
+    /// This is subject to time-of-check-to-time-of-use races, so it must not
+    /// be used to implement a security policy.
+    #[inline]
+    #[doc(alias = "SECCOMP_USER_NOTIF_FLAG_CONTINUE")]
+    pub const fn new_continue(id: u64) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: 0,
+            flags: SECCOMP_USER_NOTIF_FLAG_CONTINUE,
+        }
+    }
+}
+
+/// `SECCOMP_ADDFD_FLAG_SETFD`
+const SECCOMP_ADDFD_FLAG_SETFD: u32 = 1 << 0;
+
+bitflags::bitflags! {
+    /// `SECCOMP_ADDFD_FLAG_*` constants for use with [`seccomp_notif_addfd`].
+    pub struct SeccompNotifAddfdFlags: u32 {
+        /// `SECCOMP_ADDFD_FLAG_SEND` (since Linux 5.14)
+        const SEND = 1 << 1;
+    }
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_RECV)`—Wait for and receive a notification
+/// on a listener returned by [`seccomp_set_mode_filter`].
+///
+/// The listener is readable when a notification is pending, so it can be
+/// used with `epoll`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_RECV")]
+pub fn seccomp_notif_recv<Fd: AsFd>(listener: &Fd) -> io::Result<SeccompNotif> {
+    let listener = listener.as_fd();
+    // The kernel requires the struct to be zeroed.
+    let mut notif = SeccompNotif::default();
+    imp::syscalls::seccomp_notif_recv(listener, &mut notif)?;
+    Ok(notif)
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_SEND, resp)`—Respond to a notification.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_SEND")]
+pub fn seccomp_notif_send<Fd: AsFd>(listener: &Fd, resp: &SeccompNotifResp) -> io::Result<()> {
+    let listener = listener.as_fd();
+    imp::syscalls::seccomp_notif_send(listener, resp)
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)`—Test whether a notification
+/// is still valid.
+///
+/// This returns `false` if the target thread has been killed, or its syscall
+/// interrupted by a signal handler.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ID_VALID")]
+pub fn seccomp_notif_id_valid<Fd: AsFd>(listener: &Fd, id: u64) -> io::Result<bool> {
+    let listener = listener.as_fd();
+    match imp::syscalls::seccomp_notif_id_valid(listener, id) {
+        Ok(()) => Ok(true),
+        Err(io::Error::NOENT) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_ADDFD, addfd)`—Install a copy of `src`
+/// in the file descriptor table of the thread which made the syscall
+/// with notification `id` (since Linux 5.9).
+///
+/// If `newfd` is `Some`, the file descriptor is installed at that number,
+/// replacing any file descriptor already there. This returns the number the
+/// file descriptor was installed at, in the target's table.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ADDFD")]
+pub fn seccomp_notif_addfd<Fd: AsFd, SrcFd: AsFd>(
+    listener: &Fd,
+    id: u64,
+    src: &SrcFd,
+    newfd: Option<RawFd>,
+    flags: SeccompNotifAddfdFlags,
+    newfd_flags: DupFlags,
+) -> io::Result<RawFd> {
+    let listener = listener.as_fd();
+    let mut raw_flags = flags.bits();
+    if newfd.is_some() {
+        raw_flags |= SECCOMP_ADDFD_FLAG_SETFD;
+    }
+    let addfd = SeccompNotifAddfd {
+        id,
+        flags: raw_flags,
+        srcfd: src.as_fd().as_raw_fd() as u32,
+        newfd: newfd.unwrap_or(0) as u32,
+        newfd_flags: newfd_flags.bits() as _,
+    };
+    imp::syscalls::seccomp_notif_addfd(listener, &addfd)
+}
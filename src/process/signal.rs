@@ -0,0 +1,376 @@
+//! Signal numbers, signal sets, and sending and masking signals.
+//!
+//! # Safety
+//!
+//! `Signal::from_raw_unchecked` constructs a `Signal` without checking that
+//! its value is in range.
+#![allow(unsafe_code)]
+
+use crate::imp;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::io;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::process::Pid;
+use core::fmt;
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use imp::process::SigmaskHow;
+
+/// A signal number, for use with [`kill_process`] and related functions.
+///
+/// This covers the standard signals, as associated constants, and on Linux,
+/// real-time signals, via `Signal::rt`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/signal.7.html
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Signal(i32);
+
+impl Signal {
+    /// `SIGHUP`
+    pub const HUP: Self = Self(imp::process::SIGHUP as _);
+    /// `SIGINT`
+    pub const INT: Self = Self(imp::process::SIGINT as _);
+    /// `SIGQUIT`
+    pub const QUIT: Self = Self(imp::process::SIGQUIT as _);
+    /// `SIGILL`
+    pub const ILL: Self = Self(imp::process::SIGILL as _);
+    /// `SIGTRAP`
+    pub const TRAP: Self = Self(imp::process::SIGTRAP as _);
+    /// `SIGABRT`, aka `SIGIOT`
+    #[doc(alias = "IOT")]
+    pub const ABORT: Self = Self(imp::process::SIGABRT as _);
+    /// `SIGBUS`
+    pub const BUS: Self = Self(imp::process::SIGBUS as _);
+    /// `SIGFPE`
+    pub const FPE: Self = Self(imp::process::SIGFPE as _);
+    /// `SIGKILL`
+    pub const KILL: Self = Self(imp::process::SIGKILL as _);
+    /// `SIGUSR1`
+    pub const USR1: Self = Self(imp::process::SIGUSR1 as _);
+    /// `SIGSEGV`
+    pub const SEGV: Self = Self(imp::process::SIGSEGV as _);
+    /// `SIGUSR2`
+    pub const USR2: Self = Self(imp::process::SIGUSR2 as _);
+    /// `SIGPIPE`
+    pub const PIPE: Self = Self(imp::process::SIGPIPE as _);
+    /// `SIGALRM`
+    #[doc(alias = "ALRM")]
+    pub const ALARM: Self = Self(imp::process::SIGALRM as _);
+    /// `SIGTERM`
+    pub const TERM: Self = Self(imp::process::SIGTERM as _);
+    /// `SIGSTKFLT`
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    pub const STKFLT: Self = Self(imp::process::SIGSTKFLT as _);
+    /// `SIGCHLD`
+    #[doc(alias = "CHLD")]
+    pub const CHILD: Self = Self(imp::process::SIGCHLD as _);
+    /// `SIGCONT`
+    pub const CONT: Self = Self(imp::process::SIGCONT as _);
+    /// `SIGSTOP`
+    pub const STOP: Self = Self(imp::process::SIGSTOP as _);
+    /// `SIGTSTP`
+    pub const TSTP: Self = Self(imp::process::SIGTSTP as _);
+    /// `SIGTTIN`
+    pub const TTIN: Self = Self(imp::process::SIGTTIN as _);
+    /// `SIGTTOU`
+    pub const TTOU: Self = Self(imp::process::SIGTTOU as _);
+    /// `SIGURG`
+    pub const URG: Self = Self(imp::process::SIGURG as _);
+    /// `SIGXCPU`
+    pub const XCPU: Self = Self(imp::process::SIGXCPU as _);
+    /// `SIGXFSZ`
+    pub const XFSZ: Self = Self(imp::process::SIGXFSZ as _);
+    /// `SIGVTALRM`
+    #[doc(alias = "VTALRM")]
+    pub const VTALARM: Self = Self(imp::process::SIGVTALRM as _);
+    /// `SIGPROF`
+    pub const PROF: Self = Self(imp::process::SIGPROF as _);
+    /// `SIGWINCH`
+    pub const WINCH: Self = Self(imp::process::SIGWINCH as _);
+    /// `SIGIO`, aka `SIGPOLL`
+    #[doc(alias = "POLL")]
+    pub const IO: Self = Self(imp::process::SIGIO as _);
+    /// `SIGPWR`
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[doc(alias = "PWR")]
+    pub const POWER: Self = Self(imp::process::SIGPWR as _);
+    /// `SIGSYS`, aka `SIGUNUSED`
+    #[doc(alias = "UNUSED")]
+    pub const SYS: Self = Self(imp::process::SIGSYS as _);
+
+    /// `SIGRTMIN`—The lowest-numbered real-time signal.
+    ///
+    /// With the libc backend, this is the libc's `SIGRTMIN`, which excludes
+    /// signals the libc reserves for internal use. With the `linux_raw`
+    /// backend, this is the kernel's `SIGRTMIN`; when linking with a libc,
+    /// be aware that it may reserve the lowest few real-time signals.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[doc(alias = "SIGRTMIN")]
+    #[inline]
+    pub fn rt_min() -> Self {
+        Self(imp::process::sigrtmin())
+    }
+
+    /// `SIGRTMAX`—The highest-numbered real-time signal.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[doc(alias = "SIGRTMAX")]
+    #[inline]
+    pub fn rt_max() -> Self {
+        Self(imp::process::sigrtmax())
+    }
+
+    /// `SIGRTMIN + n`—Returns the `n`th real-time signal, or `None` if `n`
+    /// is past `SIGRTMAX`.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[inline]
+    pub fn rt(n: u32) -> Option<Self> {
+        let min = Self::rt_min().0;
+        let max = Self::rt_max().0;
+        if n <= (max - min) as u32 {
+            Some(Self(min + n as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Converts a raw signal number into a `Signal`, if it is in the range of
+    /// valid signal numbers.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[inline]
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        if raw >= 1 && raw <= imp::process::sigrtmax() {
+            Some(Self(raw))
+        } else {
+            None
+        }
+    }
+
+    /// Converts a raw signal number into a `Signal` without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid signal number.
+    #[inline]
+    pub const unsafe fn from_raw_unchecked(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `Signal` into its raw signal number.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+
+    /// Returns whether this is a real-time signal.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[inline]
+    pub fn is_rt(self) -> bool {
+        self.0 >= Self::rt_min().0 && self.0 <= Self::rt_max().0
+    }
+}
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match *self {
+            Self::HUP => "SIGHUP",
+            Self::INT => "SIGINT",
+            Self::QUIT => "SIGQUIT",
+            Self::ILL => "SIGILL",
+            Self::TRAP => "SIGTRAP",
+            Self::ABORT => "SIGABRT",
+            Self::BUS => "SIGBUS",
+            Self::FPE => "SIGFPE",
+            Self::KILL => "SIGKILL",
+            Self::USR1 => "SIGUSR1",
+            Self::SEGV => "SIGSEGV",
+            Self::USR2 => "SIGUSR2",
+            Self::PIPE => "SIGPIPE",
+            Self::ALARM => "SIGALRM",
+            Self::TERM => "SIGTERM",
+            #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+            Self::STKFLT => "SIGSTKFLT",
+            Self::CHILD => "SIGCHLD",
+            Self::CONT => "SIGCONT",
+            Self::STOP => "SIGSTOP",
+            Self::TSTP => "SIGTSTP",
+            Self::TTIN => "SIGTTIN",
+            Self::TTOU => "SIGTTOU",
+            Self::URG => "SIGURG",
+            Self::XCPU => "SIGXCPU",
+            Self::XFSZ => "SIGXFSZ",
+            Self::VTALARM => "SIGVTALRM",
+            Self::PROF => "SIGPROF",
+            Self::WINCH => "SIGWINCH",
+            Self::IO => "SIGIO",
+            #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+            Self::POWER => "SIGPWR",
+            Self::SYS => "SIGSYS",
+            #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+            _ if self.is_rt() => return write!(f, "SIGRTMIN+{}", self.0 - Self::rt_min().0),
+            _ => return write!(f, "Signal({})", self.0),
+        };
+        f.write_str(name)
+    }
+}
+
+/// A set of signals, for use with [`sigprocmask`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/sigsetops.3.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct SigSet {
+    pub(crate) sig_set: imp::process::RawSigSet,
+}
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+impl SigSet {
+    /// Create a new and empty `SigSet`.
+    #[inline]
+    pub fn new() -> Self {
+        let mut sig_set = imp::process::raw_sig_set_new();
+        imp::process::sig_set::sigemptyset(&mut sig_set);
+        Self { sig_set }
+    }
+
+    /// Create a new `SigSet` containing all signals.
+    #[inline]
+    pub fn full() -> Self {
+        let mut sig_set = imp::process::raw_sig_set_new();
+        imp::process::sig_set::sigfillset(&mut sig_set);
+        Self { sig_set }
+    }
+
+    /// Test to see if a signal is in the `SigSet`.
+    #[inline]
+    pub fn is_set(&self, sig: Signal) -> bool {
+        imp::process::sig_set::sigismember(&self.sig_set, sig.0)
+    }
+
+    /// Add a signal to the `SigSet`.
+    #[inline]
+    pub fn set(&mut self, sig: Signal) {
+        imp::process::sig_set::sigaddset(&mut self.sig_set, sig.0)
+    }
+
+    /// Remove a signal from the `SigSet`.
+    #[inline]
+    pub fn unset(&mut self, sig: Signal) {
+        imp::process::sig_set::sigdelset(&mut self.sig_set, sig.0)
+    }
+
+    /// Remove all signals from the `SigSet`.
+    #[inline]
+    pub fn clear(&mut self) {
+        imp::process::sig_set::sigemptyset(&mut self.sig_set)
+    }
+}
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+impl fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(
+                (1..=imp::process::sigrtmax())
+                    .map(Signal)
+                    .filter(|sig| self.is_set(*sig)),
+            )
+            .finish()
+    }
+}
+
+/// `kill(pid, sig)`—Sends a signal to a process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/kill.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/kill.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "kill")]
+pub fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
+    imp::syscalls::kill_process(pid, sig)
+}
+
+/// `kill(-pid, sig)`—Sends a signal to all processes in a process group.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/kill.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/kill.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "kill")]
+pub fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
+    imp::syscalls::kill_process_group(pid, sig)
+}
+
+/// `kill(0, sig)`—Sends a signal to all processes in the current process
+/// group.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/kill.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/kill.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "kill")]
+pub fn kill_current_process_group(sig: Signal) -> io::Result<()> {
+    imp::syscalls::kill_current_process_group(sig)
+}
+
+/// `tgkill(tgid, tid, sig)`—Sends a signal to a specific thread in a thread
+/// group.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/tgkill.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+pub fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    imp::syscalls::tgkill(tgid, tid, sig)
+}
+
+/// `sigprocmask(how, set, oldset)`—Adjusts the calling thread's signal mask,
+/// returning the previous mask.
+///
+/// If `set` is `None`, the mask is left unchanged and `how` is ignored.
+///
+/// On Linux, this only affects the calling thread.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigprocmask.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigprocmask.2.html
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[inline]
+#[doc(alias = "pthread_sigmask")]
+pub fn sigprocmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    let mut old = SigSet::new();
+    imp::syscalls::sigprocmask(how, set.map(|set| &set.sig_set), &mut old.sig_set)?;
+    Ok(old)
+}
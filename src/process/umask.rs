@@ -0,0 +1,19 @@
+//! Umask support.
+
+use crate::fs::Mode;
+use crate::imp;
+
+/// `umask(mask)`—Set the process file creation mask.
+///
+/// Returns the previous mask.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/umask.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/umask.2.html
+#[inline]
+pub fn umask(mask: Mode) -> Mode {
+    imp::syscalls::umask(mask)
+}
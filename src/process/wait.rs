@@ -1,4 +1,17 @@
+//! Process wait status.
+//!
+//! # Safety
+//!
+//! The signal accessors convert signal numbers from the `W*` macros into
+//! `Signal` values without checking them.
+#![allow(unsafe_code)]
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::fd::BorrowedFd;
 use crate::imp;
+use crate::process::Signal;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+use crate::process::{Pid, PtraceEvent, Uid};
 use bitflags::bitflags;
 
 bitflags! {
@@ -13,6 +26,49 @@ bitflags! {
     }
 }
 
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+bitflags! {
+    /// Options for modifying the behavior of [`waitid`].
+    ///
+    /// [`waitid`]: crate::process::waitid
+    pub struct WaitidOptions: u32 {
+        /// Return immediately if no child has changed state.
+        const NOHANG = imp::process::WNOHANG as _;
+        /// Return if a stopped child has been resumed by delivery of
+        /// `SIGCONT`.
+        const CONTINUED = imp::process::WCONTINUED as _;
+        /// Wait for processes that have exited.
+        const EXITED = imp::process::WEXITED as _;
+        /// Keep the process in a waitable state, so that it can be waited on
+        /// again.
+        const NOWAIT = imp::process::WNOWAIT as _;
+        /// Wait for processes that have been stopped by delivery of a
+        /// signal.
+        const STOPPED = imp::process::WSTOPPED as _;
+    }
+}
+
+/// The process or processes to wait on with [`waitid`].
+///
+/// [`waitid`]: crate::process::waitid
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum WaitId<'a> {
+    /// `P_ALL`—Wait on any child process.
+    All,
+
+    /// `P_PID`—Wait on the child process with the given process ID.
+    Pid(Pid),
+
+    /// `P_PGID`—Wait on any child process in the given process group, or
+    /// in the caller's process group if `None`.
+    Pgid(Option<Pid>),
+
+    /// `P_PIDFD`—Wait on the child process referred to by the given pidfd.
+    PidFd(BorrowedFd<'a>),
+}
+
 /// the status of the child processes the caller waited on
 #[derive(Debug, Clone, Copy)]
 pub struct WaitStatus(u32);
@@ -24,6 +80,26 @@ impl WaitStatus {
         WaitStatus(status)
     }
 
+    /// Create a `WaitStatus` out of the `si_code` and `si_status` fields of
+    /// a `siginfo_t` filled in by `waitid`, encoding them the way `wait4`
+    /// does.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[inline]
+    pub(crate) fn from_sigchld(code: i32, status: i32) -> Self {
+        let status = status as u32;
+        WaitStatus(match code as _ {
+            imp::process::CLD_EXITED => (status & 0xff) << 8,
+            imp::process::CLD_KILLED => status & 0x7f,
+            imp::process::CLD_DUMPED => (status & 0x7f) | 0x80,
+            // Ptrace stops report the event in the bits above the signal.
+            imp::process::CLD_STOPPED | imp::process::CLD_TRAPPED => {
+                ((status & 0xffff) << 8) | 0x7f
+            }
+            imp::process::CLD_CONTINUED => 0xffff,
+            _ => 0,
+        })
+    }
+
     /// Converts a `WaitStatus` into its raw representation as an integer.
     #[inline]
     pub const fn as_raw(self) -> u32 {
@@ -42,12 +118,36 @@ impl WaitStatus {
         imp::process::WIFCONTINUED(self.0 as _)
     }
 
-    /// Returns the number of the signal that stopped the process,
-    /// if the process was stopped by a signal.
+    /// Returns the signal that stopped the process, if the process was
+    /// stopped by a signal.
     #[inline]
-    pub fn stopping_signal(self) -> Option<u32> {
+    pub fn stopping_signal(self) -> Option<Signal> {
         if self.stopped() {
-            Some(imp::process::WSTOPSIG(self.0 as _) as _)
+            // Clear the bit `PTRACE_O_TRACESYSGOOD` sets in syscall stops.
+            let signal = imp::process::WSTOPSIG(self.0 as _) & !0x80;
+            // Safety: `WSTOPSIG` is the number of the signal which stopped
+            // the process.
+            Some(unsafe { Signal::from_raw_unchecked(signal as _) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the process is in a syscall-entry or syscall-exit
+    /// stop of a tracee with `PTRACE_O_TRACESYSGOOD` set.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[inline]
+    pub fn syscall_stopped(self) -> bool {
+        self.stopped() && imp::process::WSTOPSIG(self.0 as _) as i32 == Signal::TRAP.as_raw() | 0x80
+    }
+
+    /// Returns the `PTRACE_EVENT_*` stop the process is in, if it is a
+    /// tracee stopped at a ptrace event.
+    #[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+    #[inline]
+    pub fn ptrace_event(self) -> Option<PtraceEvent> {
+        if self.stopped() {
+            PtraceEvent::from_raw(self.0 >> 16)
         } else {
             None
         }
@@ -64,12 +164,142 @@ impl WaitStatus {
         }
     }
 
-    /// Returns the number of the signal that terminated the process,
-    /// if the process was terminated by a signal.
+    /// Returns the signal that terminated the process, if the process was
+    /// terminated by a signal.
     #[inline]
-    pub fn terminating_signal(self) -> Option<u32> {
+    pub fn terminating_signal(self) -> Option<Signal> {
         if imp::process::WIFSIGNALED(self.0 as _) {
-            Some(imp::process::WTERMSIG(self.0 as _) as _)
+            // Safety: `WTERMSIG` is the number of the signal which
+            // terminated the process.
+            Some(unsafe { Signal::from_raw_unchecked(imp::process::WTERMSIG(self.0 as _) as _) })
+        } else {
+            None
+        }
+    }
+}
+
+/// The status of a child process, as reported by [`waitid`] in a
+/// `siginfo_t`.
+///
+/// [`waitid`]: crate::process::waitid
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+#[derive(Debug, Clone, Copy)]
+pub struct WaitidStatus {
+    code: i32,
+    pid: Pid,
+    uid: Uid,
+    status: i32,
+}
+
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+impl WaitidStatus {
+    /// Create a `WaitidStatus` out of the fields of a `siginfo_t`.
+    #[inline]
+    pub(crate) fn new(code: i32, pid: Pid, uid: Uid, status: i32) -> Self {
+        Self {
+            code,
+            pid,
+            uid,
+            status,
+        }
+    }
+
+    /// Returns the process ID of the child, from `si_pid`.
+    #[inline]
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the real user ID of the child, from `si_uid`.
+    #[inline]
+    pub fn uid(&self) -> Uid {
+        self.uid
+    }
+
+    /// Returns the raw `si_code` value, one of the `CLD_*` constants.
+    #[inline]
+    pub fn raw_code(&self) -> i32 {
+        self.code
+    }
+
+    /// Returns the raw `si_status` value, which is either an exit status or
+    /// a signal number, depending on `si_code`.
+    #[inline]
+    pub fn raw_status(&self) -> i32 {
+        self.status
+    }
+
+    /// Returns whether the process exited normally (`CLD_EXITED`).
+    #[inline]
+    pub fn exited(&self) -> bool {
+        self.code == imp::process::CLD_EXITED as _
+    }
+
+    /// Returns whether the process was killed by a signal (`CLD_KILLED`).
+    #[inline]
+    pub fn killed(&self) -> bool {
+        self.code == imp::process::CLD_KILLED as _
+    }
+
+    /// Returns whether the process was killed by a signal and dumped core
+    /// (`CLD_DUMPED`).
+    #[inline]
+    pub fn dumped(&self) -> bool {
+        self.code == imp::process::CLD_DUMPED as _
+    }
+
+    /// Returns whether the traced process has trapped (`CLD_TRAPPED`).
+    #[inline]
+    pub fn trapped(&self) -> bool {
+        self.code == imp::process::CLD_TRAPPED as _
+    }
+
+    /// Returns whether the process is currently stopped (`CLD_STOPPED`).
+    #[inline]
+    pub fn stopped(&self) -> bool {
+        self.code == imp::process::CLD_STOPPED as _
+    }
+
+    /// Returns whether the process has continued from a job control stop
+    /// (`CLD_CONTINUED`).
+    #[inline]
+    pub fn continued(&self) -> bool {
+        self.code == imp::process::CLD_CONTINUED as _
+    }
+
+    /// Returns the exit status number returned by the process, if it exited
+    /// normally.
+    #[inline]
+    pub fn exit_status(&self) -> Option<u32> {
+        if self.exited() {
+            Some(self.status as u32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the signal that stopped the process, if the process was
+    /// stopped or trapped.
+    #[inline]
+    pub fn stopping_signal(&self) -> Option<Signal> {
+        if self.stopped() || self.trapped() {
+            // Safety: The low bits of `si_status` are the number of the
+            // signal which stopped the process; ptrace stops report an event
+            // or `PTRACE_O_TRACESYSGOOD` bit above them.
+            Some(unsafe { Signal::from_raw_unchecked(self.status & 0x7f) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the signal that terminated the process, if the process was
+    /// killed by a signal.
+    #[inline]
+    pub fn terminating_signal(&self) -> Option<Signal> {
+        if self.killed() || self.dumped() {
+            // Safety: `si_status` is the number of the signal which
+            // terminated the process.
+            Some(unsafe { Signal::from_raw_unchecked(self.status) })
         } else {
             None
         }
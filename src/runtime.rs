@@ -14,6 +14,10 @@
 //! This module is intended to be used for implementing a runtime library
 //! such as libc. Use of these features for any other purpose is likely
 //! to create serious problems.
+//!
+//! Most of these functions, including `sigaction`, are only available with
+//! the `linux_raw` backend. With the libc backend, the libc owns the process
+//! state they manage, so use the libc's equivalents instead.
 
 #![allow(unsafe_code)]
 
@@ -25,7 +29,7 @@ use crate::imp;
 #[cfg(linux_raw)]
 use crate::io;
 #[cfg(linux_raw)]
-use crate::process::Pid;
+use crate::process::{Pid, Signal};
 #[cfg(linux_raw)]
 use core::ffi::c_void;
 #[cfg(linux_raw)]
@@ -138,6 +142,38 @@ pub fn exe_phdrs() -> (*const c_void, usize) {
 #[cfg(linux_raw)]
 pub use imp::thread::tls::StartupTlsInfo;
 
+#[cfg(linux_raw)]
+pub use imp::process::{Sigaction, SigactionFlags, SIG_DFL, SIG_IGN};
+
+/// `sigaction(sig, new)`—Sets and/or gets the action for a signal,
+/// returning the previous action.
+///
+/// This uses the kernel's `struct sigaction` layout. On architectures which
+/// have `SA_RESTORER`, the kernel doesn't provide a default return path for
+/// handlers, so when installing a handler, the caller must set
+/// [`SigactionFlags::RESTORER`] and a `sa_restorer` function which invokes
+/// `rt_sigreturn`.
+///
+/// This is only available with the `linux_raw` backend. With the libc
+/// backend, the libc manages signal dispositions itself, reserving some
+/// signals and providing its own restorer, so use the libc's `sigaction`
+/// instead.
+///
+/// # Safety
+///
+/// The handler, if any, must be safe to run asynchronously at any point in
+/// any thread, and the restorer, if any, must be valid for the handler.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaction.2.html
+#[cfg(linux_raw)]
+#[inline]
+pub unsafe fn sigaction(sig: Signal, new: Option<Sigaction>) -> io::Result<Sigaction> {
+    imp::syscalls::sigaction(sig, new)
+}
+
 /// `fork()`—Creates a new process by duplicating the calling process.
 ///
 /// On success, the pid of the child process is returned in the parent, and
@@ -0,0 +1,109 @@
+use crate::imp;
+use crate::io;
+use crate::termios::{
+    ControlModes, InputModes, LocalModes, OutputModes, Speed, Termios, VMIN, VTIME,
+};
+
+/// `cfgetospeed(termios)`—Return the output speed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/cfgetospeed.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/cfgetospeed.3.html
+#[inline]
+#[must_use]
+pub fn cfgetospeed(termios: &Termios) -> Speed {
+    imp::syscalls::cfgetospeed(termios)
+}
+
+/// `cfgetispeed(termios)`—Return the input speed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/cfgetispeed.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/cfgetispeed.3.html
+#[inline]
+#[must_use]
+pub fn cfgetispeed(termios: &Termios) -> Speed {
+    imp::syscalls::cfgetispeed(termios)
+}
+
+/// `cfsetospeed(termios, speed)`—Set the output speed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/cfsetospeed.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/cfsetospeed.3.html
+#[inline]
+pub fn cfsetospeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    imp::syscalls::cfsetospeed(termios, speed)
+}
+
+/// `cfsetispeed(termios, speed)`—Set the input speed.
+///
+/// A `speed` of [`B0`] sets the input speed to be the same as the output
+/// speed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/cfsetispeed.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/cfsetispeed.3.html
+/// [`B0`]: crate::termios::B0
+#[inline]
+pub fn cfsetispeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    imp::syscalls::cfsetispeed(termios, speed)
+}
+
+/// `cfsetspeed(termios, speed)`—Set both the input and output speeds.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/cfsetspeed.3.html
+#[inline]
+pub fn cfsetspeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
+    cfsetospeed(termios, speed)?;
+    cfsetispeed(termios, speed)
+}
+
+/// `cfmakeraw(termios)`—Set a terminal to "raw" mode.
+///
+/// Input is available character by character, echoing is disabled, and
+/// special processing of input and output characters is disabled.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/cfmakeraw.3.html
+#[inline]
+pub fn cfmakeraw(termios: &mut Termios) {
+    // Use the same flags as glibc and musl.
+    termios.c_iflag &= !(InputModes::IGNBRK
+        | InputModes::BRKINT
+        | InputModes::PARMRK
+        | InputModes::ISTRIP
+        | InputModes::INLCR
+        | InputModes::IGNCR
+        | InputModes::ICRNL
+        | InputModes::IXON)
+        .bits();
+    termios.c_oflag &= !OutputModes::OPOST.bits();
+    termios.c_lflag &= !(LocalModes::ECHO
+        | LocalModes::ECHONL
+        | LocalModes::ICANON
+        | LocalModes::ISIG
+        | LocalModes::IEXTEN)
+        .bits();
+    termios.c_cflag &= !(ControlModes::CSIZE | ControlModes::PARENB).bits();
+    termios.c_cflag |= ControlModes::CS8.bits();
+    termios.c_cc[VMIN] = 1;
+    termios.c_cc[VTIME] = 0;
+}
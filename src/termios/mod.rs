@@ -0,0 +1,22 @@
+//! Terminal I/O stream operations.
+
+mod cf;
+mod tc;
+
+pub use cf::{cfgetispeed, cfgetospeed, cfmakeraw, cfsetispeed, cfsetospeed, cfsetspeed};
+pub use tc::{
+    tcdrain, tcflow, tcflush, tcgetattr, tcgetpgrp, tcgetsid, tcsendbreak, tcsetattr, tcsetpgrp,
+};
+
+pub use crate::imp::termios::{
+    Action, ControlModes, InputModes, LocalModes, OptionalActions, OutputModes, QueueSelector,
+    Speed, B0, B110, B115200, B1200, B134, B150, B1800, B19200, B200, B230400, B2400, B300, B38400,
+    B4800, B50, B57600, B600, B75, B9600, VEOF, VEOL, VERASE, VINTR, VKILL, VMIN, VQUIT, VSTART,
+    VSTOP, VSUSP, VTIME,
+};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use crate::imp::termios::{
+    B1000000, B1152000, B1500000, B2000000, B2500000, B3000000, B3500000, B4000000, B460800,
+    B500000, B576000, B921600, VDISCARD, VEOL2, VLNEXT, VREPRINT, VSWTC, VWERASE,
+};
+pub use crate::io::Termios;
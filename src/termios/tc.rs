@@ -0,0 +1,167 @@
+use crate::fd::AsFd;
+use crate::imp;
+use crate::io;
+use crate::process::Pid;
+use crate::termios::{Action, OptionalActions, QueueSelector, Termios};
+
+/// `tcgetattr(fd)`—Get terminal attributes.
+///
+/// Also known as the `TCGETS` operation with `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcgetattr.3.html
+#[inline]
+#[doc(alias = "TCGETS")]
+pub fn tcgetattr<Fd: AsFd>(fd: &Fd) -> io::Result<Termios> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcgetattr(fd)
+}
+
+/// `tcsetattr(fd, optional_actions, termios)`—Set terminal attributes.
+///
+/// Also known as the `TCSETS`, `TCSETSW`, and `TCSETSF` operations with
+/// `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsetattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcsetattr.3.html
+#[inline]
+#[doc(alias = "TCSETS")]
+#[doc(alias = "TCSETSW")]
+#[doc(alias = "TCSETSF")]
+pub fn tcsetattr<Fd: AsFd>(
+    fd: &Fd,
+    optional_actions: OptionalActions,
+    termios: &Termios,
+) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsetattr(fd, optional_actions, termios)
+}
+
+/// `tcsendbreak(fd, 0)`—Transmit zero-valued bits.
+///
+/// Also known as the `TCSBRK` operation with `ioctl`, with a duration of 0.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsendbreak.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcsendbreak.3.html
+#[inline]
+#[doc(alias = "TCSBRK")]
+pub fn tcsendbreak<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsendbreak(fd)
+}
+
+/// `tcdrain(fd)`—Wait until all pending output has been written.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcdrain.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcdrain.3.html
+#[inline]
+pub fn tcdrain<Fd: AsFd>(fd: &Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcdrain(fd)
+}
+
+/// `tcflush(fd, queue_selector)`—Discard pending input, output, or both.
+///
+/// Also known as the `TCFLSH` operation with `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcflush.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcflush.3.html
+#[inline]
+#[doc(alias = "TCFLSH")]
+pub fn tcflush<Fd: AsFd>(fd: &Fd, queue_selector: QueueSelector) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcflush(fd, queue_selector)
+}
+
+/// `tcflow(fd, action)`—Suspend or resume transmission or reception.
+///
+/// Also known as the `TCXONC` operation with `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcflow.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcflow.3.html
+#[inline]
+#[doc(alias = "TCXONC")]
+pub fn tcflow<Fd: AsFd>(fd: &Fd, action: Action) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcflow(fd, action)
+}
+
+/// `tcgetpgrp(fd)`—Get the foreground process group of a terminal.
+///
+/// Also known as the `TIOCGPGRP` operation with `ioctl`.
+///
+/// On Linux, if the terminal has no foreground process group, or it isn't
+/// visible in the caller's PID namespace, this fails with
+/// [`io::Error::OPNOTSUPP`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetpgrp.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcgetpgrp.3.html
+#[inline]
+#[doc(alias = "TIOCGPGRP")]
+pub fn tcgetpgrp<Fd: AsFd>(fd: &Fd) -> io::Result<Pid> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcgetpgrp(fd)
+}
+
+/// `tcsetpgrp(fd, pid)`—Set the foreground process group of a terminal.
+///
+/// Also known as the `TIOCSPGRP` operation with `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsetpgrp.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcsetpgrp.3.html
+#[inline]
+#[doc(alias = "TIOCSPGRP")]
+pub fn tcsetpgrp<Fd: AsFd>(fd: &Fd, pid: Pid) -> io::Result<()> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcsetpgrp(fd, pid)
+}
+
+/// `tcgetsid(fd)`—Get the session ID of the session for which a terminal is
+/// the controlling terminal.
+///
+/// Also known as the `TIOCGSID` operation with `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/tcgetsid.3.html
+#[inline]
+#[doc(alias = "TIOCGSID")]
+pub fn tcgetsid<Fd: AsFd>(fd: &Fd) -> io::Result<Pid> {
+    let fd = fd.as_fd();
+    imp::syscalls::tcgetsid(fd)
+}
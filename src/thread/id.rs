@@ -1,5 +1,5 @@
-use crate::imp;
-use crate::process::Pid;
+use crate::process::{Gid, Pid, Uid};
+use crate::{imp, io};
 
 /// `gettid()`—Returns the thread ID.
 ///
@@ -12,3 +12,93 @@ use crate::process::Pid;
 pub fn gettid() -> Pid {
     imp::syscalls::gettid()
 }
+
+/// `setuid(uid)`—Sets the effective user ID of the calling thread.
+///
+/// # Warning
+///
+/// This is not the `setuid` you are looking for! POSIX requires all threads
+/// in a process to share the same credentials, and libc's `setuid` signals
+/// every thread to make them all change. This function is the raw system
+/// call, which only changes the credentials of the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setuid.2.html
+#[inline]
+#[doc(alias = "setuid")]
+pub fn set_thread_uid(uid: Uid) -> io::Result<()> {
+    imp::syscalls::set_thread_uid(uid)
+}
+
+/// `setresuid(ruid, euid, suid)`—Sets the real, effective, and saved user IDs
+/// of the calling thread.
+///
+/// # Warning
+///
+/// This is not the `setresuid` you are looking for! It only changes the
+/// credentials of the calling thread; see [`set_thread_uid`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setresuid.2.html
+#[inline]
+#[doc(alias = "setresuid")]
+pub fn set_thread_res_uid(ruid: Uid, euid: Uid, suid: Uid) -> io::Result<()> {
+    imp::syscalls::set_thread_res_uid(ruid, euid, suid)
+}
+
+/// `setgid(gid)`—Sets the effective group ID of the calling thread.
+///
+/// # Warning
+///
+/// This is not the `setgid` you are looking for! It only changes the
+/// credentials of the calling thread; see [`set_thread_uid`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setgid.2.html
+#[inline]
+#[doc(alias = "setgid")]
+pub fn set_thread_gid(gid: Gid) -> io::Result<()> {
+    imp::syscalls::set_thread_gid(gid)
+}
+
+/// `setresgid(rgid, egid, sgid)`—Sets the real, effective, and saved group
+/// IDs of the calling thread.
+///
+/// # Warning
+///
+/// This is not the `setresgid` you are looking for! It only changes the
+/// credentials of the calling thread; see [`set_thread_uid`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setresgid.2.html
+#[inline]
+#[doc(alias = "setresgid")]
+pub fn set_thread_res_gid(rgid: Gid, egid: Gid, sgid: Gid) -> io::Result<()> {
+    imp::syscalls::set_thread_res_gid(rgid, egid, sgid)
+}
+
+/// `setgroups(groups)`—Sets the supplementary group IDs of the calling
+/// thread.
+///
+/// # Warning
+///
+/// This is not the `setgroups` you are looking for! It only changes the
+/// credentials of the calling thread; see [`set_thread_uid`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/setgroups.2.html
+#[inline]
+#[doc(alias = "setgroups")]
+pub fn set_thread_groups(groups: &[Gid]) -> io::Result<()> {
+    imp::syscalls::set_thread_groups(groups)
+}
@@ -11,7 +11,10 @@ mod id;
 pub use futex::{futex, FutexFlags, FutexOperation};
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use id::gettid;
+pub use id::{
+    gettid, set_thread_gid, set_thread_groups, set_thread_res_gid, set_thread_res_uid,
+    set_thread_uid,
+};
 
 #[cfg(not(target_os = "redox"))]
 pub use clock::{nanosleep, NanosleepRelativeResult};
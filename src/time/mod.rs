@@ -3,6 +3,8 @@
 use crate::imp;
 
 mod clock;
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+mod timerfd;
 
 // TODO: Convert WASI'S clock APIs to use handles rather than ambient clock
 // identifiers, update `wasi-libc`, and then add support in `rustix`.
@@ -12,3 +14,8 @@ pub use clock::clock_getres;
 pub use clock::{clock_gettime, clock_gettime_dynamic, ClockId, DynamicClockId};
 
 pub use imp::time::{Nsecs, Secs, Timespec};
+#[cfg(any(linux_raw, all(libc, any(target_os = "android", target_os = "linux"))))]
+pub use timerfd::{
+    timerfd_create, timerfd_gettime, timerfd_read, timerfd_settime, Itimerspec, TimerfdClockId,
+    TimerfdFlags, TimerfdTimerFlags,
+};
@@ -0,0 +1,83 @@
+//! The Linux `timerfd` API.
+//!
+//! # Safety
+//!
+//! `timerfd_read` reads the expiration count directly into uninitialized
+//! memory; the kernel fully initializes it when the read succeeds.
+#![allow(unsafe_code)]
+
+use crate::fd::AsFd;
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use core::mem::{size_of, MaybeUninit};
+use core::slice;
+
+pub use imp::time::{Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags};
+
+/// `timerfd_create(clockid, flags)`—Create a timer.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_create.2.html
+#[inline]
+pub fn timerfd_create(clockid: TimerfdClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
+    imp::syscalls::timerfd_create(clockid, flags)
+}
+
+/// `timerfd_settime(fd, flags, new_value, old_value)`—Set the time on a timer.
+///
+/// On success, returns the previous setting of the timer.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_settime.2.html
+#[inline]
+pub fn timerfd_settime<Fd: AsFd>(
+    fd: &Fd,
+    flags: TimerfdTimerFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let fd = fd.as_fd();
+    imp::syscalls::timerfd_settime(fd, flags, new_value)
+}
+
+/// `timerfd_gettime(fd, curr_value)`—Query a timer.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_gettime.2.html
+#[inline]
+pub fn timerfd_gettime<Fd: AsFd>(fd: &Fd) -> io::Result<Itimerspec> {
+    let fd = fd.as_fd();
+    imp::syscalls::timerfd_gettime(fd)
+}
+
+/// Reads the number of expirations since the timer was last set or read.
+///
+/// If the file descriptor is non-blocking and the timer has not expired, this
+/// fails with [`io::Error::AGAIN`]. If the timer was set with
+/// [`TimerfdTimerFlags::CANCEL_ON_SET`] and the realtime clock is changed,
+/// this fails with [`io::Error::CANCELED`]. If `fd` isn't a timerfd file
+/// descriptor and returns a partial count, this fails with
+/// [`io::Error::IO`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/timerfd_create.2.html
+#[inline]
+#[doc(alias = "read")]
+pub fn timerfd_read<Fd: AsFd>(fd: &Fd) -> io::Result<u64> {
+    let mut count = MaybeUninit::<u64>::uninit();
+    let fd = fd.as_fd();
+    let buf =
+        unsafe { slice::from_raw_parts_mut(count.as_mut_ptr().cast::<u8>(), size_of::<u64>()) };
+    let nread = imp::syscalls::read(fd, buf)?;
+    if nread != size_of::<u64>() {
+        return Err(io::Error::IO);
+    }
+    Ok(unsafe { count.assume_init() })
+}
@@ -0,0 +1,205 @@
+use rustix::fs::fanotify::{
+    fanotify_init, fanotify_mark, fanotify_read, fanotify_respond, EventMask, InfoRecord,
+    InitFlags, MarkFlags, Response,
+};
+use rustix::fs::{cwd, fstat, OFlags};
+use rustix::process::getpid;
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+/// A tmpfs mounted on a temporary directory, so that marks don't see events
+/// from anything else.
+struct Tmpfs {
+    path: PathBuf,
+    _dir: tempfile::TempDir,
+}
+
+impl Tmpfs {
+    /// Mount a tmpfs, or return `None` if we aren't privileged to.
+    fn mount() -> Option<Self> {
+        let dir = tempfile::tempdir().expect("construct tempdir");
+        let target = CString::new(dir.path().as_os_str().as_bytes()).unwrap();
+        let res = unsafe {
+            libc::mount(
+                b"tmpfs\0".as_ptr().cast(),
+                target.as_ptr(),
+                b"tmpfs\0".as_ptr().cast(),
+                0,
+                std::ptr::null(),
+            )
+        };
+        if res != 0 {
+            let err = std::io::Error::last_os_error();
+            assert_eq!(err.raw_os_error(), Some(libc::EPERM), "{}", err);
+            return None;
+        }
+        Some(Self {
+            path: dir.path().to_owned(),
+            _dir: dir,
+        })
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Tmpfs {
+    fn drop(&mut self) {
+        let target = CString::new(self.path.as_os_str().as_bytes()).unwrap();
+        unsafe {
+            libc::umount2(target.as_ptr(), libc::MNT_DETACH);
+        }
+    }
+}
+
+#[test]
+fn test_fanotify_event_fds() {
+    let tmpfs = match Tmpfs::mount() {
+        Some(tmpfs) => tmpfs,
+        None => return,
+    };
+    let fan = fanotify_init(
+        InitFlags::CLOEXEC | InitFlags::NONBLOCK,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    )
+    .unwrap();
+    fanotify_mark(
+        &fan,
+        MarkFlags::ADD | MarkFlags::MOUNT,
+        EventMask::OPEN | EventMask::CLOSE_WRITE,
+        &cwd(),
+        tmpfs.path(),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    assert_eq!(
+        unsafe { fanotify_read(&fan, &mut buf) }.unwrap_err(),
+        rustix::io::Error::AGAIN
+    );
+
+    let file = tmpfs.path().join("file");
+    std::fs::write(&file, b"hello").unwrap();
+    let ino = std::fs::metadata(&file).unwrap().ino();
+
+    // The kernel may merge the two events into one.
+    let mut mask = EventMask::empty();
+    for mut event in unsafe { fanotify_read(&fan, &mut buf) }.unwrap() {
+        mask |= event.mask();
+        assert_eq!(event.pid(), Some(getpid()));
+        assert_eq!(event.info_records().count(), 0);
+
+        let fd = event.take_fd().expect("event fd");
+        assert!(event.fd().is_none());
+        assert_eq!(fstat(&fd).unwrap().st_ino, ino);
+    }
+    assert_eq!(mask, EventMask::OPEN | EventMask::CLOSE_WRITE);
+}
+
+#[test]
+fn test_fanotify_dfid_name() {
+    let tmpfs = match Tmpfs::mount() {
+        Some(tmpfs) => tmpfs,
+        None => return,
+    };
+    let fan = match fanotify_init(
+        InitFlags::CLOEXEC
+            | InitFlags::NONBLOCK
+            | InitFlags::REPORT_DFID_NAME
+            | InitFlags::REPORT_PIDFD,
+        OFlags::RDONLY,
+    ) {
+        Ok(fan) => fan,
+        // `FAN_REPORT_PIDFD` requires Linux 5.15.
+        Err(rustix::io::Error::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    fanotify_mark(
+        &fan,
+        MarkFlags::ADD | MarkFlags::FILESYSTEM,
+        EventMask::CREATE | EventMask::ONDIR,
+        &cwd(),
+        tmpfs.path(),
+    )
+    .unwrap();
+
+    std::fs::File::create(tmpfs.path().join("file")).unwrap();
+    std::fs::create_dir(tmpfs.path().join("dir")).unwrap();
+
+    let mut buf = [0_u8; 4096];
+    let mut events = unsafe { fanotify_read(&fan, &mut buf) }.unwrap();
+
+    let mut dir_handles = Vec::new();
+    for (expected_mask, expected_name) in [
+        (EventMask::CREATE, "file"),
+        (EventMask::CREATE | EventMask::ONDIR, "dir"),
+    ] {
+        let event = events.next().expect("event");
+        assert_eq!(event.mask(), expected_mask);
+        assert!(event.fd().is_none());
+        assert!(event.pidfd().is_some());
+
+        let records = event.info_records().collect::<Vec<_>>();
+        assert_eq!(records.len(), 1, "{:?}", records);
+        match records[0] {
+            InfoRecord::DfidName(fid, name) => {
+                assert_eq!(name.to_str().unwrap(), expected_name);
+                assert!(!fid.handle().is_empty());
+                dir_handles.push(fid.handle().to_vec());
+            }
+            other => panic!("unexpected info record {:?}", other),
+        }
+    }
+    assert!(events.next().is_none());
+    assert_eq!(dir_handles[0], dir_handles[1]);
+}
+
+#[test]
+fn test_fanotify_permission() {
+    let tmpfs = match Tmpfs::mount() {
+        Some(tmpfs) => tmpfs,
+        None => return,
+    };
+    let file = tmpfs.path().join("file");
+    std::fs::write(&file, b"hello").unwrap();
+
+    let fan = fanotify_init(
+        InitFlags::CLOEXEC | InitFlags::CLASS_CONTENT,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    )
+    .unwrap();
+    fanotify_mark(
+        &fan,
+        MarkFlags::ADD | MarkFlags::MOUNT,
+        EventMask::OPEN_PERM,
+        &cwd(),
+        tmpfs.path(),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    for response in [Response::Deny, Response::Allow] {
+        let opener = {
+            let file = file.clone();
+            std::thread::spawn(move || std::fs::File::open(file))
+        };
+
+        let mut events = unsafe { fanotify_read(&fan, &mut buf) }.unwrap();
+        let event = events.next().expect("event");
+        assert_eq!(event.mask(), EventMask::OPEN_PERM);
+        fanotify_respond(&fan, &event.fd().unwrap(), response).unwrap();
+        assert!(events.next().is_none());
+
+        let opened = opener.join().unwrap();
+        match response {
+            Response::Allow => assert!(opened.is_ok()),
+            Response::Deny => assert_eq!(
+                opened.unwrap_err().raw_os_error(),
+                Some(rustix::io::Error::PERM.raw_os_error())
+            ),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use rustix::fs::inotify::{
+    inotify_add_watch, inotify_init, inotify_remove_watch, CreateFlags, Events, WatchFlags,
+};
+
+#[test]
+fn test_inotify() {
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+    let inot = inotify_init(CreateFlags::CLOEXEC | CreateFlags::NONBLOCK).unwrap();
+    let wd = inotify_add_watch(
+        &inot,
+        tmpdir.path(),
+        WatchFlags::CREATE | WatchFlags::MOVE | WatchFlags::ONLYDIR,
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    assert_eq!(
+        rustix::io::read(&inot, &mut buf).unwrap_err(),
+        rustix::io::Error::AGAIN
+    );
+
+    std::fs::File::create(tmpdir.path().join("file1")).expect("create file1");
+    std::fs::rename(tmpdir.path().join("file1"), tmpdir.path().join("file2")).expect("rename");
+    std::fs::create_dir(tmpdir.path().join("dir")).expect("create dir");
+
+    let n = rustix::io::read(&inot, &mut buf).unwrap();
+    let events = Events::new(&buf[..n]).collect::<Vec<_>>();
+    assert_eq!(events.len(), 4, "{:?}", events);
+    for event in &events {
+        assert_eq!(event.wd(), wd);
+    }
+
+    assert_eq!(events[0].events(), WatchFlags::CREATE);
+    assert_eq!(events[0].file_name().unwrap().to_bytes(), b"file1");
+
+    assert_eq!(events[1].events(), WatchFlags::MOVED_FROM);
+    assert_eq!(events[1].file_name().unwrap().to_bytes(), b"file1");
+    assert_eq!(events[2].events(), WatchFlags::MOVED_TO);
+    assert_eq!(events[2].file_name().unwrap().to_bytes(), b"file2");
+    assert_ne!(events[1].cookie(), 0);
+    assert_eq!(events[1].cookie(), events[2].cookie());
+
+    assert_eq!(events[3].events(), WatchFlags::CREATE | WatchFlags::ISDIR);
+    assert_eq!(events[3].file_name().unwrap().to_bytes(), b"dir");
+
+    // Removing the watch queues an `IN_IGNORED` event, with no name.
+    inotify_remove_watch(&inot, wd).unwrap();
+    let n = rustix::io::read(&inot, &mut buf).unwrap();
+    let events = Events::new(&buf[..n]).collect::<Vec<_>>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].events(), WatchFlags::IGNORED);
+    assert_eq!(events[0].file_name(), None);
+
+    // A truncated record ends the iteration.
+    assert_eq!(Events::new(&buf[..n - 1]).count(), 0);
+}
+
+#[test]
+fn test_inotify_add_watch_errors() {
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+    let file = tmpdir.path().join("file");
+    std::fs::File::create(&file).expect("create file");
+    let inot = inotify_init(CreateFlags::CLOEXEC).unwrap();
+
+    assert_eq!(
+        inotify_add_watch(&inot, &file, WatchFlags::ALL_EVENTS | WatchFlags::ONLYDIR).unwrap_err(),
+        rustix::io::Error::NOTDIR
+    );
+    assert_eq!(
+        inotify_remove_watch(&inot, 12345).unwrap_err(),
+        rustix::io::Error::INVAL
+    );
+}
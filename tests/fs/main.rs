@@ -2,10 +2,14 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod fanotify;
 mod fcntl;
 mod file;
 #[cfg(not(target_os = "wasi"))]
 mod flock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod inotify;
 mod invalid_offset;
 mod long_paths;
 #[cfg(not(any(
@@ -21,7 +25,11 @@ mod makedev;
 mod mkdirat;
 mod mknodat;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod mount;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat2;
 mod readdir;
 mod renameat;
 mod statfs;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod xattr;
@@ -0,0 +1,364 @@
+use rustix::fd::AsFd;
+use rustix::fs::mount::*;
+use rustix::fs::{cwd, openat, statat, AtFlags, Mode, OFlags};
+use rustix::io::{self, Error};
+use rustix::process::{getgid, getuid, unshare, UnshareFlags};
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::Command;
+
+/// Set, to the path of the temporary directory, in the environment of a test
+/// binary re-executed by `in_namespaces`.
+const NAMESPACE_CHILD_DIR: &str = "RUSTIX_TEST_NAMESPACE_CHILD_DIR";
+
+/// Run `f` in a child process, in new user and mount namespaces in which it
+/// is root.
+///
+/// Returns without running `f` if we can't create the namespaces.
+fn in_mount_namespace(test: &str, f: impl FnOnce(&Path)) {
+    in_namespaces(test, UnshareFlags::NEWUSER | UnshareFlags::NEWNS, f)
+}
+
+/// Run `f` in a child process, in the namespaces in `flags`.
+///
+/// `unshare(CLONE_NEWUSER)` fails in multithreaded processes, and a child
+/// forked from one can't safely do much before it execs, so this re-executes
+/// the test binary to run just `test`, entering the namespaces before the
+/// exec.
+fn in_namespaces(test: &str, flags: UnshareFlags, f: impl FnOnce(&Path)) {
+    if let Some(dir) = std::env::var_os(NAMESPACE_CHILD_DIR) {
+        f(Path::new(&dir));
+        return;
+    }
+
+    // Format the id maps up front, as the child can't allocate.
+    let uid_map = format!("0 {} 1", getuid().as_raw());
+    let gid_map = format!("0 {} 1", getgid().as_raw());
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+
+    let output = unsafe {
+        Command::new(std::env::current_exe().unwrap())
+            .arg("--exact")
+            .arg(format!("mount::{}", test))
+            .env(NAMESPACE_CHILD_DIR, tmpdir.path())
+            .pre_exec(move || {
+                unshare(flags)?;
+                if flags.contains(UnshareFlags::NEWUSER) {
+                    write_file(rustix::zstr!("/proc/self/setgroups"), "deny")?;
+                    write_file(rustix::zstr!("/proc/self/uid_map"), &uid_map)?;
+                    write_file(rustix::zstr!("/proc/self/gid_map"), &gid_map)?;
+                }
+                Ok(())
+            })
+            .output()
+    };
+    let output = match output {
+        Ok(output) => output,
+        // Unprivileged user namespaces may be disabled.
+        Err(err) if err.raw_os_error() == Some(libc::EPERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success() && stdout.contains(" 1 passed;"),
+        "child failed: {:?}\n{}{}",
+        output.status,
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Write `contents` to the file at `path`.
+///
+/// This doesn't allocate if `path` is a `ZStr`, so it can be used in
+/// `pre_exec`. It uses plain paths rather than `write_uid_map` and friends,
+/// so that these tests don't need the `procfs` feature.
+fn write_file<P: rustix::path::Arg>(path: P, contents: &str) -> io::Result<()> {
+    let fd = openat(
+        &cwd(),
+        path,
+        OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )?;
+    if io::write(&fd, contents.as_bytes())? != contents.len() {
+        return Err(Error::IO);
+    }
+    Ok(())
+}
+
+fn create_dir(parent: &Path, name: &str) -> std::path::PathBuf {
+    let dir = parent.join(name);
+    std::fs::create_dir(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_mount_umount2() {
+    in_mount_namespace("test_mount_umount2", |tmpdir| {
+        let target = create_dir(tmpdir, "target");
+        mount(
+            "tmpfs",
+            &target,
+            "tmpfs",
+            MountFlags::NOSUID | MountFlags::NODEV,
+            Some(rustix::zstr!("size=1m")),
+        )
+        .unwrap();
+        std::fs::write(target.join("file"), b"hello").unwrap();
+
+        mount(
+            "",
+            &target,
+            "",
+            MountFlags::REMOUNT | MountFlags::RDONLY,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::write(target.join("file"), b"world")
+                .unwrap_err()
+                .raw_os_error(),
+            Some(Error::ROFS.raw_os_error())
+        );
+
+        umount2(&target, UnmountFlags::empty()).unwrap();
+        assert!(!target.join("file").exists());
+        assert_eq!(umount2(&target, UnmountFlags::empty()), Err(Error::INVAL));
+    });
+}
+
+#[test]
+fn test_fsopen_fsmount_move_mount() {
+    in_mount_namespace("test_fsopen_fsmount_move_mount", |tmpdir| {
+        let target = create_dir(tmpdir, "target");
+
+        let fs = fsopen("tmpfs", FsOpenFlags::CLOEXEC).unwrap();
+        fsconfig_set_string(&fs, "size", "1m").unwrap();
+        assert_eq!(fsconfig_set_flag(&fs, "no-such-option"), Err(Error::INVAL));
+        fsconfig_create(&fs).unwrap();
+        let mnt = fsmount(&fs, FsMountFlags::CLOEXEC, MountAttrFlags::NODEV).unwrap();
+
+        // The detached mount can be used through its file descriptor.
+        openat(
+            &mnt,
+            "file",
+            OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+            Mode::IRUSR | Mode::IWUSR,
+        )
+        .unwrap();
+        assert!(!target.join("file").exists());
+
+        move_mount(&mnt, "", &cwd(), &target, MoveMountFlags::F_EMPTY_PATH).unwrap();
+        assert!(target.join("file").exists());
+
+        let fs = fspick(&cwd(), &target, FsPickFlags::CLOEXEC).unwrap();
+        fsconfig_set_string(&fs, "size", "2m").unwrap();
+        fsconfig_reconfigure(&fs).unwrap();
+
+        umount2(&target, UnmountFlags::DETACH).unwrap();
+    });
+}
+
+#[test]
+fn test_open_tree_mount_setattr() {
+    in_mount_namespace("test_open_tree_mount_setattr", |tmpdir| {
+        let source = create_dir(tmpdir, "source");
+        let target = create_dir(tmpdir, "target");
+        mount("tmpfs", &source, "tmpfs", MountFlags::empty(), None).unwrap();
+        std::fs::write(source.join("file"), b"hello").unwrap();
+
+        let tree = open_tree(
+            &cwd(),
+            &source,
+            OpenTreeFlags::CLONE | OpenTreeFlags::CLOEXEC,
+        )
+        .unwrap();
+        mount_setattr(
+            &tree,
+            "",
+            MountSetattrFlags::EMPTY_PATH,
+            &MountAttr {
+                attr_set: MountAttrFlags::RDONLY,
+                propagation: Some(MountPropagation::Private),
+                ..MountAttr::default()
+            },
+        )
+        .unwrap();
+        move_mount(&tree, "", &cwd(), &target, MoveMountFlags::F_EMPTY_PATH).unwrap();
+        drop(tree);
+
+        assert_eq!(std::fs::read(target.join("file")).unwrap(), b"hello");
+        assert_eq!(
+            std::fs::write(target.join("file"), b"world")
+                .unwrap_err()
+                .raw_os_error(),
+            Some(Error::ROFS.raw_os_error())
+        );
+        std::fs::write(source.join("file"), b"world").unwrap();
+        assert_eq!(std::fs::read(target.join("file")).unwrap(), b"world");
+
+        umount2(&target, UnmountFlags::empty()).unwrap();
+        umount2(&source, UnmountFlags::empty()).unwrap();
+    });
+}
+
+#[test]
+fn test_mount_setattr_idmap() {
+    // Only mounts of filesystems from the initial user namespace can be
+    // idmapped, so this needs real privileges.
+    if getuid() != rustix::process::Uid::ROOT {
+        return;
+    }
+    in_namespaces("test_mount_setattr_idmap", UnshareFlags::NEWNS, |tmpdir| {
+        let source = create_dir(tmpdir, "source");
+        let target = create_dir(tmpdir, "target");
+        mount("", "/", "", MountFlags::REC | MountFlags::PRIVATE, None).unwrap();
+        mount("tmpfs", &source, "tmpfs", MountFlags::empty(), None).unwrap();
+        let file = source.join("file");
+        std::fs::write(&file, b"").unwrap();
+
+        // Hold a user namespace which maps 0 to 1000, so that files owned by 0
+        // appear to be owned by 1000 through the idmapped mount.
+        let mut holder = unsafe {
+            Command::new("sleep")
+                .arg("1000")
+                .pre_exec(|| Ok(unshare(UnshareFlags::NEWUSER)?))
+                .spawn()
+                .unwrap()
+        };
+        for map in ["uid_map", "gid_map"] {
+            write_file(format!("/proc/{}/{}", holder.id(), map), "0 1000 1").unwrap();
+        }
+        let userns = openat(
+            &cwd(),
+            format!("/proc/{}/ns/user", holder.id()),
+            OFlags::RDONLY | OFlags::CLOEXEC,
+            Mode::empty(),
+        )
+        .unwrap();
+        holder.kill().unwrap();
+        holder.wait().unwrap();
+
+        let tree = open_tree(
+            &cwd(),
+            &source,
+            OpenTreeFlags::CLONE | OpenTreeFlags::CLOEXEC,
+        )
+        .unwrap();
+        mount_setattr(
+            &tree,
+            "",
+            MountSetattrFlags::EMPTY_PATH,
+            &MountAttr {
+                attr_set: MountAttrFlags::IDMAP,
+                userns_fd: Some(userns.as_fd()),
+                ..MountAttr::default()
+            },
+        )
+        .unwrap();
+        move_mount(&tree, "", &cwd(), &target, MoveMountFlags::F_EMPTY_PATH).unwrap();
+
+        let source_stat = statat(&cwd(), &file, AtFlags::empty()).unwrap();
+        let target_stat = statat(&cwd(), target.join("file"), AtFlags::empty()).unwrap();
+        assert_eq!((source_stat.st_uid, source_stat.st_gid), (0, 0));
+        assert_eq!((target_stat.st_uid, target_stat.st_gid), (1000, 1000));
+    });
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+fn test_statmount_statx_mnt_id() {
+    use rustix::fs::{statx, StatxFlags};
+
+    let stx = statx(&cwd(), "/", AtFlags::empty(), StatxFlags::MNT_ID_UNIQUE).unwrap();
+    // `STATX_MNT_ID_UNIQUE` and `statmount` require Linux 6.8.
+    if stx.stx_mask & StatxFlags::MNT_ID_UNIQUE.bits() == 0 {
+        return;
+    }
+
+    let mask = StatmountFlags::SB_BASIC
+        | StatmountFlags::MNT_BASIC
+        | StatmountFlags::MNT_ROOT
+        | StatmountFlags::MNT_POINT
+        | StatmountFlags::FS_TYPE;
+    let mut buf = vec![0_u8; 4096];
+    let sm = statmount(stx.stx_mnt_id, mask, &mut buf).unwrap();
+    assert!(sm.mask().contains(mask));
+    assert_eq!(sm.mnt_id(), stx.stx_mnt_id);
+    assert_eq!(sm.mnt_point().unwrap().to_str().unwrap(), "/");
+    assert!(!sm.fs_type().unwrap().to_bytes().is_empty());
+    assert!(sm.mnt_opts().is_none());
+    assert_eq!(
+        (sm.sb_dev_major(), sm.sb_dev_minor()),
+        (stx.stx_dev_major, stx.stx_dev_minor)
+    );
+
+    // The strings don't fit after the fixed-size part.
+    assert_eq!(
+        statmount(stx.stx_mnt_id, mask, &mut buf[..512]).unwrap_err(),
+        Error::OVERFLOW
+    );
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[test]
+fn test_listmount_statmount() {
+    use rustix::fs::{statx, StatxFlags};
+
+    in_mount_namespace("test_listmount_statmount", |tmpdir| {
+        let parent = create_dir(tmpdir, "parent");
+        mount("tmpfs", &parent, "tmpfs", MountFlags::empty(), None).unwrap();
+        for name in ["a", "b", "c"] {
+            let child = create_dir(&parent, name);
+            mount(
+                "tmpfs",
+                &child,
+                "tmpfs",
+                MountFlags::NOEXEC,
+                Some(rustix::zstr!("mode=0700")),
+            )
+            .unwrap();
+        }
+
+        let stx = statx(&cwd(), &parent, AtFlags::empty(), StatxFlags::MNT_ID_UNIQUE).unwrap();
+        // `STATX_MNT_ID_UNIQUE` and `listmount` require Linux 6.8.
+        if stx.stx_mask & StatxFlags::MNT_ID_UNIQUE.bits() == 0 {
+            return;
+        }
+        let parent_id = stx.stx_mnt_id;
+
+        // Use a one-element buffer so that the iterator has to refill it.
+        let mut one = [0_u64; 1];
+        let mut buf = vec![0_u8; 4096];
+        let mut children = Vec::new();
+        for id in ChildMounts::new(parent_id, ListmountFlags::empty(), &mut one) {
+            let id = id.unwrap();
+            let mask = StatmountFlags::MNT_BASIC
+                | StatmountFlags::MNT_POINT
+                | StatmountFlags::FS_TYPE
+                | StatmountFlags::MNT_OPTS;
+            let sm = statmount(id, mask, &mut buf).unwrap();
+            assert_eq!(sm.mnt_id(), id);
+            assert_eq!(sm.mnt_parent_id(), parent_id);
+            assert_eq!(sm.fs_type().unwrap().to_str().unwrap(), "tmpfs");
+            assert!(sm.mnt_attr().contains(MountAttrFlags::NOEXEC));
+            // `STATMOUNT_MNT_OPTS` requires Linux 6.10.
+            if let Some(opts) = sm.mnt_opts() {
+                assert!(opts
+                    .to_str()
+                    .unwrap()
+                    .split(',')
+                    .any(|opt| opt == "mode=700"));
+            }
+            let point = sm.mnt_point().unwrap().to_str().unwrap().to_owned();
+            children.push(Path::new(&point).strip_prefix(&parent).unwrap().to_owned());
+        }
+        assert_eq!(children, [Path::new("a"), Path::new("b"), Path::new("c")]);
+
+        let mut ids = [0_u64; 16];
+        let n = listmount(parent_id, 0, ListmountFlags::empty(), &mut ids).unwrap();
+        assert_eq!(n, 3);
+        let n = listmount(parent_id, ids[0], ListmountFlags::empty(), &mut ids).unwrap();
+        assert_eq!(n, 2);
+    });
+}
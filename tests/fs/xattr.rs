@@ -0,0 +1,145 @@
+use rustix::fs::{
+    cwd, fgetxattr, flistxattr, fremovexattr, fsetxattr, getxattr, getxattrat, lgetxattr,
+    listxattr, listxattrat, openat, removexattr, removexattrat, setxattr, setxattrat, AtFlags,
+    Mode, OFlags, XattrFlags, XattrNames,
+};
+use rustix::io::Error;
+
+#[test]
+fn test_xattr() {
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+    let file = tmpdir.path().join("file");
+    std::fs::write(&file, b"").unwrap();
+
+    match setxattr(&file, "user.rustix", b"hello", XattrFlags::CREATE) {
+        Ok(()) => (),
+        // The filesystem may not support user xattrs.
+        Err(Error::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        setxattr(&file, "user.rustix", b"hello", XattrFlags::CREATE),
+        Err(Error::EXIST)
+    );
+    assert_eq!(
+        setxattr(&file, "user.missing", b"", XattrFlags::REPLACE),
+        Err(Error::NODATA)
+    );
+    setxattr(&file, "user.rustix", b"world!", XattrFlags::REPLACE).unwrap();
+
+    let mut value = [0_u8; 16];
+    assert_eq!(getxattr(&file, "user.rustix", &mut []), Ok(6));
+    assert_eq!(
+        getxattr(&file, "user.rustix", &mut value[..2]),
+        Err(Error::RANGE)
+    );
+    let n = getxattr(&file, "user.rustix", &mut value).unwrap();
+    assert_eq!(&value[..n], b"world!");
+    assert_eq!(
+        getxattr(&file, "user.missing", &mut value),
+        Err(Error::NODATA)
+    );
+
+    let symlink = tmpdir.path().join("symlink");
+    std::os::unix::fs::symlink(&file, &symlink).unwrap();
+    assert_eq!(getxattr(&symlink, "user.rustix", &mut value), Ok(6));
+    assert_eq!(
+        lgetxattr(&symlink, "user.rustix", &mut value),
+        Err(Error::NODATA)
+    );
+
+    setxattr(&file, "user.other", b"", XattrFlags::empty()).unwrap();
+    let mut list = [0_u8; 256];
+    let n = listxattr(&file, &mut list).unwrap();
+    let mut names = XattrNames::new(&list[..n])
+        .map(|name| name.to_str().unwrap())
+        .filter(|name| name.starts_with("user."))
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    assert_eq!(names, ["user.other", "user.rustix"]);
+
+    removexattr(&file, "user.other").unwrap();
+    assert_eq!(removexattr(&file, "user.other"), Err(Error::NODATA));
+}
+
+#[test]
+fn test_fxattr() {
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+    let file = openat(
+        &cwd(),
+        tmpdir.path().join("file"),
+        OFlags::CREATE | OFlags::RDWR | OFlags::CLOEXEC,
+        Mode::IRUSR | Mode::IWUSR,
+    )
+    .unwrap();
+
+    match fsetxattr(&file, "user.rustix", b"hello", XattrFlags::empty()) {
+        Ok(()) => (),
+        Err(Error::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let mut value = [0_u8; 16];
+    let n = fgetxattr(&file, "user.rustix", &mut value).unwrap();
+    assert_eq!(&value[..n], b"hello");
+
+    let mut list = [0_u8; 256];
+    let n = flistxattr(&file, &mut list).unwrap();
+    assert!(XattrNames::new(&list[..n]).any(|name| name.to_bytes() == b"user.rustix"));
+
+    fremovexattr(&file, "user.rustix").unwrap();
+    assert_eq!(
+        fgetxattr(&file, "user.rustix", &mut value),
+        Err(Error::NODATA)
+    );
+}
+
+#[test]
+fn test_xattrat() {
+    let tmpdir = tempfile::tempdir().expect("construct tempdir");
+    let dir = openat(
+        &cwd(),
+        tmpdir.path(),
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    std::fs::write(tmpdir.path().join("file"), b"").unwrap();
+
+    match setxattrat(
+        &dir,
+        "file",
+        AtFlags::empty(),
+        "user.rustix",
+        b"hello",
+        XattrFlags::CREATE,
+    ) {
+        Ok(()) => (),
+        // The `*xattrat` syscalls require Linux 6.13.
+        Err(Error::NOSYS) | Err(Error::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let mut value = [0_u8; 16];
+    let n = getxattrat(&dir, "file", AtFlags::empty(), "user.rustix", &mut value).unwrap();
+    assert_eq!(&value[..n], b"hello");
+
+    let mut list = [0_u8; 256];
+    let n = listxattrat(&dir, "file", AtFlags::empty(), &mut list).unwrap();
+    assert!(XattrNames::new(&list[..n]).any(|name| name.to_bytes() == b"user.rustix"));
+
+    removexattrat(&dir, "file", AtFlags::empty(), "user.rustix").unwrap();
+    assert_eq!(
+        getxattrat(&dir, "file", AtFlags::empty(), "user.rustix", &mut value),
+        Err(Error::NODATA)
+    );
+}
+
+#[test]
+fn test_xattr_names() {
+    let names = XattrNames::new(b"user.a\0security.selinux\0trailing")
+        .map(|name| name.to_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [&b"user.a"[..], &b"security.selinux"[..]]);
+    assert_eq!(XattrNames::new(b"").count(), 0);
+}
@@ -0,0 +1,66 @@
+#[cfg(target_os = "linux")]
+fn nops(flags: rustix::io_uring::IoringSetupFlags) {
+    use rustix::io;
+    use rustix::io_uring::{
+        io_uring_enter, io_uring_setup, IoUringParams, IoUringRings, IoringEnterFlags, IoringOp,
+    };
+    use std::ptr::null;
+    use std::sync::atomic::Ordering;
+
+    let mut params = IoUringParams {
+        flags,
+        ..Default::default()
+    };
+    let fd = match io_uring_setup(4, &mut params) {
+        Ok(fd) => fd,
+        // io_uring may be disabled or unavailable in the test environment,
+        // and older kernels don't support all the flags.
+        Err(io::Error::NOSYS) | Err(io::Error::PERM) | Err(io::Error::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(params.sq_entries >= 4);
+
+    let mut rings = unsafe { IoUringRings::new(&fd, &params) }.unwrap();
+
+    // Submit and complete two entries, so that the second one is at a
+    // nonzero index and depends on the entry sizes.
+    for user_data in [0x1234_5678, 0x9abc_def0] {
+        let tail = rings.sq_tail().load(Ordering::Acquire);
+        let sqe = rings.sqe_mut(tail);
+        *sqe = Default::default();
+        sqe.opcode = IoringOp::Nop;
+        sqe.user_data = user_data;
+        let index = tail & (rings.sq_entries() - 1);
+        rings.sq_array()[index as usize].store(index, Ordering::Relaxed);
+        rings
+            .sq_tail()
+            .store(tail.wrapping_add(1), Ordering::Release);
+
+        let submitted =
+            unsafe { io_uring_enter(&fd, 1, 1, IoringEnterFlags::GETEVENTS, null(), 0) }.unwrap();
+        assert_eq!(submitted, 1);
+
+        let head = rings.cq_head().load(Ordering::Acquire);
+        assert_ne!(head, rings.cq_tail().load(Ordering::Acquire));
+        let cqe = rings.cqe(head);
+        assert_eq!(cqe.user_data, user_data);
+        assert_eq!(cqe.res, 0);
+        rings
+            .cq_head()
+            .store(head.wrapping_add(1), Ordering::Release);
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_io_uring_nop() {
+    nops(rustix::io_uring::IoringSetupFlags::empty());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_io_uring_nop_big_entries() {
+    use rustix::io_uring::IoringSetupFlags;
+
+    nops(IoringSetupFlags::SQE128 | IoringSetupFlags::CQE32);
+}
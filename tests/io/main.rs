@@ -13,12 +13,18 @@ mod eventfd;
 #[cfg(not(windows))]
 mod from_into;
 #[cfg(not(windows))]
+mod io_uring;
+#[cfg(not(windows))]
 mod isatty;
 #[cfg(not(windows))]
 mod mmap;
 #[cfg(not(windows))]
 mod prot;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pty;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "redox"))] // redox doesn't have cwd/openat
 #[cfg(not(target_os = "wasi"))] // wasi support for S_IRUSR etc. submitted to libc in #2264
 mod readwrite;
+#[cfg(not(windows))]
+mod signalfd;
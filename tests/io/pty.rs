@@ -0,0 +1,61 @@
+use rustix::fd::AsRawFd;
+use rustix::io::{grantpt, ioctl_tiocgptpeer, openpt, ptsname, read, unlockpt, write, OpenptFlags};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+
+#[test]
+fn test_openpt() {
+    let master = openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC).unwrap();
+    grantpt(&master).unwrap();
+    unlockpt(&master).unwrap();
+
+    let name = ptsname(&master, Vec::new()).unwrap();
+    assert!(name.to_bytes().starts_with(b"/dev/pts/"));
+
+    let mut libc_name = [0 as libc::c_char; 64];
+    assert_eq!(
+        unsafe { libc::ptsname_r(master.as_raw_fd(), libc_name.as_mut_ptr(), libc_name.len()) },
+        0
+    );
+    assert_eq!(name.as_c_str(), unsafe {
+        std::ffi::CStr::from_ptr(libc_name.as_ptr())
+    });
+
+    let mut slave = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(name.to_str().unwrap())
+        .unwrap();
+    slave.write_all(b"x").unwrap();
+
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&master, &mut buf).unwrap(), 1);
+    assert_eq!(&buf, b"x");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_ioctl_tiocgptpeer() {
+    let master = openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC).unwrap();
+    unlockpt(&master).unwrap();
+
+    let slave = ioctl_tiocgptpeer(
+        &master,
+        OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC,
+    )
+    .unwrap();
+    assert_eq!(write(&slave, b"y").unwrap(), 1);
+
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&master, &mut buf).unwrap(), 1);
+    assert_eq!(&buf, b"y");
+}
+
+#[test]
+fn test_ptsname_not_a_pty() {
+    let file = File::open("Cargo.toml").unwrap();
+    assert_eq!(
+        ptsname(&file, Vec::new()).unwrap_err(),
+        rustix::io::Error::NOTTY
+    );
+}
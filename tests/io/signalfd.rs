@@ -0,0 +1,68 @@
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_signalfd() {
+    use rustix::fd::AsFd;
+    use rustix::io::epoll::{self, Epoll};
+    use rustix::io::{signalfd, signalfd_read, signalfd_set_mask, Error, OwnedFd, SignalfdFlags};
+    use rustix::process::{getpid, sigprocmask, tgkill, SigSet, SigmaskHow, Signal};
+    use rustix::thread::gettid;
+    use std::os::unix::io::AsRawFd;
+    use std::thread;
+
+    // Signal masks are per-thread, so use a new thread to avoid disturbing
+    // other tests.
+    thread::spawn(|| {
+        let mut mask = SigSet::new();
+        mask.set(Signal::USR1);
+        mask.set(Signal::USR2);
+        sigprocmask(SigmaskHow::Block, Some(&mask)).unwrap();
+
+        let mut usr1 = SigSet::new();
+        usr1.set(Signal::USR1);
+        let sfd = signalfd(&usr1, SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK).unwrap();
+        assert_eq!(signalfd_read(&sfd).unwrap_err(), Error::AGAIN);
+
+        tgkill(getpid(), gettid(), Signal::USR1).unwrap();
+        let info = signalfd_read(&sfd).unwrap();
+        assert_eq!(info.signal(), Signal::USR1);
+        assert_eq!(info.pid(), Some(getpid()));
+        assert_eq!(signalfd_read(&sfd).unwrap_err(), Error::AGAIN);
+
+        // Switch the signalfd over to `SIGUSR2`, and wait for it with epoll.
+        let mut usr2 = SigSet::new();
+        usr2.set(Signal::USR2);
+        signalfd_set_mask(&sfd, &usr2).unwrap();
+
+        let epoll =
+            Epoll::new(epoll::CreateFlags::CLOEXEC, epoll::Owning::<OwnedFd>::new()).unwrap();
+        let raw_sfd = sfd.as_fd().as_raw_fd();
+        epoll.add(sfd, epoll::EventFlags::IN).unwrap();
+
+        tgkill(getpid(), gettid(), Signal::USR2).unwrap();
+
+        let mut event_list = epoll::EventVec::with_capacity(4);
+        epoll.wait(&mut event_list, -1).unwrap();
+        let mut seen = false;
+        for (event_flags, target) in &event_list {
+            assert_eq!(target.as_raw_fd(), raw_sfd);
+            assert!(event_flags.contains(epoll::EventFlags::IN));
+            let info = signalfd_read(&*target).unwrap();
+            assert_eq!(info.signal(), Signal::USR2);
+            seen = true;
+        }
+        assert!(seen);
+    })
+    .join()
+    .unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_signalfd_read_short() {
+    use rustix::io::{pipe, signalfd_read, write, Error};
+
+    // A partial record is reported as an error rather than a panic.
+    let (reader, writer) = pipe().unwrap();
+    write(&writer, b"abc").unwrap();
+    assert_eq!(signalfd_read(&reader).unwrap_err(), Error::IO);
+}
@@ -0,0 +1,62 @@
+use rustix::process::*;
+
+#[test]
+fn test_capabilities() {
+    let sets = capabilities(None).unwrap();
+    assert!(sets.permitted.contains(sets.effective));
+    assert_eq!(capabilities(Some(getpid())).unwrap(), sets);
+}
+
+#[test]
+fn test_set_capabilities() {
+    // Use a separate thread, since capabilities are per-thread.
+    std::thread::spawn(|| {
+        let sets = capabilities(None).unwrap();
+        set_capabilities(None, sets).unwrap();
+        assert_eq!(capabilities(None).unwrap(), sets);
+
+        let lowered = CapabilitySets {
+            effective: Capability::empty(),
+            ..sets
+        };
+        set_capabilities(None, lowered).unwrap();
+        assert_eq!(capabilities(None).unwrap(), lowered);
+        set_capabilities(None, sets).unwrap();
+        assert_eq!(capabilities(None).unwrap(), sets);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_bounding_set_capabilities() {
+    let bounding = bounding_set_capabilities().unwrap();
+    assert!(Capability::all().contains(bounding));
+}
+
+#[test]
+fn test_ambient_capabilities() {
+    std::thread::spawn(|| {
+        clear_ambient_capabilities().unwrap();
+        assert_eq!(ambient_capabilities().unwrap(), Capability::empty());
+
+        let sets = capabilities(None).unwrap();
+        if !sets.permitted.contains(Capability::CHOWN) {
+            return;
+        }
+        set_capabilities(
+            None,
+            CapabilitySets {
+                inheritable: sets.permitted,
+                ..sets
+            },
+        )
+        .unwrap();
+        raise_ambient_capabilities(Capability::CHOWN).unwrap();
+        assert_eq!(ambient_capabilities().unwrap(), Capability::CHOWN);
+        lower_ambient_capabilities(Capability::CHOWN).unwrap();
+        assert_eq!(ambient_capabilities().unwrap(), Capability::empty());
+    })
+    .join()
+    .unwrap();
+}
@@ -1,4 +1,4 @@
-use rustix::process;
+use rustix::{io, process};
 
 #[test]
 fn test_getuid() {
@@ -29,3 +29,122 @@ fn test_getpid() {
 fn test_getppid() {
     assert_eq!(process::getppid(), process::getppid());
 }
+
+#[test]
+fn test_getpgrp() {
+    let pgrp = match process::getpgrp() {
+        Some(pgrp) => pgrp,
+        None => {
+            // The process group is in an ancestor PID namespace.
+            assert_eq!(unsafe { libc::getpgrp() }, 0);
+            assert_eq!(process::getpgid(None), Err(io::Error::OPNOTSUPP));
+            return;
+        }
+    };
+    assert_eq!(pgrp.as_raw_nonzero().get() as libc::pid_t, unsafe {
+        libc::getpgrp()
+    });
+    assert_eq!(process::getpgid(None).unwrap(), pgrp);
+    assert_eq!(process::getpgid(Some(process::getpid())).unwrap(), pgrp);
+}
+
+#[test]
+fn test_getsid() {
+    let sid = match process::getsid(None) {
+        Ok(sid) => sid,
+        Err(io::Error::OPNOTSUPP) => {
+            // The session is in an ancestor PID namespace.
+            assert_eq!(unsafe { libc::getsid(0) }, 0);
+            return;
+        }
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(sid.as_raw_nonzero().get() as libc::pid_t, unsafe {
+        libc::getsid(0)
+    });
+    assert_eq!(process::getsid(Some(process::getpid())).unwrap(), sid);
+}
+
+#[test]
+fn test_setsid_setpgid() {
+    use std::os::unix::process::CommandExt;
+    use std::process::Command;
+
+    fn check(ok: bool) -> std::io::Result<()> {
+        if ok {
+            Ok(())
+        } else {
+            Err(std::io::Error::from_raw_os_error(libc::EINVAL))
+        }
+    }
+
+    // `setsid` creates a new session and process group led by the caller.
+    let status = unsafe {
+        Command::new("true")
+            .pre_exec(|| {
+                let sid = process::setsid()?;
+                check(sid == process::getpid())?;
+                check(process::getsid(None)? == sid)?;
+                check(process::getpgrp() == Some(sid))
+            })
+            .status()
+            .unwrap()
+    };
+    assert!(status.success());
+
+    // `setpgid` creates a new process group, and then `setsid` fails because
+    // the caller is a process group leader.
+    let status = unsafe {
+        Command::new("true")
+            .pre_exec(|| {
+                process::setpgid(None, None)?;
+                check(process::getpgrp() == Some(process::getpid()))?;
+                check(process::setsid() == Err(rustix::io::Error::PERM))
+            })
+            .status()
+            .unwrap()
+    };
+    assert!(status.success());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_getresuid() {
+    let (mut ruid, mut euid, mut suid) = (0, 0, 0);
+    assert_eq!(
+        unsafe { libc::getresuid(&mut ruid, &mut euid, &mut suid) },
+        0
+    );
+    let uids = process::getresuid();
+    assert_eq!(uids.ruid.as_raw(), ruid);
+    assert_eq!(uids.euid.as_raw(), euid);
+    assert_eq!(uids.suid.as_raw(), suid);
+    assert_eq!(uids.euid, process::geteuid());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_getresgid() {
+    let (mut rgid, mut egid, mut sgid) = (0, 0, 0);
+    assert_eq!(
+        unsafe { libc::getresgid(&mut rgid, &mut egid, &mut sgid) },
+        0
+    );
+    let gids = process::getresgid();
+    assert_eq!(gids.rgid.as_raw(), rgid);
+    assert_eq!(gids.egid.as_raw(), egid);
+    assert_eq!(gids.sgid.as_raw(), sgid);
+    assert_eq!(gids.egid, process::getegid());
+}
+
+#[test]
+fn test_getgroups() {
+    let groups = process::getgroups().unwrap();
+    let mut expected = vec![0; groups.len()];
+    let len = unsafe { libc::getgroups(expected.len() as _, expected.as_mut_ptr()) };
+    assert_eq!(len as usize, groups.len());
+    assert_eq!(
+        groups.iter().map(|gid| gid.as_raw()).collect::<Vec<_>>(),
+        expected
+    );
+}
@@ -3,13 +3,33 @@
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
 mod auxv;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod capabilities;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod namespace;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pidfd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod prctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod process_vm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod ptrace;
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+mod rlimit;
 mod sched_yield;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod seccomp;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod signal;
+#[cfg(not(target_os = "wasi"))]
+mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have waitpid.
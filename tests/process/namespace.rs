@@ -0,0 +1,102 @@
+use rustix::fs::{cwd, openat, Mode, OFlags};
+use rustix::process::*;
+#[cfg(feature = "procfs")]
+use std::os::unix::process::CommandExt;
+#[cfg(feature = "procfs")]
+use std::process::Command;
+
+fn open_ns(name: &str) -> rustix::io::OwnedFd {
+    openat(
+        &cwd(),
+        format!("/proc/self/ns/{}", name),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_ioctl_ns() {
+    let net = open_ns("net");
+    assert_eq!(ioctl_ns_get_nstype(&net).unwrap(), NamespaceType::NETWORK);
+
+    let user = ioctl_ns_get_userns(&net).unwrap();
+    assert_eq!(ioctl_ns_get_nstype(&user).unwrap(), NamespaceType::USER);
+    ioctl_ns_get_owner_uid(&user).unwrap();
+
+    // A network namespace has no parent.
+    assert_eq!(
+        ioctl_ns_get_parent(&net).unwrap_err(),
+        rustix::io::Error::INVAL
+    );
+}
+
+#[test]
+fn test_setns() {
+    let uts = open_ns("uts");
+    match setns(&uts, NamespaceType::UTS) {
+        Ok(()) | Err(rustix::io::Error::PERM) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        setns(&uts, NamespaceType::NETWORK).unwrap_err(),
+        rustix::io::Error::INVAL
+    );
+}
+
+#[cfg(feature = "procfs")]
+#[test]
+fn test_unshare_user_namespace() {
+    let uid = getuid().as_raw();
+    let gid = getgid().as_raw();
+
+    // `unshare(CLONE_NEWUSER)` fails in multithreaded processes, so do it in a
+    // child process.
+    let output = unsafe {
+        Command::new("cat")
+            .arg("/proc/self/uid_map")
+            .arg("/proc/self/gid_map")
+            .pre_exec(move || {
+                unshare(UnshareFlags::NEWUSER | UnshareFlags::NEWUTS)?;
+                write_setgroups(None, SetgroupsPolicy::Deny)?;
+                write_uid_map(
+                    None,
+                    &[IdMap {
+                        inside: 0,
+                        outside: uid as u32,
+                        count: 1,
+                    }],
+                )?;
+                write_gid_map(
+                    None,
+                    &[IdMap {
+                        inside: 0,
+                        outside: gid as u32,
+                        count: 1,
+                    }],
+                )?;
+                Ok(())
+            })
+            .output()
+    };
+    let output = match output {
+        Ok(output) => output,
+        // Unprivileged user namespaces may be disabled.
+        Err(err) if err.raw_os_error() == Some(libc::EPERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(output.status.success());
+
+    let maps = String::from_utf8(output.stdout).unwrap();
+    let maps: Vec<Vec<&str>> = maps
+        .lines()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    assert_eq!(
+        maps,
+        vec![
+            vec!["0", &uid.to_string(), "1"],
+            vec!["0", &gid.to_string(), "1"]
+        ]
+    );
+}
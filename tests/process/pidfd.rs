@@ -0,0 +1,77 @@
+use rustix::fd::AsRawFd;
+use rustix::io::{poll, PollFd, PollFlags};
+use rustix::process::{
+    getpid, pidfd_getfd, pidfd_open, pidfd_send_signal, waitid_pidfd, Pid, PidfdFlags,
+    PidfdGetfdFlags, Signal, WaitOptions,
+};
+use serial_test::serial;
+use std::fs::File;
+use std::process::{Command, Stdio};
+
+// This test must execute serially with the tests in `wait`, so that `wait`
+// doesn't reap the child process spawned here.
+
+#[test]
+#[serial]
+fn test_pidfd_waitid() {
+    // The child is reaped with `waitid_pidfd` rather than `Child::wait`.
+    let child_id = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child")
+        .id();
+    let pid = unsafe { Pid::from_raw(child_id as _) }.unwrap();
+    let pidfd = match pidfd_open(pid, PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(rustix::io::Error::NOSYS) => {
+            // The kernel doesn't support pidfds; clean up and skip the test.
+            rustix::process::kill_process(pid, Signal::KILL).unwrap();
+            rustix::process::waitpid(Some(pid), WaitOptions::empty()).unwrap();
+            return;
+        }
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // The process is still running.
+    let mut fds = [PollFd::new(&pidfd, PollFlags::IN)];
+    assert_eq!(poll(&mut fds, 0).unwrap(), 0);
+    assert!(waitid_pidfd(&pidfd, WaitOptions::NOHANG).unwrap().is_none());
+
+    pidfd_send_signal(&pidfd, Signal::KILL).unwrap();
+
+    // The pidfd becomes readable when the process terminates.
+    let mut fds = [PollFd::new(&pidfd, PollFlags::IN)];
+    assert_eq!(poll(&mut fds, -1).unwrap(), 1);
+    assert!(fds[0].revents().contains(PollFlags::IN));
+
+    let status = waitid_pidfd(&pidfd, WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(status.terminating_signal(), Some(Signal::KILL));
+    assert_eq!(status.exit_status(), None);
+}
+
+#[test]
+fn test_pidfd_getfd() {
+    let pidfd = match pidfd_open(getpid(), PidfdFlags::NONBLOCK) {
+        Ok(pidfd) => pidfd,
+        Err(rustix::io::Error::NOSYS) | Err(rustix::io::Error::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let file = File::open("Cargo.toml").unwrap();
+    let fd = match pidfd_getfd(&pidfd, file.as_raw_fd(), PidfdGetfdFlags::empty()) {
+        Ok(fd) => fd,
+        // Older kernels, or sandboxes that disallow it.
+        Err(rustix::io::Error::NOSYS) | Err(rustix::io::Error::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_ne!(fd.as_raw_fd(), file.as_raw_fd());
+
+    // Both descriptors refer to the same open file description.
+    let a = rustix::fs::fstat(&file).unwrap();
+    let b = rustix::fs::fstat(&fd).unwrap();
+    assert_eq!(a.st_dev, b.st_dev);
+    assert_eq!(a.st_ino, b.st_ino);
+}
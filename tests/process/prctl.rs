@@ -0,0 +1,69 @@
+use rustix::process::*;
+
+#[test]
+fn test_parent_process_death_signal() {
+    // Use a separate thread, since the signal is a per-thread attribute.
+    std::thread::spawn(|| {
+        assert_eq!(parent_process_death_signal().unwrap(), None);
+        set_parent_process_death_signal(Some(Signal::TERM)).unwrap();
+        assert_eq!(parent_process_death_signal().unwrap(), Some(Signal::TERM));
+        set_parent_process_death_signal(None).unwrap();
+        assert_eq!(parent_process_death_signal().unwrap(), None);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_dumpable_behavior() {
+    let behavior = dumpable_behavior().unwrap();
+    if behavior != DumpableBehavior::DumpableReadableOnlyByRoot {
+        set_dumpable_behavior(behavior).unwrap();
+        assert_eq!(dumpable_behavior().unwrap(), behavior);
+    }
+}
+
+#[test]
+fn test_keep_capabilities() {
+    let keep = keep_capabilities().unwrap();
+    set_keep_capabilities(keep).unwrap();
+    assert_eq!(keep_capabilities().unwrap(), keep);
+}
+
+#[test]
+fn test_thread_name() {
+    std::thread::spawn(|| {
+        set_thread_name(rustix::zstr!("rustix-test")).unwrap();
+        assert_eq!(thread_name().unwrap().as_bytes(), b"rustix-test");
+        set_thread_name(rustix::zstr!("a-thread-name-too-long")).unwrap();
+        assert_eq!(thread_name().unwrap().as_bytes(), b"a-thread-name-t");
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_timer_slack() {
+    std::thread::spawn(|| {
+        let default = timer_slack().unwrap();
+        set_timer_slack(std::num::NonZeroU64::new(100_000)).unwrap();
+        assert_eq!(timer_slack().unwrap(), 100_000);
+        set_timer_slack(None).unwrap();
+        assert_eq!(timer_slack().unwrap(), default);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_child_subreaper() {
+    let subreaper = is_child_subreaper().unwrap();
+    set_child_subreaper(subreaper).unwrap();
+    assert_eq!(is_child_subreaper().unwrap(), subreaper);
+}
+
+#[test]
+fn test_no_new_privs() {
+    // Don't set it, as it can't be unset.
+    let _ = no_new_privs().unwrap();
+}
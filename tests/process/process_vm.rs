@@ -0,0 +1,50 @@
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::process::*;
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_process_vm_readv_writev() {
+    let mut buf = *b"parent";
+    let remote = [RemoteIoVec::new(buf.as_mut_ptr() as usize, buf.len())];
+
+    // The forked child has its own copy of `buf`, at the same address. It
+    // changes it, signals that it has done so, and waits to be killed.
+    let (reader, writer) = rustix::io::pipe().unwrap();
+    let child = unsafe { libc::fork() };
+    assert!(child >= 0);
+    if child == 0 {
+        unsafe {
+            std::ptr::write_volatile(&mut buf, *b"child!");
+            rustix::io::write(&writer, &[0]).unwrap();
+            loop {
+                libc::pause();
+            }
+        }
+    }
+    drop(writer);
+    assert_eq!(rustix::io::read(&reader, &mut [0_u8]).unwrap(), 1);
+    let pid = unsafe { Pid::from_raw(child as _) }.unwrap();
+
+    let (mut head, mut tail) = ([0_u8; 2], [0_u8; 4]);
+    let n = process_vm_readv(
+        pid,
+        &[IoSliceMut::new(&mut head), IoSliceMut::new(&mut tail)],
+        &remote,
+    )
+    .unwrap();
+    assert_eq!(n, 6);
+    assert_eq!((&head, &tail), (b"ch", b"ild!"));
+    assert_eq!(&buf, b"parent");
+
+    let n = process_vm_writev(pid, &[IoSlice::new(b"wr"), IoSlice::new(b"itten")], &remote);
+    assert_eq!(n.unwrap(), 6);
+    let mut readback = [0_u8; 6];
+    let n = process_vm_readv(pid, &[IoSliceMut::new(&mut readback)], &remote).unwrap();
+    assert_eq!(n, 6);
+    assert_eq!(&readback, b"writte");
+
+    kill_process(pid, Signal::KILL).unwrap();
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.terminating_signal(), Some(Signal::KILL));
+}
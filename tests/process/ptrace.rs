@@ -0,0 +1,143 @@
+use rustix::process::*;
+use serial_test::serial;
+use std::os::unix::process::CommandExt;
+use std::process::{Command, Stdio};
+
+// These tests wait for their children, so they run serially with the `wait`
+// tests, which wait for any child.
+
+fn spawn_yes() -> Pid {
+    // The child is reaped with `waitpid` rather than `Child::wait`.
+    let child_id = Command::new("yes")
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("failed to execute child")
+        .id();
+    unsafe { Pid::from_raw(child_id as _) }.unwrap()
+}
+
+fn wait_for(pid: Pid) -> WaitStatus {
+    waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap()
+}
+
+/// `Command::spawn` may return before the child's `execve` has finished, in
+/// which case attaching to it makes the `execve` raise a `SIGTRAP`. Resume
+/// the child past that stop.
+fn wait_for_attach_stop(pid: Pid) -> WaitStatus {
+    loop {
+        let status = wait_for(pid);
+        if status.stopping_signal() == Some(Signal::TRAP) && status.ptrace_event().is_none() {
+            ptrace_cont(pid, None).unwrap();
+        } else {
+            return status;
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn test_ptrace_traceme() {
+    let child_id = unsafe {
+        Command::new("sh")
+            .args(["-c", "exit 3"])
+            .pre_exec(|| Ok(ptrace_traceme()?))
+            .spawn()
+            .expect("failed to execute child")
+            .id()
+    };
+    let pid = unsafe { Pid::from_raw(child_id as _) }.unwrap();
+
+    // The tracee stops with `SIGTRAP` after `execve`.
+    let status = wait_for(pid);
+    assert_eq!(status.stopping_signal(), Some(Signal::TRAP));
+    assert_eq!(status.ptrace_event(), None);
+    assert!(!status.syscall_stopped());
+
+    ptrace_setoptions(
+        pid,
+        PtraceOptions::TRACESYSGOOD | PtraceOptions::TRACEEXIT | PtraceOptions::EXITKILL,
+    )
+    .unwrap();
+
+    let info = ptrace_get_syscall_info(pid).unwrap();
+    assert_eq!(info.op, PtraceSyscallOp::None);
+    #[cfg(target_arch = "x86_64")]
+    assert_eq!(ptrace_getregs(pid).unwrap().rip, info.instruction_pointer);
+
+    let (mut entries, mut exits) = (0, 0);
+    loop {
+        ptrace_syscall(pid, None).unwrap();
+        let status = wait_for(pid);
+        if status.syscall_stopped() {
+            assert_eq!(status.stopping_signal(), Some(Signal::TRAP));
+            match ptrace_get_syscall_info(pid).unwrap().op {
+                PtraceSyscallOp::Entry { .. } => entries += 1,
+                PtraceSyscallOp::Exit { .. } => exits += 1,
+                op => panic!("unexpected syscall stop {:?}", op),
+            }
+        } else if status.ptrace_event() == Some(PtraceEvent::Exit) {
+            // The message is the exit status, encoded as in `WaitStatus`.
+            assert_eq!(ptrace_geteventmsg(pid).unwrap(), 3 << 8);
+            break;
+        } else {
+            panic!("unexpected stop {:?}", status);
+        }
+    }
+    assert!(entries > 0);
+    // The final `exit_group` doesn't return.
+    assert_eq!(exits, entries - 1);
+
+    ptrace_cont(pid, None).unwrap();
+    assert_eq!(wait_for(pid).exit_status(), Some(3));
+}
+
+#[test]
+#[serial]
+fn test_ptrace_attach() {
+    let pid = spawn_yes();
+
+    ptrace_attach(pid).unwrap();
+    assert_eq!(
+        wait_for_attach_stop(pid).stopping_signal(),
+        Some(Signal::STOP)
+    );
+    ptrace_detach(pid, None).unwrap();
+
+    kill_process(pid, Signal::KILL).unwrap();
+    assert_eq!(wait_for(pid).terminating_signal(), Some(Signal::KILL));
+}
+
+#[test]
+#[serial]
+fn test_ptrace_seize() {
+    let pid = spawn_yes();
+
+    ptrace_seize(pid, PtraceOptions::EXITKILL).unwrap();
+    ptrace_interrupt(pid).unwrap();
+    let status = wait_for_attach_stop(pid);
+    assert_eq!(status.ptrace_event(), Some(PtraceEvent::Stop));
+    assert_eq!(status.stopping_signal(), Some(Signal::TRAP));
+
+    // Flip the word at the top of the tracee's stack, and restore it.
+    let sp = ptrace_get_syscall_info(pid).unwrap().stack_pointer as usize;
+    let word = ptrace_peekdata(pid, sp).unwrap();
+    ptrace_pokedata(pid, sp, !word).unwrap();
+    assert_eq!(ptrace_peekdata(pid, sp).unwrap(), !word);
+    ptrace_pokedata(pid, sp, word).unwrap();
+    assert_eq!(ptrace_peekdata(pid, sp).unwrap(), word);
+
+    let mut buf = [0_u8; 4096];
+    let len = ptrace_getregset(pid, RegisterSet::GeneralPurpose, &mut buf).unwrap();
+    assert_eq!(len, std::mem::size_of::<UserRegs>());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    {
+        ptrace_singlestep(pid, None).unwrap();
+        let status = wait_for(pid);
+        assert_eq!(status.stopping_signal(), Some(Signal::TRAP));
+        assert_eq!(status.ptrace_event(), None);
+    }
+
+    kill_process(pid, Signal::KILL).unwrap();
+    assert_eq!(wait_for(pid).terminating_signal(), Some(Signal::KILL));
+}
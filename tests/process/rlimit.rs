@@ -0,0 +1,38 @@
+use rustix::process::{Resource, Rlimit};
+
+#[test]
+fn test_getrlimit() {
+    let lim = rustix::process::getrlimit(Resource::Stack);
+    assert_ne!(lim.current, Some(0));
+    assert_ne!(lim.maximum, Some(0));
+}
+
+#[test]
+fn test_setrlimit() {
+    let lim = rustix::process::getrlimit(Resource::Core);
+    let new = Rlimit {
+        current: Some(0),
+        maximum: lim.maximum,
+    };
+    rustix::process::setrlimit(Resource::Core, new).unwrap();
+    assert_eq!(rustix::process::getrlimit(Resource::Core), new);
+    rustix::process::setrlimit(Resource::Core, lim).unwrap();
+    assert_eq!(rustix::process::getrlimit(Resource::Core), lim);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_prlimit() {
+    let lim = rustix::process::prlimit(None, Resource::Msgqueue, None).unwrap();
+    assert_eq!(lim, rustix::process::getrlimit(Resource::Msgqueue));
+
+    let new = Rlimit {
+        current: Some(0),
+        maximum: lim.maximum,
+    };
+    let old = rustix::process::prlimit(None, Resource::Msgqueue, Some(new)).unwrap();
+    assert_eq!(old, lim);
+    let old = rustix::process::prlimit(None, Resource::Msgqueue, Some(lim)).unwrap();
+    assert_eq!(old, new);
+    assert_eq!(rustix::process::getrlimit(Resource::Msgqueue), lim);
+}
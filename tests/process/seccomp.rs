@@ -0,0 +1,90 @@
+use rustix::io;
+use rustix::process::*;
+
+#[test]
+fn test_seccomp_get_action_avail() {
+    assert!(seccomp_get_action_avail(SeccompAction::Allow).unwrap());
+    assert!(seccomp_get_action_avail(SeccompAction::Errno(libc::EPERM as u16)).unwrap());
+}
+
+#[test]
+fn test_seccomp_set_mode_filter() {
+    // Use a separate thread, since the filter and "no new privs" attribute
+    // apply to the calling thread.
+    std::thread::spawn(|| {
+        let program = SeccompFilter::new()
+            .syscall(Syscall::GETPGID, SeccompAction::Errno(libc::EPERM as u16))
+            .build(SeccompAction::Allow);
+
+        set_no_new_privs(true).unwrap();
+        let listener = seccomp_set_mode_filter(SeccompFilterFlags::empty(), &program).unwrap();
+        assert!(listener.is_none());
+
+        assert_eq!(getpgid(None), Err(io::Error::PERM));
+        getsid(None).unwrap();
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_seccomp_notif() {
+    use rustix::fd::AsFd;
+    use rustix::io::epoll::{self, Epoll};
+    use rustix::io::{DupFlags, OwnedFd};
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let thread = std::thread::spawn(move || {
+        let program = SeccompFilter::new()
+            .syscall(Syscall::GETPGID, SeccompAction::UserNotif)
+            .build(SeccompAction::Allow);
+
+        set_no_new_privs(true).unwrap();
+        let listener = seccomp_set_mode_filter(SeccompFilterFlags::NEW_LISTENER, &program)
+            .unwrap()
+            .unwrap();
+        sender.send(listener).unwrap();
+
+        (getpgid(None), getpgid(None))
+    });
+
+    let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC, epoll::Owning::<OwnedFd>::new()).unwrap();
+    epoll
+        .add(receiver.recv().unwrap(), epoll::EventFlags::IN)
+        .unwrap();
+    let mut event_list = epoll::EventVec::with_capacity(4);
+
+    for call in 0..2 {
+        epoll.wait(&mut event_list, -1).unwrap();
+        let (_event_flags, listener) = (&event_list).into_iter().next().unwrap();
+
+        let notif = seccomp_notif_recv(&*listener).unwrap();
+        assert_eq!(notif.data.nr as u32, Syscall::GETPGID.as_raw());
+        assert!(notif.pid().is_some());
+        assert!(seccomp_notif_id_valid(&*listener, notif.id).unwrap());
+
+        let resp = if call == 0 {
+            SeccompNotifResp::new_error(notif.id, io::Error::PERM)
+        } else {
+            // The target thread shares our file descriptor table.
+            let fd = seccomp_notif_addfd(
+                &*listener,
+                notif.id,
+                &std::io::stdin().as_fd(),
+                None,
+                SeccompNotifAddfdFlags::empty(),
+                DupFlags::CLOEXEC,
+            )
+            .unwrap();
+            unsafe { rustix::io::close(fd) };
+
+            SeccompNotifResp::new_continue(notif.id)
+        };
+        seccomp_notif_send(&*listener, &resp).unwrap();
+        assert!(!seccomp_notif_id_valid(&*listener, notif.id).unwrap());
+    }
+
+    let (first, second) = thread.join().unwrap();
+    assert_eq!(first, Err(io::Error::PERM));
+    assert_eq!(second, getpgid(None));
+}
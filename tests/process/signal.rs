@@ -0,0 +1,86 @@
+use rustix::process::{getpid, kill_process, sigprocmask, tgkill, Pid, SigSet, SigmaskHow, Signal};
+use serial_test::serial;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Command, Stdio};
+use std::thread;
+
+#[test]
+fn test_signal_raw() {
+    assert_eq!(Signal::from_raw(Signal::TERM.as_raw()), Some(Signal::TERM));
+    assert_eq!(Signal::from_raw(0), None);
+    assert_eq!(Signal::from_raw(Signal::rt_max().as_raw() + 1), None);
+    assert_eq!(Signal::rt(0), Some(Signal::rt_min()));
+    assert!(Signal::rt(1).unwrap().is_rt());
+    assert!(!Signal::KILL.is_rt());
+    assert_eq!(format!("{:?}", Signal::CHILD), "SIGCHLD");
+    assert_eq!(format!("{:?}", Signal::rt(2).unwrap()), "SIGRTMIN+2");
+}
+
+#[test]
+fn test_sigset() {
+    let mut set = SigSet::new();
+    assert!(!set.is_set(Signal::USR1));
+    set.set(Signal::USR1);
+    set.set(Signal::rt_max());
+    assert!(set.is_set(Signal::USR1));
+    assert!(set.is_set(Signal::rt_max()));
+    assert!(!set.is_set(Signal::USR2));
+    set.unset(Signal::USR1);
+    assert!(!set.is_set(Signal::USR1));
+    set.clear();
+    assert!(!set.is_set(Signal::rt_max()));
+
+    let full = SigSet::full();
+    assert!(full.is_set(Signal::TERM));
+    assert!(full.is_set(Signal::rt_max()));
+}
+
+#[test]
+fn test_sigprocmask() {
+    // The signal mask is per-thread, so use a new thread to avoid disturbing
+    // other tests.
+    thread::spawn(|| {
+        let mut set = SigSet::new();
+        set.set(Signal::USR1);
+        let orig = sigprocmask(SigmaskHow::Block, Some(&set)).unwrap();
+        assert!(!orig.is_set(Signal::USR1));
+
+        let current = sigprocmask(SigmaskHow::Block, None).unwrap();
+        assert!(current.is_set(Signal::USR1));
+
+        // Send a signal to this thread while it's blocked; it stays pending
+        // and is discarded when the thread exits.
+        tgkill(getpid(), rustix::thread::gettid(), Signal::USR1).unwrap();
+
+        let prev = sigprocmask(SigmaskHow::Unblock, Some(&SigSet::new())).unwrap();
+        assert!(prev.is_set(Signal::USR1));
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+#[serial]
+fn test_kill_process() {
+    let mut child = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { Pid::from_raw(child.id() as _) }.unwrap();
+
+    kill_process(pid, Signal::TERM).unwrap();
+
+    let status = child.wait().expect("failed to wait");
+    assert_eq!(status.signal(), Some(Signal::TERM.as_raw()));
+    assert_eq!(status.code(), None);
+}
+
+#[cfg(linux_raw)]
+#[test]
+fn test_sigaction_query() {
+    use rustix::runtime::{sigaction, SIG_DFL};
+
+    let action = unsafe { sigaction(Signal::USR2, None) }.unwrap();
+    assert_eq!(action.sa_handler, SIG_DFL);
+}
@@ -0,0 +1,9 @@
+use rustix::fs::Mode;
+use rustix::process::umask;
+
+#[test]
+fn test_umask() {
+    let mask = Mode::IRWXO | Mode::IWGRP;
+    let old = umask(mask);
+    assert_eq!(umask(old), mask);
+}
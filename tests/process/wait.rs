@@ -41,3 +41,75 @@ fn test_wait() {
     assert!(status.stopped());
     assert_eq!(child_pid, pid);
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[serial]
+fn test_waitid() {
+    use process::{kill_process, Signal, WaitId, WaitidOptions};
+
+    // The child is reaped with `waitid` rather than `Child::wait`.
+    let child_id = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child")
+        .id();
+    let pid = unsafe { process::Pid::from_raw(child_id as _) }.unwrap();
+
+    assert!(process::waitid(
+        WaitId::Pid(pid),
+        WaitidOptions::EXITED | WaitidOptions::NOHANG
+    )
+    .expect("failed to wait")
+    .is_none());
+
+    kill_process(pid, Signal::STOP).unwrap();
+
+    // With `NOWAIT`, the stop can be observed more than once.
+    for _ in 0..2 {
+        let status = process::waitid(
+            WaitId::Pid(pid),
+            WaitidOptions::STOPPED | WaitidOptions::NOWAIT,
+        )
+        .expect("failed to wait")
+        .unwrap();
+        assert!(status.stopped());
+        assert_eq!(status.pid(), pid);
+        assert_eq!(status.uid(), process::getuid());
+        assert_eq!(status.stopping_signal(), Some(Signal::STOP));
+    }
+
+    kill_process(pid, Signal::KILL).unwrap();
+
+    let status = process::waitid(WaitId::All, WaitidOptions::EXITED)
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.killed());
+    assert!(!status.exited());
+    assert_eq!(status.pid(), pid);
+    assert_eq!(status.terminating_signal(), Some(Signal::KILL));
+    assert_eq!(status.exit_status(), None);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[serial]
+fn test_waitid_exited() {
+    use process::{WaitId, WaitidOptions};
+
+    // The child is reaped with `waitid` rather than `Child::wait`.
+    let child_id = Command::new("sh")
+        .args(["-c", "exit 7"])
+        .spawn()
+        .expect("failed to execute child")
+        .id();
+    let pid = unsafe { process::Pid::from_raw(child_id as _) }.unwrap();
+
+    let status = process::waitid(WaitId::Pid(pid), WaitidOptions::EXITED)
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.exited());
+    assert_eq!(status.exit_status(), Some(7));
+    assert_eq!(status.terminating_signal(), None);
+}
@@ -0,0 +1,50 @@
+#![cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+mod termios;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod termios2;
+
+use std::fs::File;
+
+/// Open a pseudoterminal pair, returning the master and slave.
+#[cfg(target_os = "linux")]
+fn openpty() -> (File, File) {
+    use rustix::fd::{FromRawFd, IntoRawFd};
+    use rustix::io::{grantpt, ioctl_tiocgptpeer, openpt, unlockpt, OpenptFlags};
+
+    let flags = OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC;
+    let master = openpt(flags).unwrap();
+    grantpt(&master).unwrap();
+    unlockpt(&master).unwrap();
+    let slave = ioctl_tiocgptpeer(&master, flags).unwrap();
+    unsafe {
+        (
+            File::from_raw_fd(master.into_raw_fd()),
+            File::from_raw_fd(slave.into_raw_fd()),
+        )
+    }
+}
+
+/// Open a pseudoterminal pair, returning the master and slave.
+///
+/// rustix only has `ioctl_tiocgptpeer` on Linux, so use the libc's `openpty`
+/// elsewhere.
+#[cfg(not(target_os = "linux"))]
+fn openpty() -> (File, File) {
+    use std::os::unix::io::FromRawFd;
+
+    let mut master = -1;
+    let mut slave = -1;
+    let res = unsafe {
+        libc::openpty(
+            &mut master,
+            &mut slave,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    assert_eq!(res, 0);
+    unsafe { (File::from_raw_fd(master), File::from_raw_fd(slave)) }
+}
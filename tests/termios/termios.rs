@@ -0,0 +1,122 @@
+use crate::openpty;
+use rustix::io;
+use rustix::termios::{
+    cfgetospeed, cfmakeraw, cfsetspeed, tcdrain, tcflow, tcflush, tcgetattr, tcgetpgrp, tcgetsid,
+    tcsendbreak, tcsetattr, tcsetpgrp, Action, ControlModes, InputModes, LocalModes,
+    OptionalActions, OutputModes, QueueSelector, B115200, B9600, VMIN, VTIME,
+};
+use std::fs::File;
+
+#[test]
+fn test_tcsetattr_raw() {
+    let (_master, slave) = openpty();
+
+    let mut termios = tcgetattr(&slave).unwrap();
+    cfmakeraw(&mut termios);
+    tcsetattr(&slave, OptionalActions::Now, &termios).unwrap();
+
+    let termios = tcgetattr(&slave).unwrap();
+    assert_eq!(termios.c_lflag & LocalModes::ICANON.bits(), 0);
+    assert_eq!(termios.c_lflag & LocalModes::ECHO.bits(), 0);
+    assert_eq!(termios.c_lflag & LocalModes::ISIG.bits(), 0);
+    assert_eq!(termios.c_iflag & InputModes::ICRNL.bits(), 0);
+    assert_eq!(termios.c_oflag & OutputModes::OPOST.bits(), 0);
+    assert_eq!(
+        termios.c_cflag & ControlModes::CSIZE.bits(),
+        ControlModes::CS8.bits()
+    );
+    assert_eq!(termios.c_cc[VMIN], 1);
+    assert_eq!(termios.c_cc[VTIME], 0);
+}
+
+#[test]
+fn test_speed() {
+    let (_master, slave) = openpty();
+
+    let mut termios = tcgetattr(&slave).unwrap();
+    cfsetspeed(&mut termios, B115200).unwrap();
+    assert_eq!(cfgetospeed(&termios), B115200);
+    tcsetattr(&slave, OptionalActions::Drain, &termios).unwrap();
+    assert_eq!(cfgetospeed(&tcgetattr(&slave).unwrap()), B115200);
+
+    cfsetspeed(&mut termios, B9600).unwrap();
+    tcsetattr(&slave, OptionalActions::Flush, &termios).unwrap();
+    assert_eq!(cfgetospeed(&tcgetattr(&slave).unwrap()), B9600);
+}
+
+#[test]
+fn test_tc_ops() {
+    let (master, slave) = openpty();
+
+    tcdrain(&slave).unwrap();
+    tcflush(&slave, QueueSelector::IOFlush).unwrap();
+    tcflow(&slave, Action::OOff).unwrap();
+    tcflow(&slave, Action::OOn).unwrap();
+    tcsendbreak(&slave).unwrap();
+
+    // Data written to the master and flushed from the slave's input queue
+    // can't be read.
+    io::write(&master, b"hello\n").unwrap();
+    tcdrain(&master).unwrap();
+    tcflush(&slave, QueueSelector::IFlush).unwrap();
+    assert_eq!(io::ioctl_fionread(&slave).unwrap(), 0);
+}
+
+#[test]
+fn test_not_a_tty() {
+    let file = File::open("Cargo.toml").unwrap();
+    assert_eq!(tcgetattr(&file).unwrap_err(), io::Error::NOTTY);
+}
+
+#[test]
+fn test_tiocswinsz() {
+    let (master, slave) = openpty();
+
+    let winsize = io::Winsize {
+        ws_row: 24,
+        ws_col: 80,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    io::ioctl_tiocswinsz(&master, &winsize).unwrap();
+
+    let result = io::ioctl_tiocgwinsz(&slave).unwrap();
+    assert_eq!(result.ws_row, 24);
+    assert_eq!(result.ws_col, 80);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_controlling_terminal() {
+    use std::os::unix::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    let (master, slave) = openpty();
+
+    // Start a child in a new session with the pty slave as its controlling
+    // terminal, and in the foreground process group.
+    let mut child = unsafe {
+        Command::new("sleep")
+            .arg("10")
+            .stdin(Stdio::from(slave))
+            .pre_exec(|| {
+                if libc::setsid() == -1 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                io::ioctl_tiocsctty(&io::stdin(), false)?;
+                tcsetpgrp(&io::stdin(), rustix::process::getpid())?;
+                Ok(())
+            })
+            .spawn()
+            .expect("failed to spawn sleep")
+    };
+    let pid = unsafe { rustix::process::Pid::from_raw(child.id() as _) }.unwrap();
+
+    assert_eq!(tcgetsid(&master).unwrap(), pid);
+    assert_eq!(io::ioctl_tiocgsid(&master).unwrap(), pid);
+    assert_eq!(tcgetpgrp(&master).unwrap(), pid);
+    assert_eq!(io::ioctl_tiocgpgrp(&master).unwrap(), pid);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
@@ -0,0 +1,38 @@
+use crate::openpty;
+use rustix::io::{
+    ioctl_tcgets2, ioctl_tcsets2, termios2_set_ispeed, termios2_set_ospeed, termios2_set_speed,
+};
+use std::fs::File;
+
+#[test]
+fn test_termios2_speed() {
+    let (_master, slave) = openpty();
+
+    let mut termios = ioctl_tcgets2(&slave).unwrap();
+    termios2_set_speed(&mut termios, 250_000);
+    ioctl_tcsets2(&slave, &termios).unwrap();
+
+    let termios = ioctl_tcgets2(&slave).unwrap();
+    assert_eq!(termios.c_ospeed, 250_000);
+    assert_eq!(termios.c_ispeed, 250_000);
+}
+
+#[test]
+fn test_termios2_split_speed() {
+    let (_master, slave) = openpty();
+
+    let mut termios = ioctl_tcgets2(&slave).unwrap();
+    termios2_set_ospeed(&mut termios, 1_000_000);
+    termios2_set_ispeed(&mut termios, 250_000);
+    ioctl_tcsets2(&slave, &termios).unwrap();
+
+    let termios = ioctl_tcgets2(&slave).unwrap();
+    assert_eq!(termios.c_ospeed, 1_000_000);
+    assert_eq!(termios.c_ispeed, 250_000);
+}
+
+#[test]
+fn test_termios2_not_a_tty() {
+    let file = File::open("Cargo.toml").unwrap();
+    assert_eq!(ioctl_tcgets2(&file).unwrap_err(), rustix::io::Error::NOTTY);
+}
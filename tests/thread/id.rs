@@ -5,3 +5,37 @@ use rustix::thread;
 fn test_gettid() {
     assert_eq!(thread::gettid(), thread::gettid());
 }
+
+#[test]
+fn test_set_thread_res_uid_gid() {
+    use rustix::process::{getresgid, getresuid};
+
+    // Setting the IDs to their current values needs no privileges.
+    let uids = getresuid();
+    let gids = getresgid();
+    thread::set_thread_res_uid(uids.ruid, uids.euid, uids.suid).unwrap();
+    thread::set_thread_res_gid(gids.rgid, gids.egid, gids.sgid).unwrap();
+    assert_eq!(getresuid(), uids);
+    assert_eq!(getresgid(), gids);
+}
+
+#[test]
+fn test_set_thread_uid_is_per_thread() {
+    use rustix::process::{geteuid, Uid};
+
+    if geteuid() != Uid::ROOT {
+        return;
+    }
+
+    // Drop privileges in a separate thread, and check that the other threads
+    // keep theirs.
+    let nobody = unsafe { Uid::from_raw(65534) };
+    std::thread::spawn(move || {
+        thread::set_thread_groups(&[]).unwrap();
+        thread::set_thread_uid(nobody).unwrap();
+        assert_eq!(geteuid(), nobody);
+    })
+    .join()
+    .unwrap();
+    assert_eq!(geteuid(), Uid::ROOT);
+}
@@ -5,5 +5,7 @@
 mod dynamic_clocks;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 mod monotonic;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod timerfd;
 mod timespec;
 mod y2038;
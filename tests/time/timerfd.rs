@@ -0,0 +1,66 @@
+use rustix::time::{
+    timerfd_create, timerfd_gettime, timerfd_read, timerfd_settime, Itimerspec, TimerfdClockId,
+    TimerfdFlags, TimerfdTimerFlags, Timespec,
+};
+
+#[test]
+fn test_timerfd() {
+    let fd = timerfd_create(TimerfdClockId::Monotonic, TimerfdFlags::CLOEXEC).unwrap();
+
+    let set = Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 0,
+            tv_nsec: 10_000_000,
+        },
+    };
+    let _old: Itimerspec = timerfd_settime(&fd, TimerfdTimerFlags::empty(), &set).unwrap();
+
+    // Wait for the timer to expire.
+    assert_eq!(timerfd_read(&fd).unwrap(), 1);
+
+    // The one-shot timer is now disarmed.
+    let new = timerfd_gettime(&fd).unwrap();
+    assert_eq!(new.it_value.tv_sec, 0);
+    assert_eq!(new.it_value.tv_nsec, 0);
+}
+
+#[test]
+fn test_timerfd_nonblock() {
+    let fd = timerfd_create(
+        TimerfdClockId::Monotonic,
+        TimerfdFlags::CLOEXEC | TimerfdFlags::NONBLOCK,
+    )
+    .unwrap();
+
+    let set = Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 60,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 60,
+            tv_nsec: 0,
+        },
+    };
+    timerfd_settime(&fd, TimerfdTimerFlags::empty(), &set).unwrap();
+
+    let get = timerfd_gettime(&fd).unwrap();
+    assert_eq!(get.it_interval.tv_sec, 60);
+    assert!(get.it_value.tv_sec <= 60);
+
+    assert_eq!(timerfd_read(&fd), Err(rustix::io::Error::AGAIN));
+}
+
+#[test]
+fn test_timerfd_read_short() {
+    use rustix::io::{pipe, write, Error};
+
+    // A partial count is reported as an error rather than a panic.
+    let (reader, writer) = pipe().unwrap();
+    write(&writer, b"abc").unwrap();
+    assert_eq!(timerfd_read(&reader).unwrap_err(), Error::IO);
+}